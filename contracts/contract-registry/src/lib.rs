@@ -1,11 +1,16 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractevent, contractimpl, Address, BytesN, Env, String, Vec};
 
+mod payout;
 mod storage;
 mod types;
 
-pub use types::{Category, ContractMetadata, Error, PublishParams};
+pub use types::{
+    Advisory, Category, ConstructorParam, ContractMetadata, Dependency, DeployPrice, Error,
+    ForkSource, ForkedFrom, HashReport, ParamType, PayoutTerms, PublishParams, Release,
+    RevenueShare, Severity, TipTotals, VerificationLevel,
+};
 
 #[contractevent]
 pub struct Published {
@@ -17,10 +22,65 @@ pub struct Updated {
     pub contract_id: u32,
 }
 
+#[contractevent]
+pub struct ReleasePublished {
+    pub contract_id: u32,
+    pub version: String,
+}
+
+#[contractevent]
+pub struct ReleaseYanked {
+    pub contract_id: u32,
+    pub version: String,
+}
+
+#[contractevent]
+pub struct Quarantined {
+    pub contract_id: u32,
+    pub quarantined: bool,
+}
+
+#[contractevent]
+pub struct AdvisoryPublished {
+    pub advisory_id: u32,
+    pub contract_id: u32,
+    pub severity: Severity,
+}
+
+#[contractevent]
+pub struct AdvisoryResolved {
+    pub advisory_id: u32,
+}
+
 #[contractevent]
 pub struct Verified {
     pub contract_id: u32,
     pub auditor: Address,
+    pub level: VerificationLevel,
+}
+
+#[contractevent]
+pub struct RoyaltiesUpdated {
+    pub contract_id: u32,
+}
+
+#[contractevent]
+pub struct DependenciesUpdated {
+    pub contract_id: u32,
+}
+
+#[contractevent]
+pub struct SchemaUpdated {
+    pub contract_id: u32,
+}
+
+#[contractevent]
+pub struct Tipped {
+    pub contract_id: u32,
+    pub tipper: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub supporter_count: u32,
 }
 
 #[contractevent]
@@ -34,6 +94,11 @@ pub struct ContractRegistry;
 
 #[contractimpl]
 impl ContractRegistry {
+    /// Configure the registry admin (moderates listings)
+    pub fn __constructor(env: Env, admin: Address) {
+        storage::set_admin(&env, &admin);
+    }
+
     /// Publish a new contract to the registry
     pub fn publish_contract(
         env: Env,
@@ -53,9 +118,17 @@ impl ContractRegistry {
         if params.source_url.is_empty() {
             return Err(Error::InvalidMetadata);
         }
+        if params.version.is_empty() || params.version.len() > 32 {
+            return Err(Error::InvalidMetadata);
+        }
         if params.tags.len() > 10 {
             return Err(Error::InvalidMetadata);
         }
+        if let ForkedFrom::Fork(source) = &params.forked_from {
+            if storage::get_release(&env, source.contract_id, &source.release).is_none() {
+                return Err(Error::InvalidForkSource);
+            }
+        }
 
         // Generate unique contract ID
         let contract_id = storage::increment_counter(&env);
@@ -77,12 +150,28 @@ impl ContractRegistry {
             updated_at: env.ledger().timestamp(),
             total_deployments: 0,
             verified: false,
+            forked_from: params.forked_from,
+            quarantined: false,
+        };
+
+        // The published code is the first release
+        let release = Release {
+            version: metadata.version.clone(),
+            wasm_hash: metadata.wasm_hash.clone(),
+            published_at: metadata.published_at,
+            yanked: false,
         };
+        storage::save_release(&env, contract_id, &release);
+        storage::add_to_versions(&env, contract_id, &release.version);
+        storage::add_to_hash_listings(&env, &release.wasm_hash, contract_id);
 
         // Save to storage
         storage::save_contract(&env, contract_id, &metadata);
         storage::add_to_all_contracts(&env, contract_id);
         storage::add_to_category(&env, &metadata.category, contract_id);
+        if let ForkedFrom::Fork(source) = &metadata.forked_from {
+            storage::add_to_forks(&env, source.contract_id, contract_id);
+        }
 
         // Emit event
         Published { contract_id }.publish(&env);
@@ -136,6 +225,517 @@ impl ContractRegistry {
         Ok(())
     }
 
+    /// Publish a new version of a contract's code (author only)
+    /// The new release becomes the listing's current version
+    pub fn publish_release(
+        env: Env,
+        contract_id: u32,
+        version: String,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let mut metadata =
+            storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        if version.is_empty() || version.len() > 32 {
+            return Err(Error::InvalidMetadata);
+        }
+        if storage::get_release(&env, contract_id, &version).is_some() {
+            return Err(Error::ReleaseAlreadyExists);
+        }
+
+        let release = Release {
+            version: version.clone(),
+            wasm_hash: wasm_hash.clone(),
+            published_at: env.ledger().timestamp(),
+            yanked: false,
+        };
+        storage::save_release(&env, contract_id, &release);
+        storage::add_to_versions(&env, contract_id, &version);
+        storage::add_to_hash_listings(&env, &wasm_hash, contract_id);
+
+        // New code needs its own verification
+        metadata.version = version.clone();
+        metadata.wasm_hash = wasm_hash;
+        metadata.verified = false;
+        metadata.updated_at = env.ledger().timestamp();
+        storage::save_contract(&env, contract_id, &metadata);
+
+        // Emit event
+        ReleasePublished {
+            contract_id,
+            version,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Withdraw a release so it can no longer be deployed (author only)
+    pub fn yank_release(env: Env, contract_id: u32, version: String) -> Result<(), Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        let mut release =
+            storage::get_release(&env, contract_id, &version).ok_or(Error::ReleaseNotFound)?;
+        release.yanked = true;
+        storage::save_release(&env, contract_id, &release);
+
+        // Emit event
+        ReleaseYanked {
+            contract_id,
+            version,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Block (or unblock) every release of a contract from deployment (admin only)
+    pub fn set_quarantined(env: Env, contract_id: u32, quarantined: bool) -> Result<(), Error> {
+        storage::get_admin(&env).require_auth();
+
+        let mut metadata =
+            storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.quarantined = quarantined;
+        storage::save_contract(&env, contract_id, &metadata);
+
+        // Emit event
+        Quarantined {
+            contract_id,
+            quarantined,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a specific release of a contract
+    pub fn get_release(env: Env, contract_id: u32, version: String) -> Result<Release, Error> {
+        storage::get_release(&env, contract_id, &version).ok_or(Error::ReleaseNotFound)
+    }
+
+    /// Get all releases of a contract, oldest first
+    pub fn get_releases(env: Env, contract_id: u32) -> Vec<Release> {
+        let versions = storage::get_versions(&env, contract_id);
+        let mut releases = Vec::new(&env);
+
+        for version in versions.iter() {
+            if let Some(release) = storage::get_release(&env, contract_id, &version) {
+                releases.push_back(release);
+            }
+        }
+
+        releases
+    }
+
+    /// Find the release of a listing that published a WASM hash
+    /// Returns None if no release of the listing uses the hash
+    pub fn find_release_by_hash(
+        env: Env,
+        contract_id: u32,
+        wasm_hash: BytesN<32>,
+    ) -> Option<String> {
+        let versions = storage::get_versions(&env, contract_id);
+
+        for version in versions.iter() {
+            if let Some(release) = storage::get_release(&env, contract_id, &version) {
+                if release.wasm_hash == wasm_hash {
+                    return Some(version);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get the WASM hash of a release that may be deployed
+    /// Fails for unknown, yanked or quarantined releases
+    pub fn resolve_release(
+        env: Env,
+        contract_id: u32,
+        version: String,
+    ) -> Result<BytesN<32>, Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        if metadata.quarantined {
+            return Err(Error::ContractQuarantined);
+        }
+
+        let release =
+            storage::get_release(&env, contract_id, &version).ok_or(Error::ReleaseNotFound)?;
+        if release.yanked {
+            return Err(Error::ReleaseYanked);
+        }
+
+        Ok(release.wasm_hash)
+    }
+
+    /// Publish a security advisory against one of a listing's builds
+    /// Only the listing's author or the registry admin can publish advisories
+    /// An author's advisory only counts against the hash as a whole when their
+    /// listing was the first to release it, so copies can't flag the original
+    pub fn publish_advisory(
+        env: Env,
+        publisher: Address,
+        contract_id: u32,
+        wasm_hash: BytesN<32>,
+        severity: Severity,
+        description: String,
+    ) -> Result<u32, Error> {
+        publisher.require_auth();
+
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        let by_admin = publisher == storage::get_admin(&env);
+        if publisher != metadata.author && !by_admin {
+            return Err(Error::UnauthorizedUpdate);
+        }
+        if !storage::get_hash_listings(&env, &wasm_hash).contains(contract_id) {
+            return Err(Error::ReleaseNotFound);
+        }
+        if severity == Severity::None || description.is_empty() || description.len() > 500 {
+            return Err(Error::InvalidMetadata);
+        }
+
+        let advisory_id = storage::increment_advisory_counter(&env);
+        let advisory = Advisory {
+            advisory_id,
+            contract_id,
+            wasm_hash: wasm_hash.clone(),
+            severity,
+            description,
+            published_at: env.ledger().timestamp(),
+            resolved: false,
+            by_admin,
+        };
+        storage::save_advisory(&env, &advisory);
+        storage::add_to_hash_advisories(&env, &wasm_hash, advisory_id);
+        storage::add_to_contract_advisories(&env, contract_id, advisory_id);
+
+        // Emit event
+        AdvisoryPublished {
+            advisory_id,
+            contract_id,
+            severity,
+        }
+        .publish(&env);
+
+        Ok(advisory_id)
+    }
+
+    /// Mark an advisory as resolved (listing author or registry admin only)
+    pub fn resolve_advisory(env: Env, publisher: Address, advisory_id: u32) -> Result<(), Error> {
+        publisher.require_auth();
+
+        let mut advisory =
+            storage::get_advisory(&env, advisory_id).ok_or(Error::AdvisoryNotFound)?;
+        let metadata =
+            storage::get_contract(&env, advisory.contract_id).ok_or(Error::ContractNotFound)?;
+        if publisher != metadata.author && publisher != storage::get_admin(&env) {
+            return Err(Error::UnauthorizedUpdate);
+        }
+
+        advisory.resolved = true;
+        storage::save_advisory(&env, &advisory);
+
+        // Emit event
+        AdvisoryResolved { advisory_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get all advisories published against a listing, including resolved ones
+    pub fn get_advisories(env: Env, contract_id: u32) -> Vec<Advisory> {
+        let advisory_ids = storage::get_contract_advisories(&env, contract_id);
+        let mut advisories = Vec::new(&env);
+
+        for id in advisory_ids.iter() {
+            if let Some(advisory) = storage::get_advisory(&env, id) {
+                advisories.push_back(advisory);
+            }
+        }
+
+        advisories
+    }
+
+    /// Summarize what the registry knows about a WASM hash: the listings that
+    /// released it, how strongly it was verified, and its worst open advisory
+    /// Only advisories from the admin or from the listing that first released
+    /// the hash count; the rest stay visible through get_advisories
+    pub fn get_hash_report(env: Env, wasm_hash: BytesN<32>) -> HashReport {
+        let listings = storage::get_hash_listings(&env, &wasm_hash);
+        let verification = storage::get_hash_verification(&env, &wasm_hash);
+        let original = listings.first();
+
+        let mut highest_severity = Severity::None;
+        for id in storage::get_hash_advisories(&env, &wasm_hash).iter() {
+            if let Some(advisory) = storage::get_advisory(&env, id) {
+                let hash_wide = advisory.by_admin || Some(advisory.contract_id) == original;
+                if hash_wide && !advisory.resolved && advisory.severity > highest_severity {
+                    highest_severity = advisory.severity;
+                }
+            }
+        }
+
+        HashReport {
+            listings,
+            verification,
+            highest_severity,
+        }
+    }
+
+    /// Set (or clear) the price deployers pay per deployment (author only)
+    pub fn set_deploy_price(
+        env: Env,
+        contract_id: u32,
+        price: Option<DeployPrice>,
+    ) -> Result<(), Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        if let Some(price) = &price {
+            if price.amount <= 0 {
+                return Err(Error::InvalidRoyalty);
+            }
+        }
+
+        storage::set_deploy_price(&env, contract_id, &price);
+
+        // Emit event
+        RoyaltiesUpdated { contract_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Split author revenue with co-authors (author only)
+    /// Shares are in basis points; the author keeps whatever is left
+    pub fn set_revenue_split(
+        env: Env,
+        contract_id: u32,
+        shares: Vec<RevenueShare>,
+    ) -> Result<(), Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        if shares.len() > 10 {
+            return Err(Error::InvalidRoyalty);
+        }
+        let mut total_bps: u32 = 0;
+        for share in shares.iter() {
+            if share.share_bps == 0 {
+                return Err(Error::InvalidRoyalty);
+            }
+            total_bps = total_bps.saturating_add(share.share_bps);
+        }
+        if total_bps > payout::MAX_BPS {
+            return Err(Error::InvalidRoyalty);
+        }
+
+        storage::set_revenue_split(&env, contract_id, &shares);
+
+        // Emit event
+        RoyaltiesUpdated { contract_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the deploy price of a contract, if the author set one
+    pub fn get_deploy_price(env: Env, contract_id: u32) -> Option<DeployPrice> {
+        storage::get_deploy_price(&env, contract_id)
+    }
+
+    /// Get the author and revenue split of a contract
+    pub fn get_payout_terms(env: Env, contract_id: u32) -> Result<PayoutTerms, Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+
+        Ok(PayoutTerms {
+            author: metadata.author,
+            shares: storage::get_revenue_split(&env, contract_id),
+        })
+    }
+
+    /// Declare the listings a contract depends on (author only)
+    /// Replaces any previously declared dependencies
+    pub fn set_dependencies(
+        env: Env,
+        contract_id: u32,
+        dependencies: Vec<Dependency>,
+    ) -> Result<(), Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        // Validate dependencies
+        if dependencies.len() > 10 {
+            return Err(Error::InvalidDependency);
+        }
+        for (i, dependency) in dependencies.iter().enumerate() {
+            if dependency.contract_id == contract_id {
+                return Err(Error::InvalidDependency);
+            }
+            if dependency.version_req.is_empty() || dependency.version_req.len() > 64 {
+                return Err(Error::InvalidDependency);
+            }
+            if storage::get_contract(&env, dependency.contract_id).is_none() {
+                return Err(Error::DependencyNotFound);
+            }
+            // No listing may be declared twice
+            for other in dependencies.iter().skip(i + 1) {
+                if other.contract_id == dependency.contract_id {
+                    return Err(Error::InvalidDependency);
+                }
+            }
+        }
+
+        // Keep the reverse index in sync
+        for old in storage::get_dependencies(&env, contract_id).iter() {
+            storage::remove_dependent(&env, old.contract_id, contract_id);
+        }
+        for dependency in dependencies.iter() {
+            storage::add_dependent(&env, dependency.contract_id, contract_id);
+        }
+
+        storage::set_dependencies(&env, contract_id, &dependencies);
+
+        // Emit event
+        DependenciesUpdated { contract_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the listings a contract depends on
+    pub fn get_dependencies(env: Env, contract_id: u32) -> Vec<Dependency> {
+        storage::get_dependencies(&env, contract_id)
+    }
+
+    /// Get the contracts that declare a dependency on this one
+    pub fn get_dependents(env: Env, contract_id: u32) -> Vec<ContractMetadata> {
+        let contract_ids = storage::get_dependents(&env, contract_id);
+        let mut contracts = Vec::new(&env);
+
+        for id in contract_ids.iter() {
+            if let Some(metadata) = storage::get_contract(&env, id) {
+                contracts.push_back(metadata);
+            }
+        }
+
+        contracts
+    }
+
+    /// Describe the constructor arguments of a contract (author only)
+    /// Parameters with defaults may only follow required ones, so deployers
+    /// can omit trailing arguments
+    pub fn set_constructor_schema(
+        env: Env,
+        contract_id: u32,
+        params: Vec<ConstructorParam>,
+    ) -> Result<(), Error> {
+        let metadata = storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        metadata.author.require_auth();
+
+        // Validate parameters
+        if params.len() > 20 {
+            return Err(Error::InvalidSchema);
+        }
+        let mut has_default = false;
+        for param in params.iter() {
+            if param.name.is_empty() || param.name.len() > 32 {
+                return Err(Error::InvalidSchema);
+            }
+            if param.description.len() > 200 || param.default_value.len() > 1 {
+                return Err(Error::InvalidSchema);
+            }
+            if param.default_value.is_empty() && has_default {
+                return Err(Error::InvalidSchema);
+            }
+            has_default = !param.default_value.is_empty();
+        }
+
+        storage::set_constructor_schema(&env, contract_id, &params);
+
+        // Emit event
+        SchemaUpdated { contract_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the constructor schema of a contract, if the author attached one
+    pub fn get_constructor_schema(env: Env, contract_id: u32) -> Option<Vec<ConstructorParam>> {
+        storage::get_constructor_schema(&env, contract_id)
+    }
+
+    /// Allow or disallow a token for tips (admin only)
+    pub fn set_tip_token(env: Env, token: Address, allowed: bool) {
+        storage::get_admin(&env).require_auth();
+        storage::set_tip_token(&env, &token, allowed);
+    }
+
+    /// Get the tokens tips can be paid in
+    pub fn get_tip_tokens(env: Env) -> Vec<Address> {
+        storage::get_tip_tokens(&env)
+    }
+
+    /// Tip the author of a contract (split with co-authors like other revenue)
+    /// Only tokens allowed by the admin are accepted
+    pub fn tip_author(
+        env: Env,
+        tipper: Address,
+        contract_id: u32,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        tipper.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !storage::get_tip_tokens(&env).contains(&token) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        // Pay co-author shares, the author keeps the remainder
+        let terms = Self::get_payout_terms(env.clone(), contract_id)?;
+        payout::distribute(&env, &token, &tipper, amount, &terms);
+        let author = terms.author;
+
+        // Update running totals
+        let mut listing_tips = storage::get_listing_tips(&env, contract_id, &token);
+        listing_tips.total_amount += amount;
+        listing_tips.tip_count += 1;
+        if storage::mark_listing_supporter(&env, contract_id, &token, &tipper) {
+            listing_tips.supporter_count += 1;
+        }
+        storage::save_listing_tips(&env, contract_id, &token, &listing_tips);
+
+        let mut author_tips = storage::get_author_tips(&env, &author, &token);
+        author_tips.total_amount += amount;
+        author_tips.tip_count += 1;
+        if storage::mark_author_supporter(&env, &author, &token, &tipper) {
+            author_tips.supporter_count += 1;
+        }
+        storage::save_author_tips(&env, &author, &token, &author_tips);
+
+        // Emit event
+        Tipped {
+            contract_id,
+            tipper,
+            token,
+            amount,
+            supporter_count: listing_tips.supporter_count,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get tip totals received by a contract in a token
+    pub fn get_listing_tips(env: Env, contract_id: u32, token: Address) -> TipTotals {
+        storage::get_listing_tips(&env, contract_id, &token)
+    }
+
+    /// Get tip totals received by an author across their contracts in a token
+    pub fn get_author_tips(env: Env, author: Address, token: Address) -> TipTotals {
+        storage::get_author_tips(&env, &author, &token)
+    }
+
     /// Get metadata for a specific contract
     pub fn get_contract(env: Env, contract_id: u32) -> Result<ContractMetadata, Error> {
         storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)
@@ -169,6 +769,38 @@ impl ContractRegistry {
         contracts
     }
 
+    /// Get the direct forks of a contract
+    pub fn get_forks(env: Env, contract_id: u32) -> Vec<ContractMetadata> {
+        let contract_ids = storage::get_forks(&env, contract_id);
+        let mut contracts = Vec::new(&env);
+
+        for id in contract_ids.iter() {
+            if let Some(metadata) = storage::get_contract(&env, id) {
+                contracts.push_back(metadata);
+            }
+        }
+
+        contracts
+    }
+
+    /// Get the chain of releases a contract was derived from, closest first
+    pub fn get_fork_ancestry(env: Env, contract_id: u32) -> Result<Vec<ForkSource>, Error> {
+        let mut metadata =
+            storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
+        let mut ancestry = Vec::new(&env);
+
+        // Parents always exist before their forks, so this cannot cycle
+        while let ForkedFrom::Fork(source) = metadata.forked_from {
+            metadata = match storage::get_contract(&env, source.contract_id) {
+                Some(parent) => parent,
+                None => break,
+            };
+            ancestry.push_back(source);
+        }
+
+        Ok(ancestry)
+    }
+
     /// Search contracts by tag
     pub fn search_by_tag(env: Env, tag: String) -> Vec<ContractMetadata> {
         let all_contracts = Self::get_all_contracts(env.clone());
@@ -186,13 +818,35 @@ impl ContractRegistry {
         matching
     }
 
-    /// Verify a contract (auditors only - for MVP, anyone can verify for testing)
+    /// Approve or remove an auditor (admin only)
+    pub fn set_auditor(env: Env, auditor: Address, allowed: bool) {
+        storage::get_admin(&env).require_auth();
+        storage::set_auditor(&env, &auditor, allowed);
+    }
+
+    /// Get the auditors approved by the admin
+    pub fn get_auditors(env: Env) -> Vec<Address> {
+        storage::get_auditors(&env)
+    }
+
+    /// Verify a contract's current release (for MVP, anyone can verify for testing)
+    /// The release's hash is Audited when verified by an approved auditor and
+    /// Community otherwise; publishing a new release clears the listing's flag
     pub fn verify_contract(env: Env, contract_id: u32, auditor: Address) -> Result<(), Error> {
         auditor.require_auth();
 
         let mut metadata =
             storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
 
+        let level = if storage::get_auditors(&env).contains(&auditor) {
+            VerificationLevel::Audited
+        } else {
+            VerificationLevel::Community
+        };
+        if level > storage::get_hash_verification(&env, &metadata.wasm_hash) {
+            storage::set_hash_verification(&env, &metadata.wasm_hash, level);
+        }
+
         metadata.verified = true;
         storage::save_contract(&env, contract_id, &metadata);
 
@@ -200,14 +854,30 @@ impl ContractRegistry {
         Verified {
             contract_id,
             auditor,
+            level,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    /// Increment deployment count (called by DeploymentManager)
+    /// Set the DeploymentManager allowed to report deployments (admin only)
+    pub fn set_deployment_manager(env: Env, manager: Address) {
+        storage::get_admin(&env).require_auth();
+        storage::set_deployment_manager(&env, &manager);
+    }
+
+    /// Get the configured DeploymentManager, if any
+    pub fn get_deployment_manager(env: Env) -> Option<Address> {
+        storage::get_deployment_manager(&env)
+    }
+
+    /// Increment deployment count (called by the configured DeploymentManager only)
     pub fn increment_deployment_count(env: Env, contract_id: u32) -> Result<(), Error> {
+        storage::get_deployment_manager(&env)
+            .ok_or(Error::UnauthorizedUpdate)?
+            .require_auth();
+
         let mut metadata =
             storage::get_contract(&env, contract_id).ok_or(Error::ContractNotFound)?;
 
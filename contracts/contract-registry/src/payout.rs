@@ -0,0 +1,25 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::types::PayoutTerms;
+
+/// Basis points in 100%
+pub const MAX_BPS: u32 = 10_000;
+
+/// Transfer `amount` from `from`: co-author shares first, with the
+/// remainder going to the author
+pub fn distribute(env: &Env, token: &Address, from: &Address, amount: i128, terms: &PayoutTerms) {
+    let client = token::Client::new(env, token);
+    let mut remaining = amount;
+
+    for share in terms.shares.iter() {
+        let part = amount * share.share_bps as i128 / MAX_BPS as i128;
+        if part > 0 {
+            client.transfer(from, &share.recipient, &part);
+            remaining -= part;
+        }
+    }
+
+    if remaining > 0 {
+        client.transfer(from, &terms.author, &remaining);
+    }
+}
@@ -1,10 +1,51 @@
-use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
-use crate::types::{Category, ContractMetadata};
+use crate::types::{
+    Advisory, Category, ConstructorParam, ContractMetadata, Dependency, DeployPrice, Release,
+    RevenueShare, TipTotals, VerificationLevel,
+};
 
 // Storage keys
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const DEPLOYMENT_MANAGER: Symbol = symbol_short!("DEPLMGR");
 const COUNTER: Symbol = symbol_short!("COUNTER");
 const ALL_CONTRACTS: Symbol = symbol_short!("ALL");
+const RELEASE_PREFIX: Symbol = symbol_short!("RELEASE");
+const VERSIONS_PREFIX: Symbol = symbol_short!("VERSIONS");
+const PRICE_PREFIX: Symbol = symbol_short!("PRICE");
+const SPLIT_PREFIX: Symbol = symbol_short!("SPLIT");
+const DEPS_PREFIX: Symbol = symbol_short!("DEPS");
+const DEPENDENTS_PREFIX: Symbol = symbol_short!("DEPENDNTS");
+const FORKS_PREFIX: Symbol = symbol_short!("FORKS");
+const SCHEMA_PREFIX: Symbol = symbol_short!("SCHEMA");
+const TIPS_PREFIX: Symbol = symbol_short!("TIPS");
+const AUTHOR_TIPS_PREFIX: Symbol = symbol_short!("AUTHTIPS");
+const SUPPORTER_PREFIX: Symbol = symbol_short!("SUPPORTER");
+const AUTHOR_SUPPORTER_PREFIX: Symbol = symbol_short!("AUTHSUP");
+const HASH_LISTINGS_PREFIX: Symbol = symbol_short!("HASHLIST");
+const ADVISORY_COUNTER: Symbol = symbol_short!("ADVCOUNT");
+const ADVISORY_PREFIX: Symbol = symbol_short!("ADVISORY");
+const HASH_ADVISORIES_PREFIX: Symbol = symbol_short!("HASHADV");
+const CONTRACT_ADVISORIES_PREFIX: Symbol = symbol_short!("CTADV");
+const TIP_TOKENS: Symbol = symbol_short!("TIPTOKENS");
+const AUDITORS: Symbol = symbol_short!("AUDITORS");
+const HASH_VERIFICATION_PREFIX: Symbol = symbol_short!("HASHVERIF");
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage().instance().get(&ADMIN).unwrap()
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&ADMIN, admin);
+}
+
+pub fn get_deployment_manager(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DEPLOYMENT_MANAGER)
+}
+
+pub fn set_deployment_manager(env: &Env, manager: &Address) {
+    env.storage().instance().set(&DEPLOYMENT_MANAGER, manager);
+}
 
 pub fn get_counter(env: &Env) -> u32 {
     env.storage().instance().get(&COUNTER).unwrap_or(0)
@@ -52,3 +93,279 @@ pub fn add_to_category(env: &Env, category: &Category, contract_id: u32) {
     let cat_num = *category as u32;
     env.storage().instance().set(&cat_num, &contracts);
 }
+
+pub fn get_deploy_price(env: &Env, contract_id: u32) -> Option<DeployPrice> {
+    let key = (PRICE_PREFIX, contract_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn set_deploy_price(env: &Env, contract_id: u32, price: &Option<DeployPrice>) {
+    let key = (PRICE_PREFIX, contract_id);
+    match price {
+        Some(price) => env.storage().instance().set(&key, price),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+pub fn get_revenue_split(env: &Env, contract_id: u32) -> Vec<RevenueShare> {
+    let key = (SPLIT_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn set_revenue_split(env: &Env, contract_id: u32, shares: &Vec<RevenueShare>) {
+    let key = (SPLIT_PREFIX, contract_id);
+    env.storage().instance().set(&key, shares);
+}
+
+fn empty_tip_totals() -> TipTotals {
+    TipTotals {
+        total_amount: 0,
+        tip_count: 0,
+        supporter_count: 0,
+    }
+}
+
+pub fn get_listing_tips(env: &Env, contract_id: u32, token: &Address) -> TipTotals {
+    let key = (TIPS_PREFIX, contract_id, token);
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(empty_tip_totals())
+}
+
+pub fn save_listing_tips(env: &Env, contract_id: u32, token: &Address, totals: &TipTotals) {
+    let key = (TIPS_PREFIX, contract_id, token);
+    env.storage().instance().set(&key, totals);
+}
+
+pub fn get_author_tips(env: &Env, author: &Address, token: &Address) -> TipTotals {
+    let key = (AUTHOR_TIPS_PREFIX, author, token);
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(empty_tip_totals())
+}
+
+pub fn save_author_tips(env: &Env, author: &Address, token: &Address, totals: &TipTotals) {
+    let key = (AUTHOR_TIPS_PREFIX, author, token);
+    env.storage().instance().set(&key, totals);
+}
+
+pub fn get_tip_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&TIP_TOKENS)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_tip_token(env: &Env, token: &Address, allowed: bool) {
+    let mut tokens = get_tip_tokens(env);
+    match (tokens.first_index_of(token), allowed) {
+        (None, true) => tokens.push_back(token.clone()),
+        (Some(index), false) => {
+            tokens.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().instance().set(&TIP_TOKENS, &tokens);
+}
+
+// Supporter markers grow with every new tipper, so they live in persistent
+// storage rather than in the instance entry every call loads
+
+/// Record a supporter of a listing, returning true the first time they tip in `token`
+pub fn mark_listing_supporter(
+    env: &Env,
+    contract_id: u32,
+    token: &Address,
+    tipper: &Address,
+) -> bool {
+    let key = (SUPPORTER_PREFIX, contract_id, token, tipper);
+    if env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().set(&key, &true);
+    true
+}
+
+/// Record a supporter of an author, returning true the first time they tip in `token`
+pub fn mark_author_supporter(
+    env: &Env,
+    author: &Address,
+    token: &Address,
+    tipper: &Address,
+) -> bool {
+    let key = (AUTHOR_SUPPORTER_PREFIX, author, token, tipper);
+    if env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().set(&key, &true);
+    true
+}
+
+pub fn get_dependencies(env: &Env, contract_id: u32) -> Vec<Dependency> {
+    let key = (DEPS_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn set_dependencies(env: &Env, contract_id: u32, dependencies: &Vec<Dependency>) {
+    let key = (DEPS_PREFIX, contract_id);
+    env.storage().instance().set(&key, dependencies);
+}
+
+pub fn get_dependents(env: &Env, contract_id: u32) -> Vec<u32> {
+    let key = (DEPENDENTS_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_dependent(env: &Env, contract_id: u32, dependent_id: u32) {
+    let mut dependents = get_dependents(env, contract_id);
+    if dependents.contains(dependent_id) {
+        return;
+    }
+    dependents.push_back(dependent_id);
+    let key = (DEPENDENTS_PREFIX, contract_id);
+    env.storage().instance().set(&key, &dependents);
+}
+
+pub fn remove_dependent(env: &Env, contract_id: u32, dependent_id: u32) {
+    let mut dependents = get_dependents(env, contract_id);
+    if let Some(index) = dependents.first_index_of(dependent_id) {
+        dependents.remove(index);
+        let key = (DEPENDENTS_PREFIX, contract_id);
+        env.storage().instance().set(&key, &dependents);
+    }
+}
+
+pub fn get_forks(env: &Env, contract_id: u32) -> Vec<u32> {
+    let key = (FORKS_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_forks(env: &Env, contract_id: u32, fork_id: u32) {
+    let mut forks = get_forks(env, contract_id);
+    forks.push_back(fork_id);
+    let key = (FORKS_PREFIX, contract_id);
+    env.storage().instance().set(&key, &forks);
+}
+
+pub fn get_constructor_schema(env: &Env, contract_id: u32) -> Option<Vec<ConstructorParam>> {
+    let key = (SCHEMA_PREFIX, contract_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn set_constructor_schema(env: &Env, contract_id: u32, params: &Vec<ConstructorParam>) {
+    let key = (SCHEMA_PREFIX, contract_id);
+    env.storage().instance().set(&key, params);
+}
+
+pub fn get_release(env: &Env, contract_id: u32, version: &String) -> Option<Release> {
+    let key = (RELEASE_PREFIX, contract_id, version.clone());
+    env.storage().instance().get(&key)
+}
+
+pub fn save_release(env: &Env, contract_id: u32, release: &Release) {
+    let key = (RELEASE_PREFIX, contract_id, release.version.clone());
+    env.storage().instance().set(&key, release);
+}
+
+pub fn get_versions(env: &Env, contract_id: u32) -> Vec<String> {
+    let key = (VERSIONS_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_versions(env: &Env, contract_id: u32, version: &String) {
+    let mut versions = get_versions(env, contract_id);
+    versions.push_back(version.clone());
+    let key = (VERSIONS_PREFIX, contract_id);
+    env.storage().instance().set(&key, &versions);
+}
+
+pub fn get_hash_listings(env: &Env, wasm_hash: &BytesN<32>) -> Vec<u32> {
+    let key = (HASH_LISTINGS_PREFIX, wasm_hash.clone());
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_hash_listings(env: &Env, wasm_hash: &BytesN<32>, contract_id: u32) {
+    let mut listings = get_hash_listings(env, wasm_hash);
+    if !listings.contains(contract_id) {
+        listings.push_back(contract_id);
+        let key = (HASH_LISTINGS_PREFIX, wasm_hash.clone());
+        env.storage().instance().set(&key, &listings);
+    }
+}
+
+pub fn get_auditors(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&AUDITORS)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_auditor(env: &Env, auditor: &Address, allowed: bool) {
+    let mut auditors = get_auditors(env);
+    match (auditors.first_index_of(auditor), allowed) {
+        (None, true) => auditors.push_back(auditor.clone()),
+        (Some(index), false) => {
+            auditors.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().instance().set(&AUDITORS, &auditors);
+}
+
+pub fn get_hash_verification(env: &Env, wasm_hash: &BytesN<32>) -> VerificationLevel {
+    let key = (HASH_VERIFICATION_PREFIX, wasm_hash.clone());
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(VerificationLevel::Unverified)
+}
+
+pub fn set_hash_verification(env: &Env, wasm_hash: &BytesN<32>, level: VerificationLevel) {
+    let key = (HASH_VERIFICATION_PREFIX, wasm_hash.clone());
+    env.storage().instance().set(&key, &level);
+}
+
+pub fn increment_advisory_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&ADVISORY_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage()
+        .instance()
+        .set(&ADVISORY_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn get_advisory(env: &Env, advisory_id: u32) -> Option<Advisory> {
+    let key = (ADVISORY_PREFIX, advisory_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn save_advisory(env: &Env, advisory: &Advisory) {
+    let key = (ADVISORY_PREFIX, advisory.advisory_id);
+    env.storage().instance().set(&key, advisory);
+}
+
+pub fn get_hash_advisories(env: &Env, wasm_hash: &BytesN<32>) -> Vec<u32> {
+    let key = (HASH_ADVISORIES_PREFIX, wasm_hash.clone());
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_hash_advisories(env: &Env, wasm_hash: &BytesN<32>, advisory_id: u32) {
+    let mut advisories = get_hash_advisories(env, wasm_hash);
+    advisories.push_back(advisory_id);
+    let key = (HASH_ADVISORIES_PREFIX, wasm_hash.clone());
+    env.storage().instance().set(&key, &advisories);
+}
+
+pub fn get_contract_advisories(env: &Env, contract_id: u32) -> Vec<u32> {
+    let key = (CONTRACT_ADVISORIES_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_contract_advisories(env: &Env, contract_id: u32, advisory_id: u32) {
+    let mut advisories = get_contract_advisories(env, contract_id);
+    advisories.push_back(advisory_id);
+    let key = (CONTRACT_ADVISORIES_PREFIX, contract_id);
+    env.storage().instance().set(&key, &advisories);
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, vec, BytesN, Env};
+use soroban_sdk::{testutils::Address as _, token, vec, BytesN, Env, IntoVal};
 
 fn create_test_params(env: &Env) -> PublishParams {
     PublishParams {
@@ -18,13 +18,14 @@ fn create_test_params(env: &Env) -> PublishParams {
         source_url: String::from_str(env, "https://github.com/test/contract"),
         documentation_url: String::from_str(env, "https://docs.test.com"),
         license: String::from_str(env, "MIT"),
+        forked_from: ForkedFrom::Original,
     }
 }
 
 #[test]
 fn test_publish_contract_success() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -49,7 +50,7 @@ fn test_publish_contract_success() {
 #[should_panic]
 fn test_publish_contract_empty_name() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -65,7 +66,7 @@ fn test_publish_contract_empty_name() {
 #[test]
 fn test_get_contract_exists() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -85,7 +86,7 @@ fn test_get_contract_exists() {
 #[should_panic]
 fn test_get_contract_not_found() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     let fake_id = 999;
@@ -96,7 +97,7 @@ fn test_get_contract_not_found() {
 #[test]
 fn test_update_metadata_by_author() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -118,7 +119,7 @@ fn test_update_metadata_by_author() {
 #[test]
 fn test_search_by_category() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -152,7 +153,7 @@ fn test_search_by_category() {
 #[test]
 fn test_increment_deployment_count() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -162,12 +163,21 @@ fn test_increment_deployment_count() {
 
     let published_id = client.publish_contract(&author, &params);
 
+    // Only the configured deployment manager may report deployments
+    let result = client.try_increment_deployment_count(&published_id);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedUpdate)));
+
+    let manager = Address::generate(&env);
+    client.set_deployment_manager(&manager);
+    assert_eq!(client.get_deployment_manager(), Some(manager.clone()));
+
     // Initial deployment count should be 0
     let metadata = client.get_contract(&published_id);
     assert_eq!(metadata.total_deployments, 0);
 
     // Increment deployment count
     client.increment_deployment_count(&published_id);
+    assert_eq!(env.auths()[0].0, manager);
 
     // Verify count incremented
     let metadata = client.get_contract(&published_id);
@@ -182,7 +192,7 @@ fn test_increment_deployment_count() {
 #[test]
 fn test_verify_contract() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -203,12 +213,27 @@ fn test_verify_contract() {
     // Verify status changed
     let metadata = client.get_contract(&published_id);
     assert!(metadata.verified);
+    let report = client.get_hash_report(&metadata.wasm_hash);
+    assert_eq!(report.verification, VerificationLevel::Community);
+
+    // Approved auditors raise the hash to Audited
+    client.set_auditor(&auditor, &true);
+    client.verify_contract(&published_id, &auditor);
+    let report = client.get_hash_report(&metadata.wasm_hash);
+    assert_eq!(report.verification, VerificationLevel::Audited);
+
+    // New releases start unverified
+    let new_hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.publish_release(&published_id, &String::from_str(&env, "2.0.0"), &new_hash);
+    assert!(!client.get_contract(&published_id).verified);
+    let report = client.get_hash_report(&new_hash);
+    assert_eq!(report.verification, VerificationLevel::Unverified);
 }
 
 #[test]
 fn test_get_all_contracts() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -233,7 +258,7 @@ fn test_get_all_contracts() {
 #[test]
 fn test_get_contract_count() {
     let env = Env::default();
-    let contract_id = env.register(ContractRegistry, ());
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
     let client = ContractRegistryClient::new(&env, &contract_id);
 
     env.mock_all_auths();
@@ -255,3 +280,526 @@ fn test_get_contract_count() {
 
     assert_eq!(client.get_contract_count(), 2);
 }
+
+#[test]
+fn test_payout_terms() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let co_author = Address::generate(&env);
+    let token = Address::generate(&env);
+    let params = create_test_params(&env);
+
+    let published_id = client.publish_contract(&author, &params);
+
+    // No price or split by default
+    let terms = client.get_payout_terms(&published_id);
+    assert_eq!(terms.author, author);
+    assert_eq!(terms.shares.len(), 0);
+    assert_eq!(client.get_deploy_price(&published_id), None);
+
+    let price = DeployPrice { token, amount: 500 };
+    client.set_deploy_price(&published_id, &Some(price.clone()));
+    client.set_revenue_split(
+        &published_id,
+        &vec![
+            &env,
+            RevenueShare {
+                recipient: co_author,
+                share_bps: 3000,
+            },
+        ],
+    );
+
+    assert_eq!(client.get_deploy_price(&published_id), Some(price));
+    assert_eq!(client.get_payout_terms(&published_id).shares.len(), 1);
+
+    // Clearing the price makes deployments free again
+    client.set_deploy_price(&published_id, &None);
+    assert_eq!(client.get_deploy_price(&published_id), None);
+}
+
+#[test]
+#[should_panic]
+fn test_revenue_split_over_100_percent() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+
+    let published_id = client.publish_contract(&author, &params);
+
+    // This should panic due to InvalidRoyalty error
+    client.set_revenue_split(
+        &published_id,
+        &vec![
+            &env,
+            RevenueShare {
+                recipient: Address::generate(&env),
+                share_bps: 6000,
+            },
+            RevenueShare {
+                recipient: Address::generate(&env),
+                share_bps: 5000,
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_tip_author() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let co_author = Address::generate(&env);
+    let tipper1 = Address::generate(&env);
+    let tipper2 = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_admin_client = token::StellarAssetClient::new(&env, &token_id);
+    token_admin_client.mint(&tipper1, &1000);
+    token_admin_client.mint(&tipper2, &1000);
+    let token = token::Client::new(&env, &token_id);
+
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+    client.set_revenue_split(
+        &published_id,
+        &vec![
+            &env,
+            RevenueShare {
+                recipient: co_author.clone(),
+                share_bps: 2000,
+            },
+        ],
+    );
+
+    // Tips are only accepted in tokens the admin allowed
+    let result = client.try_tip_author(&tipper1, &published_id, &token_id, &100);
+    assert_eq!(result, Err(Ok(Error::TokenNotAllowed)));
+    client.set_tip_token(&token_id, &true);
+    assert_eq!(client.get_tip_tokens(), vec![&env, token_id.clone()]);
+
+    client.tip_author(&tipper1, &published_id, &token_id, &100);
+    client.tip_author(&tipper1, &published_id, &token_id, &100);
+    client.tip_author(&tipper2, &published_id, &token_id, &300);
+
+    // Tips follow the revenue split
+    assert_eq!(token.balance(&author), 400);
+    assert_eq!(token.balance(&co_author), 100);
+
+    let listing_tips = client.get_listing_tips(&published_id, &token_id);
+    assert_eq!(listing_tips.total_amount, 500);
+    assert_eq!(listing_tips.tip_count, 3);
+    assert_eq!(listing_tips.supporter_count, 2);
+
+    let author_tips = client.get_author_tips(&author, &token_id);
+    assert_eq!(author_tips, listing_tips);
+}
+
+#[test]
+#[should_panic]
+fn test_tip_author_zero_amount() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let tipper = Address::generate(&env);
+    let token = Address::generate(&env);
+    let params = create_test_params(&env);
+
+    let published_id = client.publish_contract(&author, &params);
+
+    // This should panic due to InvalidAmount error
+    client.tip_author(&tipper, &published_id, &token, &0);
+}
+
+#[test]
+fn test_dependencies_and_dependents() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let mut params = create_test_params(&env);
+
+    let token_id = client.publish_contract(&author, &params);
+    params.name = String::from_str(&env, "Governor");
+    let governor_id = client.publish_contract(&author, &params);
+    params.name = String::from_str(&env, "Vault");
+    let vault_id = client.publish_contract(&author, &params);
+
+    let dependency = Dependency {
+        contract_id: token_id,
+        version_req: String::from_str(&env, "^1.0.0"),
+    };
+    client.set_dependencies(&vault_id, &vec![&env, dependency.clone()]);
+    client.set_dependencies(&governor_id, &vec![&env, dependency.clone()]);
+
+    assert_eq!(client.get_dependencies(&vault_id), vec![&env, dependency]);
+    let dependents = client.get_dependents(&token_id);
+    assert_eq!(dependents.len(), 2);
+    assert_eq!(dependents.get(0).unwrap().id, vault_id);
+    assert_eq!(dependents.get(1).unwrap().id, governor_id);
+
+    // Replacing the dependencies updates the reverse index
+    client.set_dependencies(&vault_id, &vec![&env]);
+    let dependents = client.get_dependents(&token_id);
+    assert_eq!(dependents.len(), 1);
+    assert_eq!(dependents.get(0).unwrap().id, governor_id);
+}
+
+#[test]
+fn test_dependency_validation() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+
+    // Unknown target
+    let missing = Dependency {
+        contract_id: 999,
+        version_req: String::from_str(&env, "^1.0.0"),
+    };
+    let result = client.try_set_dependencies(&published_id, &vec![&env, missing]);
+    assert_eq!(result, Err(Ok(Error::DependencyNotFound)));
+
+    // Self dependency
+    let itself = Dependency {
+        contract_id: published_id,
+        version_req: String::from_str(&env, "^1.0.0"),
+    };
+    let result = client.try_set_dependencies(&published_id, &vec![&env, itself]);
+    assert_eq!(result, Err(Ok(Error::InvalidDependency)));
+}
+
+#[test]
+fn test_fork_lineage() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let forker = Address::generate(&env);
+    let mut params = create_test_params(&env);
+
+    let original_id = client.publish_contract(&author, &params);
+
+    let first_source = ForkSource {
+        contract_id: original_id,
+        release: String::from_str(&env, "1.0.0"),
+    };
+    params.name = String::from_str(&env, "ForkedContract");
+    params.forked_from = ForkedFrom::Fork(first_source.clone());
+    let fork_id = client.publish_contract(&forker, &params);
+
+    let second_source = ForkSource {
+        contract_id: fork_id,
+        release: String::from_str(&env, "1.0.0"),
+    };
+    params.name = String::from_str(&env, "ForkOfFork");
+    params.forked_from = ForkedFrom::Fork(second_source.clone());
+    let grandchild_id = client.publish_contract(&forker, &params);
+
+    let forks = client.get_forks(&original_id);
+    assert_eq!(forks.len(), 1);
+    assert_eq!(forks.get(0).unwrap().id, fork_id);
+
+    assert_eq!(
+        client.get_fork_ancestry(&grandchild_id),
+        vec![&env, second_source, first_source]
+    );
+    assert_eq!(client.get_fork_ancestry(&original_id).len(), 0);
+}
+
+#[test]
+fn test_fork_of_unknown_listing() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let mut params = create_test_params(&env);
+    params.forked_from = ForkedFrom::Fork(ForkSource {
+        contract_id: 42,
+        release: String::from_str(&env, "1.0.0"),
+    });
+
+    let result = client.try_publish_contract(&author, &params);
+    assert_eq!(result, Err(Ok(Error::InvalidForkSource)));
+}
+
+#[test]
+fn test_constructor_schema() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+
+    assert_eq!(client.get_constructor_schema(&published_id), None);
+
+    let admin = ConstructorParam {
+        name: String::from_str(&env, "admin"),
+        param_type: ParamType::Address,
+        description: String::from_str(&env, "Account allowed to manage the contract"),
+        default_value: vec![&env],
+    };
+    let limit = ConstructorParam {
+        name: String::from_str(&env, "limit"),
+        param_type: ParamType::U32,
+        description: String::from_str(&env, "Maximum number of items"),
+        default_value: vec![&env, 10u32.into_val(&env)],
+    };
+
+    // Required parameters cannot follow optional ones
+    let result =
+        client.try_set_constructor_schema(&published_id, &vec![&env, limit.clone(), admin.clone()]);
+    assert_eq!(result, Err(Ok(Error::InvalidSchema)));
+
+    let schema = vec![&env, admin, limit];
+    client.set_constructor_schema(&published_id, &schema);
+    assert_eq!(client.get_constructor_schema(&published_id), Some(schema));
+}
+
+#[test]
+fn test_releases() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+
+    // Publishing creates the first release
+    let v1 = String::from_str(&env, "1.0.0");
+    assert_eq!(client.resolve_release(&published_id, &v1), params.wasm_hash);
+
+    let v2 = String::from_str(&env, "1.1.0");
+    let v2_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.publish_release(&published_id, &v2, &v2_hash);
+
+    let metadata = client.get_contract(&published_id);
+    assert_eq!(metadata.version, v2);
+    assert_eq!(metadata.wasm_hash, v2_hash);
+    assert_eq!(client.get_releases(&published_id).len(), 2);
+
+    // Hashes map back to the release that published them
+    assert_eq!(
+        client.find_release_by_hash(&published_id, &v2_hash),
+        Some(v2.clone())
+    );
+    let unknown_hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        client.find_release_by_hash(&published_id, &unknown_hash),
+        None
+    );
+
+    // Versions are unique
+    let result = client.try_publish_release(&published_id, &v2, &v2_hash);
+    assert_eq!(result, Err(Ok(Error::ReleaseAlreadyExists)));
+
+    // Yanked releases cannot be resolved
+    client.yank_release(&published_id, &v1);
+    assert!(client.get_release(&published_id, &v1).yanked);
+    let result = client.try_resolve_release(&published_id, &v1);
+    assert_eq!(result, Err(Ok(Error::ReleaseYanked)));
+
+    let unknown = String::from_str(&env, "9.9.9");
+    let result = client.try_resolve_release(&published_id, &unknown);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotFound)));
+}
+
+#[test]
+fn test_quarantine_blocks_releases() {
+    let env = Env::default();
+    let contract_id = env.register(ContractRegistry, (Address::generate(&env),));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+    let v1 = String::from_str(&env, "1.0.0");
+
+    client.set_quarantined(&published_id, &true);
+    assert!(client.get_contract(&published_id).quarantined);
+    let result = client.try_resolve_release(&published_id, &v1);
+    assert_eq!(result, Err(Ok(Error::ContractQuarantined)));
+
+    client.set_quarantined(&published_id, &false);
+    assert_eq!(client.resolve_release(&published_id, &v1), params.wasm_hash);
+}
+
+#[test]
+fn test_advisories_and_hash_report() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ContractRegistry, (&admin,));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let published_id = client.publish_contract(&author, &params);
+    let wasm_hash = params.wasm_hash.clone();
+
+    let report = client.get_hash_report(&wasm_hash);
+    assert_eq!(report.listings, vec![&env, published_id]);
+    assert_eq!(report.verification, VerificationLevel::Unverified);
+    assert_eq!(report.highest_severity, Severity::None);
+
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+    assert_eq!(client.get_hash_report(&unknown).listings.len(), 0);
+
+    let description = String::from_str(&env, "Unchecked transfer amount");
+    let low = client.publish_advisory(
+        &author,
+        &published_id,
+        &wasm_hash,
+        &Severity::Low,
+        &description,
+    );
+    let high = client.publish_advisory(
+        &admin,
+        &published_id,
+        &wasm_hash,
+        &Severity::High,
+        &description,
+    );
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).highest_severity,
+        Severity::High
+    );
+
+    client.resolve_advisory(&author, &high);
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).highest_severity,
+        Severity::Low
+    );
+    client.resolve_advisory(&admin, &low);
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).highest_severity,
+        Severity::None
+    );
+    assert_eq!(client.get_advisories(&published_id).len(), 2);
+
+    // Only the author or admin can publish, and only against released hashes
+    let result = client.try_publish_advisory(
+        &Address::generate(&env),
+        &published_id,
+        &wasm_hash,
+        &Severity::Critical,
+        &description,
+    );
+    assert_eq!(result, Err(Ok(Error::UnauthorizedUpdate)));
+    let result = client.try_publish_advisory(
+        &author,
+        &published_id,
+        &unknown,
+        &Severity::Low,
+        &description,
+    );
+    assert_eq!(result, Err(Ok(Error::ReleaseNotFound)));
+
+    client.verify_contract(&published_id, &Address::generate(&env));
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).verification,
+        VerificationLevel::Community
+    );
+}
+
+#[test]
+fn test_copied_hash_advisories_stay_with_the_copy() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ContractRegistry, (&admin,));
+    let client = ContractRegistryClient::new(&env, &contract_id);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let params = create_test_params(&env);
+    let original_id = client.publish_contract(&author, &params);
+    let wasm_hash = params.wasm_hash.clone();
+
+    // Another author lists the same build and flags it
+    let copier = Address::generate(&env);
+    let mut copy_params = create_test_params(&env);
+    copy_params.name = String::from_str(&env, "CopiedContract");
+    let copy_id = client.publish_contract(&copier, &copy_params);
+
+    let description = String::from_str(&env, "Drains every balance");
+    let flagged = client.publish_advisory(
+        &copier,
+        &copy_id,
+        &wasm_hash,
+        &Severity::Critical,
+        &description,
+    );
+    assert_eq!(client.get_advisories(&copy_id).len(), 1);
+    let report = client.get_hash_report(&wasm_hash);
+    assert_eq!(report.listings, vec![&env, original_id, copy_id]);
+    assert_eq!(report.highest_severity, Severity::None);
+
+    // The admin can still flag the hash through any listing that released it
+    let confirmed =
+        client.publish_advisory(&admin, &copy_id, &wasm_hash, &Severity::High, &description);
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).highest_severity,
+        Severity::High
+    );
+    client.resolve_advisory(&admin, &confirmed);
+    client.resolve_advisory(&copier, &flagged);
+
+    // The original author's advisories count for everyone
+    client.publish_advisory(
+        &author,
+        &original_id,
+        &wasm_hash,
+        &Severity::Medium,
+        &description,
+    );
+    assert_eq!(
+        client.get_hash_report(&wasm_hash).highest_severity,
+        Severity::Medium
+    );
+}
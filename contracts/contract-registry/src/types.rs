@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Val, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -12,6 +12,7 @@ pub struct PublishParams {
     pub source_url: String,
     pub documentation_url: String,
     pub license: String,
+    pub forked_from: ForkedFrom,
 }
 
 #[contracttype]
@@ -32,6 +33,159 @@ pub struct ContractMetadata {
     pub updated_at: u64,
     pub total_deployments: u32,
     pub verified: bool,
+    pub forked_from: ForkedFrom,
+    pub quarantined: bool,
+}
+
+/// A published version of a contract's code
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Release {
+    pub version: String,
+    pub wasm_hash: BytesN<32>,
+    pub published_at: u64,
+    pub yanked: bool,
+}
+
+/// The listing release a contract was derived from
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForkSource {
+    pub contract_id: u32,
+    pub release: String,
+}
+
+/// Whether a contract is original work or a modified copy of another listing
+/// An enum rather than Option<ForkSource>: the SDK's testutils can't convert
+/// optional struct fields of contract types
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForkedFrom {
+    Original,
+    Fork(ForkSource),
+}
+
+/// Price charged to deployers each time the contract is deployed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployPrice {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Portion of author revenue paid to a co-author, in basis points
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevenueShare {
+    pub recipient: Address,
+    pub share_bps: u32,
+}
+
+/// Who gets paid for a contract
+/// (the author receives whatever the co-author shares leave over)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutTerms {
+    pub author: Address,
+    pub shares: Vec<RevenueShare>,
+}
+
+/// A listing this contract needs alongside it, with the versions it works with
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dependency {
+    pub contract_id: u32,
+    pub version_req: String, // e.g. "^1.2.0"
+}
+
+/// Type of a constructor parameter
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum ParamType {
+    Any = 0,
+    Bool = 1,
+    U32 = 2,
+    I32 = 3,
+    U64 = 4,
+    I64 = 5,
+    U128 = 6,
+    I128 = 7,
+    U256 = 8,
+    I256 = 9,
+    Address = 10,
+    String = 11,
+    Symbol = 12,
+    Bytes = 13,
+    Vec = 14,
+    Map = 15,
+}
+
+/// One constructor parameter, in the order the constructor takes them
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorParam {
+    pub name: String,
+    pub param_type: ParamType,
+    pub description: String,
+    pub default_value: Vec<Val>, // Empty if required, otherwise the single default
+}
+
+/// Running tip totals in one token, for a listing or an author
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TipTotals {
+    pub total_amount: i128,
+    pub tip_count: u32,
+    pub supporter_count: u32,
+}
+
+/// How serious a security advisory is
+/// None is only used in reports, for hashes without open advisories
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum Severity {
+    None = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+    Critical = 4,
+}
+
+/// Security advisory against one build of a listing
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Advisory {
+    pub advisory_id: u32,
+    pub contract_id: u32,
+    pub wasm_hash: BytesN<32>,
+    pub severity: Severity,
+    pub description: String,
+    pub published_at: u64,
+    pub resolved: bool,
+    pub by_admin: bool, // Published by the registry admin rather than the author
+}
+
+/// How strongly a build was verified
+/// Anyone can give a Community verification; Audited ones come from auditors
+/// approved by the admin
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum VerificationLevel {
+    Unverified = 0,
+    Community = 1,
+    Audited = 2,
+}
+
+/// What the registry knows about a WASM hash
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashReport {
+    pub listings: Vec<u32>,              // Listings with a release of the hash
+    pub verification: VerificationLevel, // Highest verification of the hash itself
+    pub highest_severity: Severity,      // Of the unresolved hash-wide advisories
 }
 
 #[contracttype]
@@ -57,4 +211,16 @@ pub enum Error {
     InvalidMetadata = 4,
     InvalidWasmHash = 5,
     UnauthorizedVerification = 6,
+    InvalidRoyalty = 7,
+    InvalidAmount = 8,
+    InvalidDependency = 9,
+    DependencyNotFound = 10,
+    InvalidForkSource = 11,
+    InvalidSchema = 12,
+    ReleaseNotFound = 13,
+    ReleaseAlreadyExists = 14,
+    ReleaseYanked = 15,
+    ContractQuarantined = 16,
+    AdvisoryNotFound = 17,
+    TokenNotAllowed = 18,
 }
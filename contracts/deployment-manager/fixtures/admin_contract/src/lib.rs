@@ -0,0 +1,27 @@
+#![no_std]
+
+//! Contract with constructor arguments, deployed by the DeploymentManager tests.
+
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const LIMIT: Symbol = symbol_short!("LIMIT");
+
+#[contract]
+pub struct AdminContract;
+
+#[contractimpl]
+impl AdminContract {
+    pub fn __constructor(env: Env, admin: Address, limit: u32) {
+        env.storage().instance().set(&ADMIN, &admin);
+        env.storage().instance().set(&LIMIT, &limit);
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN).unwrap()
+    }
+
+    pub fn limit(env: Env) -> u32 {
+        env.storage().instance().get(&LIMIT).unwrap()
+    }
+}
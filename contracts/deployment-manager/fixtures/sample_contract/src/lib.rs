@@ -0,0 +1,16 @@
+#![no_std]
+
+//! Minimal contract deployed by the DeploymentManager tests.
+
+use soroban_sdk::{contract, contractimpl};
+
+#[contract]
+pub struct SampleContract;
+
+#[contractimpl]
+impl SampleContract {
+    /// Fixed version number, used to tell fixture builds apart
+    pub fn version() -> u32 {
+        1
+    }
+}
@@ -0,0 +1,32 @@
+#![no_std]
+
+//! Contract upgradeable by its admin, deployed by the DeploymentManager tests.
+//! The `v2` feature builds the release it is upgraded to.
+
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+
+#[contract]
+pub struct UpgradeableContract;
+
+#[contractimpl]
+impl UpgradeableContract {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN, &admin);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    pub fn version() -> u32 {
+        if cfg!(feature = "v2") {
+            2
+        } else {
+            1
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, String, Val, Vec};
+
+use crate::types::{
+    DeployPrice, DeploymentInfo, DeploymentRecord, DeploymentStatus, Error, LicenseScope,
+    PostDeployCall, UNLISTED,
+};
+use crate::{
+    license, policy, registry, royalty, schema, storage, upgrade, DeployedEvent, RoyaltyPaid,
+};
+
+/// Everything needed to deploy and record one contract instance
+pub struct DeployRequest {
+    pub contract_id: u32,
+    pub release: Option<String>,
+    pub nonce: Option<u64>,
+    pub group_id: Option<u32>,
+    pub deployer: Address,
+    pub wasm_hash: BytesN<32>,
+    pub salt: BytesN<32>,
+    pub init_args: Vec<Val>,
+    pub calls: Vec<PostDeployCall>,
+    pub payer: Option<Address>, // Pays the deploy price instead of the deployer
+    pub price: Option<DeployPrice>, // Price the caller budgeted for, instead of the registry's
+    pub from_manager: bool,     // Deploy from the manager's address instead of the deployer's
+    pub intent_id: Option<u32>,
+}
+
+impl DeployRequest {
+    /// Deploy from the deployer's own address with nothing else attached
+    /// Entry points override the fields they need with struct update syntax
+    pub fn new(
+        env: &Env,
+        contract_id: u32,
+        deployer: Address,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+    ) -> Self {
+        DeployRequest {
+            contract_id,
+            release: None,
+            nonce: None,
+            group_id: None,
+            deployer,
+            wasm_hash,
+            salt,
+            init_args,
+            calls: Vec::new(env),
+            payer: None,
+            price: None,
+            from_manager: false,
+            intent_id: None,
+        }
+    }
+}
+
+/// Deploy a contract instance and record it
+/// The caller is responsible for the deployer's authentication
+pub fn deploy(env: &Env, request: DeployRequest) -> Result<u32, Error> {
+    let DeployRequest {
+        contract_id,
+        release,
+        nonce,
+        group_id,
+        deployer,
+        wasm_hash,
+        salt,
+        init_args,
+        calls,
+        payer,
+        price,
+        from_manager,
+        intent_id,
+    } = request;
+
+    // A listing's license, price and count only apply to code it published
+    let (contract_id, release, listed) = attribute(env, contract_id, release, &wasm_hash)?;
+
+    // Licensed listings can only be deployed by license holders
+    let license = if listed && license::is_licensed(env, contract_id) {
+        Some(
+            license::find_usable_license(env, contract_id, &deployer)
+                .ok_or(Error::LicenseRequired)?,
+        )
+    } else {
+        None
+    };
+
+    // The hash must satisfy the global and the deployer's deploy policy
+    policy::check(env, &deployer, &wasm_hash)?;
+
+    // Check constructor arguments against the listing's schema
+    // A failed deploy keeps no events, so the offending index is only
+    // available through check_init_args
+    let init_args = match registry::get_constructor_schema(env, contract_id) {
+        Some(schema) => {
+            schema::resolve_args(env, &schema, &init_args).map_err(|_| Error::InvalidParameters)?
+        }
+        None => init_args,
+    };
+
+    // Fail cleanly instead of trapping when the salt was already used
+    let contract_deployer = if from_manager {
+        env.deployer().with_current_contract(salt.clone())
+    } else {
+        env.deployer().with_address(deployer.clone(), salt.clone())
+    };
+    if contract_deployer.deployed_address().exists() {
+        return Err(Error::AddressTaken);
+    }
+
+    // Deploy the contract using the Soroban deployer
+    // This creates a new contract instance from the WASM hash with constructor args
+    let deployed_address = contract_deployer.deploy_v2(wasm_hash.clone(), init_args.clone());
+
+    // Initialize and smoke-test the instance before it is recorded
+    run_calls(env, &deployed_address, &calls)?;
+
+    // Generate deployment ID
+    let deployment_id = storage::increment_counter(env);
+
+    // Per-instance licenses are used up by this deployment
+    if let Some(mut license) = license {
+        if license.scope == LicenseScope::PerInstance {
+            license.deployment_id = Some(deployment_id);
+            storage::save_license(env, &license);
+        }
+    }
+
+    // Create deployment record
+    let record = DeploymentRecord {
+        deployment_id,
+        contract_id,
+        deployer: deployer.clone(),
+        deployed_contract_address: deployed_address.clone(),
+        deployed_at: env.ledger().timestamp(),
+        wasm_hash,
+        salt,
+        release,
+        nonce,
+        group_id,
+        init_args,
+        managed: false,
+        upgrades: Vec::new(env),
+        info: DeploymentInfo::empty(env),
+        owner: deployer.clone(),
+        status: DeploymentStatus::Active,
+        hidden: false,
+        intent_id,
+    };
+
+    // Save to storage
+    storage::save_deployment(env, deployment_id, &record);
+    storage::add_to_all_deployments(env, deployment_id);
+    storage::add_to_deployer(env, &deployer, deployment_id);
+    storage::add_to_owner(env, &deployer, deployment_id);
+    storage::add_to_contract_deployments(env, contract_id, deployment_id);
+    storage::set_deployment_address(env, &deployed_address, deployment_id);
+    storage::add_to_hash_deployments(env, &record.wasm_hash, deployment_id);
+    upgrade::observe(env, deployment_id, &record.wasm_hash, &record.release);
+    if let Some(release) = &record.release {
+        storage::add_to_release_deployments(env, contract_id, release, deployment_id);
+    }
+    if let Some(group_id) = group_id {
+        storage::add_to_group(env, group_id, deployment_id);
+    }
+
+    // Only deployments of a listing's own code are counted and paid for
+    let amount = if !listed {
+        0
+    } else {
+        // Keep the registry's deployment count in sync
+        registry::increment_deployment_count(env, contract_id)?;
+
+        // Pay the author's deploy price, if any
+        match price.or_else(|| registry::get_deploy_price(env, contract_id)) {
+            Some(price) => royalty::pay_deploy_price(
+                env,
+                contract_id,
+                payer.as_ref().unwrap_or(&deployer),
+                &price,
+            ),
+            None => 0,
+        }
+    };
+
+    // Emit events
+    DeployedEvent {
+        deployment_id,
+        deployed_address,
+    }
+    .publish(env);
+
+    if amount > 0 {
+        RoyaltyPaid {
+            deployment_id,
+            contract_id,
+            amount,
+        }
+        .publish(env);
+    }
+
+    Ok(deployment_id)
+}
+
+/// Work out which listing release a deployment belongs to
+/// Returns the contract_id and release to record and whether the listing
+/// published the code; deployments by hash get the release that published it
+/// Hashes a known listing never released are recorded as UNLISTED; ids the
+/// registry doesn't know are kept as given but not counted or charged
+/// Releases found by hash must still be deployable: yanked releases and
+/// quarantined listings fail as they do when deployed by version
+fn attribute(
+    env: &Env,
+    contract_id: u32,
+    release: Option<String>,
+    wasm_hash: &BytesN<32>,
+) -> Result<(u32, Option<String>, bool), Error> {
+    // Releases were resolved to this hash through the registry
+    if contract_id == UNLISTED || release.is_some() {
+        return Ok((contract_id, release, contract_id != UNLISTED));
+    }
+    if let Some(release) = registry::find_release_by_hash(env, contract_id, wasm_hash) {
+        registry::resolve_release(env, contract_id, &release)?;
+        return Ok((contract_id, Some(release), true));
+    }
+    match registry::get_payout_terms(env, contract_id) {
+        Ok(_) => Ok((UNLISTED, None, false)),
+        Err(_) => Ok((contract_id, None, false)),
+    }
+}
+
+/// Salt for a deployer's `nonce`-th manager-derived deployment of a listing
+pub fn derive_salt(env: &Env, deployer: &Address, contract_id: u32, nonce: u64) -> BytesN<32> {
+    let preimage = (deployer.clone(), contract_id, nonce).to_xdr(env);
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Compute the salt an executed intent deploys with
+/// Intents deploy from the manager's address, so mixing in the proposer keeps
+/// one proposer from claiming the address another proposer's intent asked for
+pub fn derive_intent_salt(env: &Env, proposer: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let preimage = (proposer.clone(), salt.clone()).to_xdr(env);
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Take the deployer's next nonce whose derived address is still free
+/// Nonces whose address was claimed with a caller-provided salt are skipped
+pub fn next_salt(env: &Env, deployer: &Address, contract_id: u32) -> (u64, BytesN<32>) {
+    let mut nonce = storage::get_deployer_nonce(env, deployer);
+    loop {
+        let salt = derive_salt(env, deployer, contract_id, nonce);
+        let taken = env
+            .deployer()
+            .with_address(deployer.clone(), salt.clone())
+            .deployed_address()
+            .exists();
+        nonce += 1;
+        if !taken {
+            storage::set_deployer_nonce(env, deployer, nonce);
+            return (nonce - 1, salt);
+        }
+    }
+}
+
+/// Invoke each call on the new instance, checking expected return values
+/// Any failure aborts the deployment
+fn run_calls(env: &Env, address: &Address, calls: &Vec<PostDeployCall>) -> Result<(), Error> {
+    for call in calls.iter() {
+        let result = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            address,
+            &call.function,
+            call.args.clone(),
+        );
+        let value = match result {
+            Ok(Ok(value)) => value,
+            _ => return Err(Error::PostDeployCallFailed),
+        };
+
+        if let Some(expected) = call.expected.get(0) {
+            // Compare by encoding, since object values are only equal by handle
+            if value.to_xdr(env) != expected.to_xdr(env) {
+                return Err(Error::PostDeployCheckFailed);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::storage;
+use crate::types::{KeepAlive, KeepAliveConfig};
+
+/// Whether an instance last extended at `extended_at` has fewer than
+/// `threshold` ledgers of TTL left
+pub fn is_due(env: &Env, config: &KeepAliveConfig, keep_alive: &KeepAlive) -> bool {
+    let expires_at = keep_alive.extended_at.saturating_add(config.extend_to);
+    env.ledger().sequence().saturating_add(config.threshold) >= expires_at
+}
+
+/// Check up to `limit` pool entries starting at index `start`, extending the
+/// due, funded instances and paying `caller` the fee for each from the
+/// instance's deposit
+/// Returns the number of instances extended
+pub fn extend_due(
+    env: &Env,
+    config: &KeepAliveConfig,
+    caller: &Address,
+    start: u32,
+    limit: u32,
+) -> u32 {
+    let client = token::Client::new(env, &config.token);
+    let end = start
+        .saturating_add(limit)
+        .min(storage::get_keep_alive_pool_size(env));
+    let mut extended = 0;
+
+    for index in start..end {
+        let deployment_id = match storage::get_keep_alive_pool_entry(env, index) {
+            Some(deployment_id) => deployment_id,
+            None => continue,
+        };
+        let mut keep_alive = match storage::get_keep_alive(env, deployment_id) {
+            Some(keep_alive) => keep_alive,
+            None => continue,
+        };
+        if keep_alive.balance < config.fee || !is_due(env, config, &keep_alive) {
+            continue;
+        }
+        let record = match storage::get_deployment(env, deployment_id) {
+            Some(record) => record,
+            None => continue,
+        };
+
+        // Extends both the instance and its code
+        env.deployer().extend_ttl(
+            record.deployed_contract_address,
+            config.threshold,
+            config.extend_to,
+        );
+
+        keep_alive.balance -= config.fee;
+        keep_alive.extended_at = env.ledger().sequence();
+        storage::set_keep_alive(env, deployment_id, &keep_alive);
+        if config.fee > 0 {
+            client.transfer(&env.current_contract_address(), caller, &config.fee);
+        }
+
+        extended += 1;
+    }
+
+    extended
+}
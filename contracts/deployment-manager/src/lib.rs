@@ -1,11 +1,35 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, BytesN, Env, Val, Vec};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, token, Address, Bytes, BytesN, Env, IntoVal, String,
+    Val, Vec,
+};
 
+mod deploy;
+mod keep_alive;
+mod license;
+mod policy;
+mod registry;
+mod royalty;
+mod schema;
 mod storage;
 mod types;
+mod upgrade;
 
-pub use types::{DeploymentRecord, Error};
+pub use types::{
+    BatchStep, ConstructorParam, DeployPolicy, DeployPrice, DeploymentEnvironment, DeploymentInfo,
+    DeploymentIntent, DeploymentRecord, DeploymentStatus, DeploymentTemplate, Error, IntentParams,
+    KeepAlive, KeepAliveConfig, License, LicenseOffer, LicenseScope, ObservedHash, ParamType,
+    PayoutTerms, PlatformFee, PostDeployCall, RevenueShare, SaltStrategy, Severity,
+    SponsoredDeployment, Sponsorship, SponsorshipParams, StepLink, TemplateParams, UpgradeEntry,
+    VerificationLevel, UNLISTED,
+};
+
+use deploy::DeployRequest;
+use types::{
+    MAX_INTENT_APPROVERS, MAX_LABELS, MAX_LABEL_LEN, MAX_NOTE_LEN, MAX_PROJECT_LEN,
+    MAX_SPONSORED_DEPLOYERS,
+};
 
 #[contractevent]
 pub struct DeployedEvent {
@@ -13,11 +37,171 @@ pub struct DeployedEvent {
     pub deployed_address: Address,
 }
 
+#[contractevent]
+pub struct RoyaltyPaid {
+    pub deployment_id: u32,
+    pub contract_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct InstanceUpgraded {
+    pub deployment_id: u32,
+    pub wasm_hash: BytesN<32>,
+    pub rollback: bool,
+}
+
+#[contractevent]
+pub struct DeploymentSynced {
+    pub deployment_id: u32,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct DeploymentStatusChanged {
+    pub deployment_id: u32,
+    pub status: DeploymentStatus,
+}
+
+#[contractevent]
+pub struct DeploymentVisibilityChanged {
+    pub deployment_id: u32,
+    pub hidden: bool,
+}
+
+#[contractevent]
+pub struct DeploymentTransferred {
+    pub deployment_id: u32,
+    pub from: Address,
+    pub to: Address,
+}
+
+#[contractevent]
+pub struct IntentProposed {
+    pub intent_id: u32,
+    pub proposer: Address,
+}
+
+#[contractevent]
+pub struct IntentApproved {
+    pub intent_id: u32,
+    pub approver: Address,
+}
+
+#[contractevent]
+pub struct IntentExecuted {
+    pub intent_id: u32,
+    pub deployment_id: u32,
+}
+
+#[contractevent]
+pub struct SponsorshipFunded {
+    pub sponsorship_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SponsoredDeploy {
+    pub sponsorship_id: u32,
+    pub deployment_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct LicenseOfferCreated {
+    pub offer_id: u32,
+    pub contract_id: u32,
+}
+
+#[contractevent]
+pub struct LicensePurchased {
+    pub license_id: u32,
+    pub offer_id: u32,
+    pub holder: Address,
+}
+
 #[contract]
 pub struct DeploymentManager;
 
 #[contractimpl]
 impl DeploymentManager {
+    /// Configure the admin and the ContractRegistry this manager deploys from
+    /// The registry's admin must then name this manager with set_deployment_manager;
+    /// until then deployments of a listing's published code fail with UnauthorizedAccess
+    pub fn __constructor(env: Env, admin: Address, registry: Address) {
+        storage::set_admin(&env, &admin);
+        storage::set_registry(&env, &registry);
+    }
+
+    /// Set the platform cut taken from every deploy price (admin only)
+    pub fn set_platform_fee(env: Env, recipient: Address, fee_bps: u32) -> Result<(), Error> {
+        storage::get_admin(&env).require_auth();
+
+        if fee_bps > royalty::MAX_BPS {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_platform_fee(&env, &PlatformFee { recipient, fee_bps });
+
+        Ok(())
+    }
+
+    /// Get the platform fee, if one is configured
+    pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+        storage::get_platform_fee(&env)
+    }
+
+    /// Configure the keep-alive pool (admin only)
+    /// The deposit token can't change once set, since deposits are held in it
+    pub fn set_keep_alive_config(env: Env, config: KeepAliveConfig) -> Result<(), Error> {
+        storage::get_admin(&env).require_auth();
+
+        if config.fee < 0 || config.threshold >= config.extend_to {
+            return Err(Error::InvalidParameters);
+        }
+        if let Some(current) = storage::get_keep_alive_config(&env) {
+            if current.token != config.token {
+                return Err(Error::InvalidParameters);
+            }
+        }
+
+        storage::set_keep_alive_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the keep-alive pool terms, if configured
+    pub fn get_keep_alive_config(env: Env) -> Option<KeepAliveConfig> {
+        storage::get_keep_alive_config(&env)
+    }
+
+    /// Set or clear the policy every deployment must meet (admin only)
+    pub fn set_deploy_policy(env: Env, policy: Option<DeployPolicy>) {
+        storage::get_admin(&env).require_auth();
+        storage::set_global_policy(&env, &policy);
+    }
+
+    /// Get the policy every deployment must meet, if set
+    pub fn get_deploy_policy(env: Env) -> Option<DeployPolicy> {
+        storage::get_global_policy(&env)
+    }
+
+    /// Set or clear a deployer's own policy, applied on top of the global one
+    pub fn set_deployer_policy(env: Env, deployer: Address, policy: Option<DeployPolicy>) {
+        deployer.require_auth();
+        storage::set_deployer_policy(&env, &deployer, &policy);
+    }
+
+    /// Get a deployer's own policy, if set
+    pub fn get_deployer_policy(env: Env, deployer: Address) -> Option<DeployPolicy> {
+        storage::get_deployer_policy(&env, &deployer)
+    }
+
+    /// Get the ContractRegistry address
+    pub fn get_registry(env: Env) -> Address {
+        storage::get_registry(&env)
+    }
+
     /// Deploy a contract with an admin address constructor parameter
     /// This is a convenience method for the common pattern of contracts with admin initialization
     pub fn deploy_with_admin(
@@ -39,6 +223,13 @@ impl DeploymentManager {
     /// Deploy a contract from the registry
     /// This deploys a new contract instance on-chain from the WASM hash
     /// init_args: Constructor arguments for the contract (empty vec if no constructor)
+    /// If the listing has a constructor schema, init_args are checked against it and
+    /// omitted trailing arguments take their defaults; mismatches fail with
+    /// InvalidParameters
+    /// If the author set a deploy price it is charged to the deployer in the same transaction
+    /// Only a hash the listing released is licensed, charged and counted against it;
+    /// other hashes are recorded as UNLISTED, and listing ids unknown to the registry
+    /// are kept on the record without being counted
     pub fn deploy_from_wasm(
         env: Env,
         contract_id: u32,
@@ -50,65 +241,172 @@ impl DeploymentManager {
         // Require authentication from deployer
         deployer.require_auth();
 
-        // Deploy the contract using the Soroban deployer
-        // This creates a new contract instance from the WASM hash with constructor args
-        let deployed_address = env
-            .deployer()
-            .with_address(deployer.clone(), salt.clone())
-            .deploy_v2(wasm_hash.clone(), init_args);
+        deploy::deploy(
+            &env,
+            DeployRequest::new(&env, contract_id, deployer, wasm_hash, salt, init_args),
+        )
+    }
 
-        // Generate deployment ID
-        let deployment_id = storage::increment_counter(&env);
+    /// Upload WASM and deploy it without publishing it to the registry
+    /// The record's contract_id is UNLISTED and keeps the uploaded hash; unlisted
+    /// deployments are listed by get_contract_deployments(UNLISTED)
+    pub fn deploy_from_bytes(
+        env: Env,
+        deployer: Address,
+        wasm: Bytes,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
 
-        // Create deployment record
-        let record = DeploymentRecord {
-            deployment_id,
-            contract_id,
-            deployer: deployer.clone(),
-            deployed_contract_address: deployed_address.clone(),
-            deployed_at: env.ledger().timestamp(),
-            wasm_hash,
-            salt,
-        };
+        let wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
-        // Save to storage
-        storage::save_deployment(&env, deployment_id, &record);
-        storage::add_to_all_deployments(&env, deployment_id);
-        storage::add_to_deployer(&env, &deployer, deployment_id);
-        storage::add_to_contract_deployments(&env, contract_id, deployment_id);
+        deploy::deploy(
+            &env,
+            DeployRequest::new(&env, UNLISTED, deployer, wasm_hash, salt, init_args),
+        )
+    }
 
-        // Emit event
-        DeployedEvent {
-            deployment_id,
-            deployed_address,
-        }
-        .publish(&env);
+    /// Deploy a release of a registry listing
+    /// The WASM hash is resolved through the ContractRegistry, so the record is
+    /// guaranteed to match the listing's published code
+    /// Unknown, yanked and quarantined releases are rejected
+    pub fn deploy_from_registry(
+        env: Env,
+        contract_id: u32,
+        release: String,
+        deployer: Address,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
 
-        Ok(deployment_id)
+        let wasm_hash = registry::resolve_release(&env, contract_id, &release)?;
+
+        deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(release),
+                ..DeployRequest::new(&env, contract_id, deployer, wasm_hash, salt, init_args)
+            },
+        )
     }
 
-    /// Get a specific deployment record
-    pub fn get_deployment(env: Env, deployment_id: u32) -> Result<DeploymentRecord, Error> {
-        storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)
+    /// Deploy a release of a registry listing and invoke functions on the new instance
+    /// Calls run in order in the same transaction, e.g. a legacy `initialize` followed
+    /// by read-only health checks
+    /// If a call fails or returns something other than its expected value the
+    /// deployment is aborted
+    pub fn deploy_and_call(
+        env: Env,
+        contract_id: u32,
+        release: String,
+        deployer: Address,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+        calls: Vec<PostDeployCall>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
+
+        let wasm_hash = registry::resolve_release(&env, contract_id, &release)?;
+
+        deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(release),
+                calls,
+                ..DeployRequest::new(&env, contract_id, deployer, wasm_hash, salt, init_args)
+            },
+        )
     }
 
-    /// Get deployment history for a specific deployer
-    pub fn get_deployment_history(env: Env, deployer: Address) -> Vec<DeploymentRecord> {
-        let deployment_ids = storage::get_deployer_deployments(&env, &deployer);
-        let mut records = Vec::new(&env);
+    /// Deploy a release of a registry listing with a salt derived by the manager
+    /// The salt is the SHA-256 of (deployer, contract_id, nonce), where nonce is the
+    /// deployer's next unused nonce, so repeated deployments never collide
+    /// The nonce is stored on the record so the salt can be reproduced with derive_salt
+    pub fn deploy_next(
+        env: Env,
+        contract_id: u32,
+        release: String,
+        deployer: Address,
+        init_args: Vec<Val>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
 
-        for id in deployment_ids.iter() {
-            if let Some(record) = storage::get_deployment(&env, id) {
-                records.push_back(record);
+        let wasm_hash = registry::resolve_release(&env, contract_id, &release)?;
+        let (nonce, salt) = deploy::next_salt(&env, &deployer, contract_id);
+
+        deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(release),
+                nonce: Some(nonce),
+                ..DeployRequest::new(&env, contract_id, deployer, wasm_hash, salt, init_args)
+            },
+        )
+    }
+
+    /// Deploy several listing releases in order as one group
+    /// Each step may replace constructor arguments with addresses deployed by
+    /// earlier steps, e.g. a vault taking the address of the token deployed before it
+    /// If any step fails the whole batch is rolled back
+    /// Returns the group id linking the resulting deployment records
+    pub fn deploy_batch(env: Env, deployer: Address, steps: Vec<BatchStep>) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
+
+        if steps.is_empty() {
+            return Err(Error::InvalidParameters);
+        }
+
+        let group_id = storage::increment_group_counter(&env);
+        let mut addresses: Vec<Address> = Vec::new(&env);
+
+        for step in steps.iter() {
+            let mut init_args = step.init_args.clone();
+            for link in step.links.iter() {
+                // Links can only point back to steps that already ran
+                let address = addresses.get(link.step).ok_or(Error::InvalidParameters)?;
+                if link.arg_index >= init_args.len() {
+                    return Err(Error::InvalidParameters);
+                }
+                init_args.set(link.arg_index, address.into_val(&env));
             }
+
+            let wasm_hash = registry::resolve_release(&env, step.contract_id, &step.release)?;
+            let (nonce, salt) = deploy::next_salt(&env, &deployer, step.contract_id);
+
+            let deployment_id = deploy::deploy(
+                &env,
+                DeployRequest {
+                    release: Some(step.release),
+                    nonce: Some(nonce),
+                    group_id: Some(group_id),
+                    ..DeployRequest::new(
+                        &env,
+                        step.contract_id,
+                        deployer.clone(),
+                        wasm_hash,
+                        salt,
+                        init_args,
+                    )
+                },
+            )?;
+
+            let record = storage::get_deployment(&env, deployment_id).unwrap();
+            addresses.push_back(record.deployed_contract_address);
         }
 
-        records
+        Ok(group_id)
     }
 
-    /// Get all deployments of a specific contract
-    pub fn get_contract_deployments(env: Env, contract_id: u32) -> Vec<DeploymentRecord> {
-        let deployment_ids = storage::get_contract_deployments(&env, contract_id);
+    /// Get the deployments of a batch, in step order
+    pub fn get_group_deployments(env: Env, group_id: u32) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_group_deployments(&env, group_id);
         let mut records = Vec::new(&env);
 
         for id in deployment_ids.iter() {
@@ -120,23 +418,1182 @@ impl DeploymentManager {
         records
     }
 
-    /// Get all deployment records
-    pub fn get_all_deployments(env: Env) -> Vec<DeploymentRecord> {
-        let deployment_ids = storage::get_all_deployment_ids(&env);
-        let mut records = Vec::new(&env);
+    /// Save a deployment template for a listing release
+    /// Templates saved by the listing's author are marked as recommended
+    pub fn create_template(env: Env, owner: Address, params: TemplateParams) -> Result<u32, Error> {
+        owner.require_auth();
 
-        for id in deployment_ids.iter() {
-            if let Some(record) = storage::get_deployment(&env, id) {
-                records.push_back(record);
+        // The release must be deployable when the template is saved
+        registry::resolve_release(&env, params.contract_id, &params.release)?;
+        let recommended = registry::get_author(&env, params.contract_id)? == owner;
+
+        let template_id = storage::increment_template_counter(&env);
+        let template = DeploymentTemplate {
+            template_id,
+            owner: owner.clone(),
+            name: params.name,
+            contract_id: params.contract_id,
+            release: params.release,
+            init_args: params.init_args,
+            salt_strategy: params.salt_strategy,
+            recommended,
+        };
+
+        storage::save_template(&env, &template);
+        storage::add_to_owner_templates(&env, &owner, template_id);
+        if recommended {
+            storage::add_to_recommended(&env, template.contract_id, template_id);
+        }
+
+        Ok(template_id)
+    }
+
+    /// Replace a template's parameters (template owner only)
+    pub fn update_template(
+        env: Env,
+        template_id: u32,
+        params: TemplateParams,
+    ) -> Result<(), Error> {
+        let template = storage::get_template(&env, template_id).ok_or(Error::TemplateNotFound)?;
+        template.owner.require_auth();
+
+        registry::resolve_release(&env, params.contract_id, &params.release)?;
+        let recommended = registry::get_author(&env, params.contract_id)? == template.owner;
+
+        if template.recommended {
+            storage::remove_from_recommended(&env, template.contract_id, template_id);
+        }
+        if recommended {
+            storage::add_to_recommended(&env, params.contract_id, template_id);
+        }
+
+        storage::save_template(
+            &env,
+            &DeploymentTemplate {
+                name: params.name,
+                contract_id: params.contract_id,
+                release: params.release,
+                init_args: params.init_args,
+                salt_strategy: params.salt_strategy,
+                recommended,
+                ..template
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Delete a template (template owner only)
+    pub fn delete_template(env: Env, template_id: u32) -> Result<(), Error> {
+        let template = storage::get_template(&env, template_id).ok_or(Error::TemplateNotFound)?;
+        template.owner.require_auth();
+
+        storage::remove_template(&env, template_id);
+        storage::remove_from_owner_templates(&env, &template.owner, template_id);
+        if template.recommended {
+            storage::remove_from_recommended(&env, template.contract_id, template_id);
+        }
+
+        Ok(())
+    }
+
+    /// Deploy from a saved template
+    /// salt: Required for templates with a provided salt strategy, omitted for derived salts
+    pub fn deploy_from_template(
+        env: Env,
+        template_id: u32,
+        deployer: Address,
+        salt: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
+
+        let template = storage::get_template(&env, template_id).ok_or(Error::TemplateNotFound)?;
+        let wasm_hash = registry::resolve_release(&env, template.contract_id, &template.release)?;
+
+        let (nonce, salt) = match (template.salt_strategy, salt) {
+            (SaltStrategy::Derived, None) => {
+                let (nonce, salt) = deploy::next_salt(&env, &deployer, template.contract_id);
+                (Some(nonce), salt)
+            }
+            (SaltStrategy::Provided, Some(salt)) => (None, salt),
+            _ => return Err(Error::InvalidParameters),
+        };
+
+        deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(template.release),
+                nonce,
+                ..DeployRequest::new(
+                    &env,
+                    template.contract_id,
+                    deployer,
+                    wasm_hash,
+                    salt,
+                    template.init_args,
+                )
+            },
+        )
+    }
+
+    /// Get a specific template
+    pub fn get_template(env: Env, template_id: u32) -> Result<DeploymentTemplate, Error> {
+        storage::get_template(&env, template_id).ok_or(Error::TemplateNotFound)
+    }
+
+    /// Get all templates saved by an owner
+    pub fn get_templates(env: Env, owner: Address) -> Vec<DeploymentTemplate> {
+        let template_ids = storage::get_owner_templates(&env, &owner);
+        let mut templates = Vec::new(&env);
+
+        for id in template_ids.iter() {
+            if let Some(template) = storage::get_template(&env, id) {
+                templates.push_back(template);
             }
         }
 
-        records
+        templates
     }
 
-    /// Get total number of deployments
-    pub fn get_total_deployments(env: Env) -> u32 {
-        storage::get_counter(&env)
+    /// Get the templates a listing's author recommends
+    pub fn get_recommended_templates(env: Env, contract_id: u32) -> Vec<DeploymentTemplate> {
+        let template_ids = storage::get_recommended_templates(&env, contract_id);
+        let mut templates = Vec::new(&env);
+
+        for id in template_ids.iter() {
+            if let Some(template) = storage::get_template(&env, id) {
+                templates.push_back(template);
+            }
+        }
+
+        templates
+    }
+
+    /// Propose a deployment that needs sign-off before it runs
+    /// Executed intents deploy from the manager's own address, so the instance
+    /// address is predict_address(manager address, derive_intent_salt(proposer, salt));
+    /// the proposer is recorded as the deployer and owner and can't approve
+    pub fn propose_intent(env: Env, proposer: Address, params: IntentParams) -> Result<u32, Error> {
+        proposer.require_auth();
+
+        if params.approvers.is_empty()
+            || params.approvers.contains(&proposer)
+            || params.approvers.len() > MAX_INTENT_APPROVERS
+            || params.threshold == 0
+            || params.threshold > params.approvers.len()
+            || params.deadline <= env.ledger().timestamp()
+        {
+            return Err(Error::InvalidParameters);
+        }
+        for (index, approver) in params.approvers.iter().enumerate() {
+            if params.approvers.first_index_of(&approver) != Some(index as u32) {
+                return Err(Error::InvalidParameters);
+            }
+        }
+        registry::resolve_release(&env, params.contract_id, &params.release)?;
+
+        let intent_id = storage::increment_intent_counter(&env);
+        let intent = DeploymentIntent {
+            intent_id,
+            proposer: proposer.clone(),
+            contract_id: params.contract_id,
+            release: params.release,
+            init_args: params.init_args,
+            salt: params.salt,
+            approvers: params.approvers,
+            threshold: params.threshold,
+            deadline: params.deadline,
+            approvals: Vec::new(&env),
+            deployment_id: None,
+        };
+        storage::save_intent(&env, &intent);
+
+        // Emit event
+        IntentProposed {
+            intent_id,
+            proposer,
+        }
+        .publish(&env);
+
+        Ok(intent_id)
+    }
+
+    /// Approve a pending intent (designated approvers only)
+    pub fn approve_intent(env: Env, approver: Address, intent_id: u32) -> Result<(), Error> {
+        approver.require_auth();
+
+        let mut intent = storage::get_intent(&env, intent_id).ok_or(Error::IntentNotFound)?;
+        if intent.deployment_id.is_some() || intent.approvals.contains(&approver) {
+            return Err(Error::InvalidParameters);
+        }
+        if env.ledger().timestamp() > intent.deadline {
+            return Err(Error::IntentExpired);
+        }
+        if !intent.approvers.contains(&approver) {
+            return Err(Error::UnauthorizedAccess);
+        }
+
+        intent.approvals.push_back(approver.clone());
+        storage::save_intent(&env, &intent);
+
+        // Emit event
+        IntentApproved {
+            intent_id,
+            approver,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Execute an intent once enough approvers signed off (anyone can call)
+    /// The executor pays the listing's deploy price, if any
+    pub fn execute_intent(env: Env, executor: Address, intent_id: u32) -> Result<u32, Error> {
+        executor.require_auth();
+
+        let mut intent = storage::get_intent(&env, intent_id).ok_or(Error::IntentNotFound)?;
+        if intent.deployment_id.is_some() {
+            return Err(Error::InvalidParameters);
+        }
+        if env.ledger().timestamp() > intent.deadline {
+            return Err(Error::IntentExpired);
+        }
+        if intent.approvals.len() < intent.threshold {
+            return Err(Error::IntentNotApproved);
+        }
+
+        let wasm_hash = registry::resolve_release(&env, intent.contract_id, &intent.release)?;
+
+        let deployment_id = deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(intent.release.clone()),
+                payer: Some(executor),
+                from_manager: true,
+                intent_id: Some(intent_id),
+                ..DeployRequest::new(
+                    &env,
+                    intent.contract_id,
+                    intent.proposer.clone(),
+                    wasm_hash,
+                    deploy::derive_intent_salt(&env, &intent.proposer, &intent.salt),
+                    intent.init_args.clone(),
+                )
+            },
+        )?;
+
+        intent.deployment_id = Some(deployment_id);
+        storage::save_intent(&env, &intent);
+
+        // Emit event
+        IntentExecuted {
+            intent_id,
+            deployment_id,
+        }
+        .publish(&env);
+
+        Ok(deployment_id)
+    }
+
+    /// Get a specific deployment intent
+    pub fn get_intent(env: Env, intent_id: u32) -> Result<DeploymentIntent, Error> {
+        storage::get_intent(&env, intent_id).ok_or(Error::IntentNotFound)
+    }
+
+    /// Get the nonce the deployer's next deploy_next call will start from
+    pub fn get_deployer_nonce(env: Env, deployer: Address) -> u64 {
+        storage::get_deployer_nonce(&env, &deployer)
+    }
+
+    /// Compute the salt deploy_next uses for a deployer, listing and nonce
+    pub fn derive_salt(env: Env, deployer: Address, contract_id: u32, nonce: u64) -> BytesN<32> {
+        deploy::derive_salt(&env, &deployer, contract_id, nonce)
+    }
+
+    /// Compute the salt an intent by `proposer` with `salt` deploys with
+    pub fn derive_intent_salt(env: Env, proposer: Address, salt: BytesN<32>) -> BytesN<32> {
+        deploy::derive_intent_salt(&env, &proposer, &salt)
+    }
+
+    /// Deploy an identical instance of an earlier deployment (original deployer only)
+    /// The recorded WASM hash and constructor arguments are reused with a new salt
+    /// Deployments of a registry release are rejected once it is yanked or quarantined
+    pub fn redeploy(env: Env, deployment_id: u32, new_salt: BytesN<32>) -> Result<u32, Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.deployer.require_auth();
+
+        if let Some(release) = &record.release {
+            registry::resolve_release(&env, record.contract_id, release)?;
+        }
+
+        deploy::deploy(
+            &env,
+            DeployRequest {
+                release: record.release,
+                ..DeployRequest::new(
+                    &env,
+                    record.contract_id,
+                    record.deployer,
+                    record.wasm_hash,
+                    new_salt,
+                    record.init_args,
+                )
+            },
+        )
+    }
+
+    /// Opt an instance in or out of managed upgrades (record owner only)
+    /// The instance must expose `upgrade(new_wasm_hash)` and accept this manager as
+    /// its upgrade authority, e.g. by being deployed with the manager as its admin
+    pub fn set_managed_upgrades(env: Env, deployment_id: u32, managed: bool) -> Result<(), Error> {
+        let mut record =
+            storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        record.managed = managed;
+        storage::save_deployment(&env, deployment_id, &record);
+
+        Ok(())
+    }
+
+    /// Upgrade a managed instance to a newer release of its listing (record owner only)
+    /// The release is resolved through the registry, so yanked and quarantined
+    /// releases are rejected, and must meet the global and the owner's deploy policy
+    /// Releases published no later than the current one fail with ReleaseNotNewer;
+    /// use rollback_deployment to undo an upgrade
+    pub fn upgrade_deployment(env: Env, deployment_id: u32, release: String) -> Result<(), Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        let wasm_hash = registry::resolve_release(&env, record.contract_id, &release)?;
+        if let Some(current) = &record.release {
+            if !registry::is_later_release(&env, record.contract_id, current, &release) {
+                return Err(Error::ReleaseNotNewer);
+            }
+        }
+        policy::check(&env, &record.owner, &wasm_hash)?;
+        upgrade::apply(&env, record, wasm_hash.clone(), Some(release), false)?;
+
+        InstanceUpgraded {
+            deployment_id,
+            wasm_hash,
+            rollback: false,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Undo the most recent upgrade of a managed instance (record owner only)
+    /// Only upgrades can be rolled back, not earlier rollbacks, and the restored
+    /// code must meet the global and the owner's deploy policy
+    /// Fails if the instance no longer runs the code that upgrade installed,
+    /// e.g. after an upgrade made outside the manager
+    pub fn rollback_deployment(env: Env, deployment_id: u32) -> Result<(), Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        let last = record.upgrades.last().ok_or(Error::InvalidParameters)?;
+        if last.rollback || upgrade::current_hash(&record)? != last.to_hash {
+            return Err(Error::InvalidParameters);
+        }
+        policy::check(&env, &record.owner, &last.from_hash)?;
+
+        let wasm_hash = last.from_hash;
+        upgrade::apply(&env, record, wasm_hash.clone(), last.from_release, true)?;
+
+        InstanceUpgraded {
+            deployment_id,
+            wasm_hash,
+            rollback: true,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Update a record to the code its instance currently runs
+    /// Catches upgrades made outside the manager; anyone can call it
+    /// Changes are kept in the observed hash history, with the release of the
+    /// record's listing that published the new hash, if any
+    /// Returns the current WASM hash
+    pub fn sync_deployment(env: Env, deployment_id: u32) -> Result<BytesN<32>, Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        upgrade::sync(&env, record)
+    }
+
+    /// Get the code a deployment has run, oldest first: the deployed hash,
+    /// then every managed upgrade or rollback and change seen by sync_deployment
+    pub fn get_observed_hashes(env: Env, deployment_id: u32) -> Vec<ObservedHash> {
+        storage::get_observed_hashes(&env, deployment_id)
+    }
+
+    /// Get the address a deployment by `deployer` with `salt` will have
+    /// Uses the same derivation as the deploy functions
+    pub fn predict_address(env: Env, deployer: Address, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_address(deployer, salt)
+            .deployed_address()
+    }
+
+    /// Check whether a contract already exists at the address for `deployer` and `salt`
+    /// Deploying with a taken salt fails with AddressTaken
+    pub fn is_address_taken(env: Env, deployer: Address, salt: BytesN<32>) -> bool {
+        Self::predict_address(env, deployer, salt).exists()
+    }
+
+    /// Check constructor arguments against a listing's schema without deploying
+    /// Returns the index of the first invalid argument, or None if they would be accepted
+    /// Every deploy entry point applies the same check but only reports InvalidParameters
+    pub fn check_init_args(env: Env, contract_id: u32, init_args: Vec<Val>) -> Option<u32> {
+        let schema = registry::get_constructor_schema(&env, contract_id)?;
+        schema::resolve_args(&env, &schema, &init_args).err()
+    }
+
+    /// Get a specific deployment record
+    pub fn get_deployment(env: Env, deployment_id: u32) -> Result<DeploymentRecord, Error> {
+        storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)
+    }
+
+    /// Get the record of the deployment that created a contract address
+    /// Fails with ContractNotFound for addresses not deployed through this manager
+    pub fn get_deployment_by_address(
+        env: Env,
+        address: Address,
+    ) -> Result<DeploymentRecord, Error> {
+        let deployment_id =
+            storage::get_deployment_id_by_address(&env, &address).ok_or(Error::ContractNotFound)?;
+        storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)
+    }
+
+    /// Get deployment history for a specific deployer
+    /// Hidden records are left out of this and the other public listings
+    pub fn get_deployment_history(env: Env, deployer: Address) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_deployer_deployments(&env, &deployer);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                if !record.hidden {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Set the labels, environment, note and project of a deployment (record owner only)
+    pub fn set_deployment_info(
+        env: Env,
+        deployment_id: u32,
+        info: DeploymentInfo,
+    ) -> Result<(), Error> {
+        let mut record =
+            storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        if info.labels.len() > MAX_LABELS
+            || info.note.len() > MAX_NOTE_LEN
+            || info.project.len() > MAX_PROJECT_LEN
+        {
+            return Err(Error::InvalidParameters);
+        }
+        for label in info.labels.iter() {
+            if label.is_empty() || label.len() > MAX_LABEL_LEN {
+                return Err(Error::InvalidParameters);
+            }
+        }
+
+        record.info = info;
+        storage::save_deployment(&env, deployment_id, &record);
+
+        Ok(())
+    }
+
+    /// Get an owner's deployments that belong to a project, including hidden ones
+    pub fn get_project_deployments(
+        env: Env,
+        owner: Address,
+        project: String,
+    ) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_owner_deployments(&env, &owner);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                if record.info.project == project {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Get an owner's deployments tagged with an environment, including hidden ones
+    pub fn get_environment_deployments(
+        env: Env,
+        owner: Address,
+        environment: DeploymentEnvironment,
+    ) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_owner_deployments(&env, &owner);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                if record.info.environment == environment {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Move a deployment to another lifecycle stage (record owner only)
+    /// Decommissioned records can't change stage again
+    pub fn set_deployment_status(
+        env: Env,
+        deployment_id: u32,
+        status: DeploymentStatus,
+    ) -> Result<(), Error> {
+        let mut record =
+            storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        if record.status == DeploymentStatus::Decommissioned {
+            return Err(Error::InvalidParameters);
+        }
+
+        record.status = status;
+        storage::save_deployment(&env, deployment_id, &record);
+
+        // Decommissioned instances are never extended again; the owner can
+        // still leave the pool to recover the deposit
+        if status == DeploymentStatus::Decommissioned {
+            storage::remove_from_keep_alive_pool(&env, deployment_id);
+        }
+
+        DeploymentStatusChanged {
+            deployment_id,
+            status,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Hide a deployment from public listings, or show it again (record owner only)
+    pub fn set_deployment_hidden(env: Env, deployment_id: u32, hidden: bool) -> Result<(), Error> {
+        let mut record =
+            storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        record.hidden = hidden;
+        storage::save_deployment(&env, deployment_id, &record);
+
+        DeploymentVisibilityChanged {
+            deployment_id,
+            hidden,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Hand a deployment record to a new owner, e.g. a team multisig (record owner only)
+    /// The original deployer stays on the record and in its deployment history
+    pub fn transfer_deployment(
+        env: Env,
+        deployment_id: u32,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        let mut record =
+            storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        if new_owner == record.owner {
+            return Err(Error::InvalidParameters);
+        }
+
+        let previous_owner = record.owner.clone();
+        storage::remove_from_owner(&env, &previous_owner, deployment_id);
+        storage::add_to_owner(&env, &new_owner, deployment_id);
+
+        record.owner = new_owner.clone();
+        storage::save_deployment(&env, deployment_id, &record);
+
+        DeploymentTransferred {
+            deployment_id,
+            from: previous_owner,
+            to: new_owner,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the deployments an address owns, including hidden ones
+    pub fn get_owned_deployments(env: Env, owner: Address) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_owner_deployments(&env, &owner);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                records.push_back(record);
+            }
+        }
+
+        records
+    }
+
+    /// Get all deployments of a specific contract
+    pub fn get_contract_deployments(env: Env, contract_id: u32) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_contract_deployments(&env, contract_id);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                if !record.hidden {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Get a page of the deployments running a WASM hash
+    /// Deployments are listed in the order they were indexed, except that when
+    /// one is upgraded away the last one takes its slot
+    /// Hidden records are included, so every affected instance can be found
+    /// start: Index of the first deployment to return
+    /// limit: Maximum number of records to return
+    pub fn get_hash_deployments(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_hash_deployment_ids(&env, &wasm_hash, start, limit);
+        storage::get_deployment_page(&env, &deployment_ids, 0, limit)
+    }
+
+    /// Get the number of deployments running a WASM hash
+    pub fn get_hash_deployment_count(env: Env, wasm_hash: BytesN<32>) -> u32 {
+        storage::get_hash_deployment_count(&env, &wasm_hash)
+    }
+
+    /// Get a page of the deployments of a listing release, ordered like get_hash_deployments
+    /// Deployments by WASM hash are indexed under the release that published the hash
+    pub fn get_release_deployments(
+        env: Env,
+        contract_id: u32,
+        release: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DeploymentRecord> {
+        let deployment_ids =
+            storage::get_release_deployment_ids(&env, contract_id, &release, start, limit);
+        storage::get_deployment_page(&env, &deployment_ids, 0, limit)
+    }
+
+    /// Get the number of deployments of a listing release
+    pub fn get_release_deployment_count(env: Env, contract_id: u32, release: String) -> u32 {
+        storage::get_release_deployment_count(&env, contract_id, &release)
+    }
+
+    /// Get all deployment records
+    pub fn get_all_deployments(env: Env) -> Vec<DeploymentRecord> {
+        let deployment_ids = storage::get_all_deployment_ids(&env);
+        let mut records = Vec::new(&env);
+
+        for id in deployment_ids.iter() {
+            if let Some(record) = storage::get_deployment(&env, id) {
+                if !record.hidden {
+                    records.push_back(record);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Get total number of deployments
+    pub fn get_total_deployments(env: Env) -> u32 {
+        storage::get_counter(&env)
+    }
+
+    /// Add an instance to the keep-alive pool, or top up its deposit (record owner only)
+    /// amount: Deposit in the pool token, spent on extension fees
+    /// Decommissioned instances can't be funded
+    pub fn fund_keep_alive(env: Env, deployment_id: u32, amount: i128) -> Result<(), Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        let config = storage::get_keep_alive_config(&env).ok_or(Error::KeepAliveNotConfigured)?;
+        if amount <= 0 || record.status == DeploymentStatus::Decommissioned {
+            return Err(Error::InvalidParameters);
+        }
+
+        token::Client::new(&env, &config.token).transfer(
+            &record.owner,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let keep_alive = match storage::get_keep_alive(&env, deployment_id) {
+            Some(mut keep_alive) => {
+                keep_alive.balance += amount;
+                keep_alive
+            }
+            None => {
+                storage::add_to_keep_alive_pool(&env, deployment_id);
+                KeepAlive {
+                    balance: amount,
+                    extended_at: 0,
+                }
+            }
+        };
+        storage::set_keep_alive(&env, deployment_id, &keep_alive);
+
+        Ok(())
+    }
+
+    /// Take an instance out of the keep-alive pool and refund its remaining deposit
+    /// (record owner only)
+    /// Returns the refunded amount
+    pub fn leave_keep_alive(env: Env, deployment_id: u32) -> Result<i128, Error> {
+        let record = storage::get_deployment(&env, deployment_id).ok_or(Error::ContractNotFound)?;
+        record.owner.require_auth();
+
+        let keep_alive =
+            storage::get_keep_alive(&env, deployment_id).ok_or(Error::InvalidParameters)?;
+        let config = storage::get_keep_alive_config(&env).ok_or(Error::KeepAliveNotConfigured)?;
+
+        storage::remove_keep_alive(&env, deployment_id);
+        storage::remove_from_keep_alive_pool(&env, deployment_id);
+        if keep_alive.balance > 0 {
+            token::Client::new(&env, &config.token).transfer(
+                &env.current_contract_address(),
+                &record.owner,
+                &keep_alive.balance,
+            );
+        }
+
+        Ok(keep_alive.balance)
+    }
+
+    /// Extend the TTL of instances in the keep-alive pool that are due (anyone can call)
+    /// Each extension covers the instance and its code and pays the caller the pool fee
+    /// from that instance's deposit; instances that can't pay the fee are skipped
+    /// start: Index of the first pool entry to check
+    /// limit: Maximum number of pool entries to check
+    /// Returns the number of instances extended
+    pub fn extend_instances(
+        env: Env,
+        caller: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+
+        let config = storage::get_keep_alive_config(&env).ok_or(Error::KeepAliveNotConfigured)?;
+        Ok(keep_alive::extend_due(&env, &config, &caller, start, limit))
+    }
+
+    /// Get the number of instances in the keep-alive pool
+    pub fn get_keep_alive_pool_size(env: Env) -> u32 {
+        storage::get_keep_alive_pool_size(&env)
+    }
+
+    /// Get an instance's keep-alive deposit, if it is in the pool
+    pub fn get_keep_alive(env: Env, deployment_id: u32) -> Option<KeepAlive> {
+        storage::get_keep_alive(&env, deployment_id)
+    }
+
+    /// Set aside a budget that pays deploy prices for allowlisted deployers
+    /// amount: Initial budget in `params.token`, held by the manager
+    pub fn create_sponsorship(
+        env: Env,
+        sponsor: Address,
+        params: SponsorshipParams,
+        amount: i128,
+    ) -> Result<u32, Error> {
+        sponsor.require_auth();
+
+        if amount < 0
+            || params.deployers.is_empty()
+            || params.deployers.len() > MAX_SPONSORED_DEPLOYERS
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        if amount > 0 {
+            token::Client::new(&env, &params.token).transfer(
+                &sponsor,
+                env.current_contract_address(),
+                &amount,
+            );
+        }
+
+        let sponsorship_id = storage::increment_sponsorship_counter(&env);
+        let sponsorship = Sponsorship {
+            sponsorship_id,
+            sponsor: sponsor.clone(),
+            token: params.token,
+            deployers: params.deployers,
+            listings: params.listings,
+            balance: amount,
+            spent: 0,
+            deployment_count: 0,
+        };
+        storage::save_sponsorship(&env, &sponsorship);
+        storage::add_to_sponsor(&env, &sponsor, sponsorship_id);
+
+        // Emit event
+        SponsorshipFunded {
+            sponsorship_id,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(sponsorship_id)
+    }
+
+    /// Add to a sponsorship's budget (sponsor only)
+    pub fn fund_sponsorship(env: Env, sponsorship_id: u32, amount: i128) -> Result<(), Error> {
+        let mut sponsorship =
+            storage::get_sponsorship(&env, sponsorship_id).ok_or(Error::SponsorshipNotFound)?;
+        sponsorship.sponsor.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        token::Client::new(&env, &sponsorship.token).transfer(
+            &sponsorship.sponsor,
+            env.current_contract_address(),
+            &amount,
+        );
+        sponsorship.balance += amount;
+        storage::save_sponsorship(&env, &sponsorship);
+
+        // Emit event
+        SponsorshipFunded {
+            sponsorship_id,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Replace the deployers and listings a sponsorship covers (sponsor only)
+    pub fn set_sponsorship_allowlist(
+        env: Env,
+        sponsorship_id: u32,
+        deployers: Vec<Address>,
+        listings: Vec<u32>,
+    ) -> Result<(), Error> {
+        let mut sponsorship =
+            storage::get_sponsorship(&env, sponsorship_id).ok_or(Error::SponsorshipNotFound)?;
+        sponsorship.sponsor.require_auth();
+
+        if deployers.is_empty() || deployers.len() > MAX_SPONSORED_DEPLOYERS {
+            return Err(Error::InvalidParameters);
+        }
+
+        sponsorship.deployers = deployers;
+        sponsorship.listings = listings;
+        storage::save_sponsorship(&env, &sponsorship);
+
+        Ok(())
+    }
+
+    /// Refund a sponsorship's remaining budget to the sponsor (sponsor only)
+    /// The sponsorship stays, with an empty budget, and can be funded again
+    /// Returns the refunded amount
+    pub fn withdraw_sponsorship(env: Env, sponsorship_id: u32) -> Result<i128, Error> {
+        let mut sponsorship =
+            storage::get_sponsorship(&env, sponsorship_id).ok_or(Error::SponsorshipNotFound)?;
+        sponsorship.sponsor.require_auth();
+
+        let amount = sponsorship.balance;
+        if amount > 0 {
+            token::Client::new(&env, &sponsorship.token).transfer(
+                &env.current_contract_address(),
+                &sponsorship.sponsor,
+                &amount,
+            );
+        }
+        sponsorship.balance = 0;
+        storage::save_sponsorship(&env, &sponsorship);
+
+        Ok(amount)
+    }
+
+    /// Deploy a release of a registry listing with the deploy price paid from a sponsorship
+    /// The deployer must be on the sponsorship's allowlist, the listing must be covered
+    /// and priced in the sponsorship's token, and the budget must cover the price
+    /// Exactly the amount debited from the sponsorship is paid out of the manager
+    pub fn deploy_sponsored(
+        env: Env,
+        sponsorship_id: u32,
+        contract_id: u32,
+        release: String,
+        deployer: Address,
+        salt: BytesN<32>,
+        init_args: Vec<Val>,
+    ) -> Result<u32, Error> {
+        // Require authentication from deployer
+        deployer.require_auth();
+
+        let mut sponsorship =
+            storage::get_sponsorship(&env, sponsorship_id).ok_or(Error::SponsorshipNotFound)?;
+        if !sponsorship.deployers.contains(&deployer) {
+            return Err(Error::UnauthorizedAccess);
+        }
+        if !sponsorship.listings.is_empty() && !sponsorship.listings.contains(contract_id) {
+            return Err(Error::UnauthorizedAccess);
+        }
+
+        let amount = match registry::get_deploy_price(&env, contract_id) {
+            Some(price) if price.token != sponsorship.token => {
+                return Err(Error::InvalidParameters)
+            }
+            Some(price) => price.amount,
+            None => 0,
+        };
+        if amount > sponsorship.balance {
+            return Err(Error::SponsorshipExhausted);
+        }
+
+        let wasm_hash = registry::resolve_release(&env, contract_id, &release)?;
+
+        // Sponsored funds share the manager's balance with every other
+        // sponsorship, so pay exactly what this one is debited and check it
+        let manager = env.current_contract_address();
+        let token_client = token::Client::new(&env, &sponsorship.token);
+        let balance_before = token_client.balance(&manager);
+
+        let deployment_id = deploy::deploy(
+            &env,
+            DeployRequest {
+                release: Some(release),
+                payer: Some(manager.clone()),
+                price: Some(DeployPrice {
+                    token: sponsorship.token.clone(),
+                    amount,
+                }),
+                ..DeployRequest::new(
+                    &env,
+                    contract_id,
+                    deployer.clone(),
+                    wasm_hash,
+                    salt,
+                    init_args,
+                )
+            },
+        )?;
+
+        if balance_before - token_client.balance(&manager) != amount {
+            return Err(Error::PaymentMismatch);
+        }
+
+        sponsorship.balance -= amount;
+        sponsorship.spent += amount;
+        sponsorship.deployment_count += 1;
+        storage::save_sponsorship(&env, &sponsorship);
+        storage::add_sponsor_usage(
+            &env,
+            sponsorship_id,
+            &SponsoredDeployment {
+                deployment_id,
+                deployer,
+                contract_id,
+                amount,
+                deployed_at: env.ledger().timestamp(),
+            },
+        );
+
+        // Emit event
+        SponsoredDeploy {
+            sponsorship_id,
+            deployment_id,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(deployment_id)
+    }
+
+    /// Get a specific sponsorship, including its remaining and spent budget
+    pub fn get_sponsorship(env: Env, sponsorship_id: u32) -> Result<Sponsorship, Error> {
+        storage::get_sponsorship(&env, sponsorship_id).ok_or(Error::SponsorshipNotFound)
+    }
+
+    /// Get all sponsorships created by a sponsor
+    pub fn get_sponsorships(env: Env, sponsor: Address) -> Vec<Sponsorship> {
+        let sponsorship_ids = storage::get_sponsor_sponsorships(&env, &sponsor);
+        let mut sponsorships = Vec::new(&env);
+
+        for id in sponsorship_ids.iter() {
+            if let Some(sponsorship) = storage::get_sponsorship(&env, id) {
+                sponsorships.push_back(sponsorship);
+            }
+        }
+
+        sponsorships
+    }
+
+    /// Get the deployments a sponsorship paid for, oldest first
+    pub fn get_sponsorship_usage(env: Env, sponsorship_id: u32) -> Vec<SponsoredDeployment> {
+        storage::get_sponsor_usage(&env, sponsorship_id)
+    }
+
+    /// Offer licenses for a listing (listing author only)
+    /// Once a listing has an offer, deploying it requires a valid license
+    /// duration: Seconds a license stays valid after purchase (0 for perpetual)
+    pub fn create_license_offer(
+        env: Env,
+        contract_id: u32,
+        token: Address,
+        price: i128,
+        duration: u64,
+        scope: LicenseScope,
+    ) -> Result<u32, Error> {
+        registry::get_author(&env, contract_id)?.require_auth();
+
+        // Licenses bought now must get a representable expiry
+        if price <= 0 || env.ledger().timestamp().checked_add(duration).is_none() {
+            return Err(Error::InvalidParameters);
+        }
+
+        let offer_id = storage::increment_offer_counter(&env);
+        let offer = LicenseOffer {
+            offer_id,
+            contract_id,
+            token,
+            price,
+            duration,
+            scope,
+            active: true,
+        };
+
+        storage::save_offer(&env, &offer);
+        storage::add_to_contract_offers(&env, contract_id, offer_id);
+
+        // Emit event
+        LicenseOfferCreated {
+            offer_id,
+            contract_id,
+        }
+        .publish(&env);
+
+        Ok(offer_id)
+    }
+
+    /// Open or close an offer for new purchases (listing author only)
+    /// Closing every offer does not unlicense the listing
+    pub fn set_license_offer_active(env: Env, offer_id: u32, active: bool) -> Result<(), Error> {
+        let mut offer = storage::get_offer(&env, offer_id).ok_or(Error::OfferNotFound)?;
+        registry::get_author(&env, offer.contract_id)?.require_auth();
+
+        offer.active = active;
+        storage::save_offer(&env, &offer);
+
+        Ok(())
+    }
+
+    /// Buy a license, paying the offer price to the listing's authors
+    pub fn purchase_license(env: Env, buyer: Address, offer_id: u32) -> Result<u32, Error> {
+        buyer.require_auth();
+
+        let offer = storage::get_offer(&env, offer_id).ok_or(Error::OfferNotFound)?;
+        if !offer.active {
+            return Err(Error::OfferInactive);
+        }
+
+        let terms = registry::get_payout_terms(&env, offer.contract_id)?;
+        royalty::distribute(&env, &offer.token, &buyer, offer.price, &terms);
+
+        let purchased_at = env.ledger().timestamp();
+        let expires_at = if offer.duration == 0 {
+            None
+        } else {
+            Some(
+                purchased_at
+                    .checked_add(offer.duration)
+                    .ok_or(Error::InvalidParameters)?,
+            )
+        };
+
+        let license_id = storage::increment_license_counter(&env);
+        let license = License {
+            license_id,
+            offer_id,
+            contract_id: offer.contract_id,
+            holder: buyer.clone(),
+            scope: offer.scope,
+            purchased_at,
+            expires_at,
+            deployment_id: None,
+        };
+
+        storage::save_license(&env, &license);
+        storage::add_to_holder(&env, &buyer, license_id);
+
+        // Emit event
+        LicensePurchased {
+            license_id,
+            offer_id,
+            holder: buyer,
+        }
+        .publish(&env);
+
+        Ok(license_id)
+    }
+
+    /// Get a specific license offer
+    pub fn get_license_offer(env: Env, offer_id: u32) -> Result<LicenseOffer, Error> {
+        storage::get_offer(&env, offer_id).ok_or(Error::OfferNotFound)
+    }
+
+    /// Get all license offers for a listing
+    pub fn get_license_offers(env: Env, contract_id: u32) -> Vec<LicenseOffer> {
+        let offer_ids = storage::get_contract_offers(&env, contract_id);
+        let mut offers = Vec::new(&env);
+
+        for id in offer_ids.iter() {
+            if let Some(offer) = storage::get_offer(&env, id) {
+                offers.push_back(offer);
+            }
+        }
+
+        offers
+    }
+
+    /// Get all licenses bought by a holder, including expired and used ones
+    pub fn get_licenses(env: Env, holder: Address) -> Vec<License> {
+        let license_ids = storage::get_holder_licenses(&env, &holder);
+        let mut licenses = Vec::new(&env);
+
+        for id in license_ids.iter() {
+            if let Some(license) = storage::get_license(&env, id) {
+                licenses.push_back(license);
+            }
+        }
+
+        licenses
+    }
+
+    /// Check whether a holder can currently deploy a listing
+    pub fn has_valid_license(env: Env, contract_id: u32, holder: Address) -> bool {
+        !license::is_licensed(&env, contract_id)
+            || license::find_usable_license(&env, contract_id, &holder).is_some()
     }
 }
 
@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::types::{License, LicenseScope};
+
+/// A listing is licensed once its author has created any license offer
+pub fn is_licensed(env: &Env, contract_id: u32) -> bool {
+    !storage::get_contract_offers(env, contract_id).is_empty()
+}
+
+/// Whether a license can cover a new deployment right now
+pub fn is_usable(env: &Env, license: &License) -> bool {
+    let not_expired = match license.expires_at {
+        Some(expires_at) => env.ledger().timestamp() < expires_at,
+        None => true,
+    };
+    let unused = license.scope == LicenseScope::PerDeployer || license.deployment_id.is_none();
+    not_expired && unused
+}
+
+/// Find a license held by `holder` that covers a new deployment of `contract_id`
+/// Per-deployer licenses are preferred so per-instance ones are not used up needlessly
+pub fn find_usable_license(env: &Env, contract_id: u32, holder: &Address) -> Option<License> {
+    let mut per_instance = None;
+
+    for id in storage::get_holder_licenses(env, holder).iter() {
+        if let Some(license) = storage::get_license(env, id) {
+            if license.contract_id != contract_id || !is_usable(env, &license) {
+                continue;
+            }
+            if license.scope == LicenseScope::PerDeployer {
+                return Some(license);
+            }
+            if per_instance.is_none() {
+                per_instance = Some(license);
+            }
+        }
+    }
+
+    per_instance
+}
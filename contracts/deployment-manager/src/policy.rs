@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::registry::RegistryClient;
+use crate::storage;
+use crate::types::{DeployPolicy, Error, HashReport};
+
+/// Check a WASM hash against the global policy and the deployer's own policy
+/// The registry is only consulted when at least one policy is set
+pub fn check(env: &Env, deployer: &Address, wasm_hash: &BytesN<32>) -> Result<(), Error> {
+    let global = storage::get_global_policy(env);
+    let own = storage::get_deployer_policy(env, deployer);
+    if global.is_none() && own.is_none() {
+        return Ok(());
+    }
+
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    let report = registry.get_hash_report(wasm_hash);
+
+    for policy in [global, own].iter().flatten() {
+        enforce(policy, &report)?;
+    }
+    Ok(())
+}
+
+fn enforce(policy: &DeployPolicy, report: &HashReport) -> Result<(), Error> {
+    if policy.require_published && report.listings.is_empty() {
+        return Err(Error::HashNotPublished);
+    }
+    if report.verification < policy.min_verification {
+        return Err(Error::HashNotVerified);
+    }
+    if report.highest_severity > policy.max_severity {
+        return Err(Error::HashHasAdvisories);
+    }
+    Ok(())
+}
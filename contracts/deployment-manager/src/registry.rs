@@ -0,0 +1,117 @@
+use soroban_sdk::{contractclient, contracterror, Address, BytesN, Env, String, Vec};
+
+use crate::storage;
+use crate::types::{ConstructorParam, DeployPrice, Error, HashReport, PayoutTerms, Release};
+
+/// The ContractRegistry errors the DeploymentManager tells apart
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq)]
+#[repr(u32)]
+pub enum RegistryError {
+    ContractNotFound = 2,
+    ReleaseNotFound = 13,
+    ReleaseYanked = 15,
+    ContractQuarantined = 16,
+}
+
+/// The subset of the ContractRegistry interface used by the DeploymentManager
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    fn get_deploy_price(env: Env, contract_id: u32) -> Option<DeployPrice>;
+    fn get_payout_terms(env: Env, contract_id: u32) -> PayoutTerms;
+    fn get_constructor_schema(env: Env, contract_id: u32) -> Option<Vec<ConstructorParam>>;
+    fn resolve_release(
+        env: Env,
+        contract_id: u32,
+        version: String,
+    ) -> Result<BytesN<32>, RegistryError>;
+    fn get_hash_report(env: Env, wasm_hash: BytesN<32>) -> HashReport;
+    fn find_release_by_hash(env: Env, contract_id: u32, wasm_hash: BytesN<32>) -> Option<String>;
+    fn get_releases(env: Env, contract_id: u32) -> Vec<Release>;
+    fn increment_deployment_count(env: Env, contract_id: u32) -> Result<(), RegistryError>;
+}
+
+/// Look up who gets paid for a registry listing
+pub fn get_payout_terms(env: &Env, contract_id: u32) -> Result<PayoutTerms, Error> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_get_payout_terms(&contract_id) {
+        Ok(Ok(terms)) => Ok(terms),
+        _ => Err(Error::ContractNotFound),
+    }
+}
+
+/// Look up the author of a registry listing
+pub fn get_author(env: &Env, contract_id: u32) -> Result<Address, Error> {
+    Ok(get_payout_terms(env, contract_id)?.author)
+}
+
+/// Look up the constructor schema of a registry listing, if it has one
+pub fn get_constructor_schema(env: &Env, contract_id: u32) -> Option<Vec<ConstructorParam>> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_get_constructor_schema(&contract_id) {
+        Ok(Ok(schema)) => schema,
+        _ => None,
+    }
+}
+
+/// Resolve a deployable release of a registry listing to its WASM hash
+pub fn resolve_release(env: &Env, contract_id: u32, version: &String) -> Result<BytesN<32>, Error> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_resolve_release(&contract_id, version) {
+        Ok(Ok(wasm_hash)) => Ok(wasm_hash),
+        Err(Ok(RegistryError::ReleaseNotFound)) => Err(Error::ReleaseNotFound),
+        Err(Ok(RegistryError::ReleaseYanked)) => Err(Error::ReleaseYanked),
+        Err(Ok(RegistryError::ContractQuarantined)) => Err(Error::ListingQuarantined),
+        _ => Err(Error::ContractNotFound),
+    }
+}
+
+/// Get a listing's deploy price, if the author set one
+pub fn get_deploy_price(env: &Env, contract_id: u32) -> Option<DeployPrice> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_get_deploy_price(&contract_id) {
+        Ok(Ok(price)) => price,
+        _ => None,
+    }
+}
+
+/// Find the listing release that published a WASM hash, if any
+pub fn find_release_by_hash(env: &Env, contract_id: u32, wasm_hash: &BytesN<32>) -> Option<String> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_find_release_by_hash(&contract_id, wasm_hash) {
+        Ok(Ok(release)) => release,
+        _ => None,
+    }
+}
+
+/// Whether a listing published `version` after `current`
+pub fn is_later_release(env: &Env, contract_id: u32, current: &String, version: &String) -> bool {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    let releases = match registry.try_get_releases(&contract_id) {
+        Ok(Ok(releases)) => releases,
+        _ => return false,
+    };
+
+    // Releases are listed in publication order
+    let mut after_current = false;
+    for release in releases.iter() {
+        if release.version == *current {
+            after_current = true;
+        } else if release.version == *version {
+            return after_current;
+        }
+    }
+    false
+}
+
+/// Count a deployment against a registry listing
+/// The registry only accepts this from its configured deployment manager
+pub fn increment_deployment_count(env: &Env, contract_id: u32) -> Result<(), Error> {
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    match registry.try_increment_deployment_count(&contract_id) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(RegistryError::ContractNotFound)) => Err(Error::ContractNotFound),
+        _ => Err(Error::UnauthorizedAccess),
+    }
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::registry::RegistryClient;
+use crate::storage;
+use crate::types::{DeployPrice, PayoutTerms};
+
+/// Basis points in 100%
+pub const MAX_BPS: u32 = 10_000;
+
+/// Charge `price` for a deployment of a listing to `payer`, split by the
+/// listing's payout terms. Returns the amount charged.
+pub fn pay_deploy_price(env: &Env, contract_id: u32, payer: &Address, price: &DeployPrice) -> i128 {
+    if price.amount <= 0 {
+        return 0;
+    }
+    let registry = RegistryClient::new(env, &storage::get_registry(env));
+    let terms = registry.get_payout_terms(&contract_id);
+
+    distribute(env, &price.token, payer, price.amount, &terms);
+    price.amount
+}
+
+/// Transfer `amount` from `from`: platform fee first, then co-author shares
+/// of the rest, with the remainder going to the author
+pub fn distribute(env: &Env, token: &Address, from: &Address, amount: i128, terms: &PayoutTerms) {
+    let client = token::Client::new(env, token);
+    let mut remaining = amount;
+
+    if let Some(fee) = storage::get_platform_fee(env) {
+        let cut = amount * fee.fee_bps as i128 / MAX_BPS as i128;
+        if cut > 0 {
+            client.transfer(from, &fee.recipient, &cut);
+            remaining -= cut;
+        }
+    }
+
+    let pool = remaining;
+    for share in terms.shares.iter() {
+        let part = pool * share.share_bps as i128 / MAX_BPS as i128;
+        if part > 0 {
+            client.transfer(from, &share.recipient, &part);
+            remaining -= part;
+        }
+    }
+
+    if remaining > 0 {
+        client.transfer(from, &terms.author, &remaining);
+    }
+}
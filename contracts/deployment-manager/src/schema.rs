@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, Bytes, Env, Map, String, Symbol, TryFromVal, Val, Vec, I256, U256};
+
+use crate::types::{ConstructorParam, ParamType};
+
+/// Whether `value` has the type a constructor parameter expects
+fn matches_type(env: &Env, param_type: ParamType, value: &Val) -> bool {
+    match param_type {
+        ParamType::Any => true,
+        ParamType::Bool => bool::try_from_val(env, value).is_ok(),
+        ParamType::U32 => u32::try_from_val(env, value).is_ok(),
+        ParamType::I32 => i32::try_from_val(env, value).is_ok(),
+        ParamType::U64 => u64::try_from_val(env, value).is_ok(),
+        ParamType::I64 => i64::try_from_val(env, value).is_ok(),
+        ParamType::U128 => u128::try_from_val(env, value).is_ok(),
+        ParamType::I128 => i128::try_from_val(env, value).is_ok(),
+        ParamType::U256 => U256::try_from_val(env, value).is_ok(),
+        ParamType::I256 => I256::try_from_val(env, value).is_ok(),
+        ParamType::Address => Address::try_from_val(env, value).is_ok(),
+        ParamType::String => String::try_from_val(env, value).is_ok(),
+        ParamType::Symbol => Symbol::try_from_val(env, value).is_ok(),
+        ParamType::Bytes => Bytes::try_from_val(env, value).is_ok(),
+        ParamType::Vec => Vec::<Val>::try_from_val(env, value).is_ok(),
+        ParamType::Map => Map::<Val, Val>::try_from_val(env, value).is_ok(),
+    }
+}
+
+/// Check `args` against a constructor schema, filling omitted trailing
+/// arguments with their defaults.
+/// On failure returns the index of the first offending argument.
+pub fn resolve_args(
+    env: &Env,
+    schema: &Vec<ConstructorParam>,
+    args: &Vec<Val>,
+) -> Result<Vec<Val>, u32> {
+    // Extra arguments the constructor does not take
+    if args.len() > schema.len() {
+        return Err(schema.len());
+    }
+
+    let mut resolved = Vec::new(env);
+    for (i, param) in schema.iter().enumerate() {
+        let index = i as u32;
+        let value = match args.get(index) {
+            Some(value) => value,
+            None => param.default_value.get(0).ok_or(index)?,
+        };
+        if !matches_type(env, param.param_type, &value) {
+            return Err(index);
+        }
+        resolved.push_back(value);
+    }
+
+    Ok(resolved)
+}
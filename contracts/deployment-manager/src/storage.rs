@@ -1,12 +1,75 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+use soroban_sdk::{
+    symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
 
-use crate::types::DeploymentRecord;
+use crate::types::{
+    DeployPolicy, DeploymentIntent, DeploymentRecord, DeploymentTemplate, KeepAlive,
+    KeepAliveConfig, License, LicenseOffer, ObservedHash, PlatformFee, SponsoredDeployment,
+    Sponsorship,
+};
 
 // Storage keys
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const REGISTRY: Symbol = symbol_short!("REGISTRY");
+const PLATFORM_FEE: Symbol = symbol_short!("PLATFEE");
 const DEPLOYMENT_COUNTER: Symbol = symbol_short!("DCOUNT");
 const ALL_DEPLOYMENTS: Symbol = symbol_short!("ALLDEPL");
 const DEPLOYER_PREFIX: Symbol = symbol_short!("DEPLOYER");
 const CONTRACT_PREFIX: Symbol = symbol_short!("CONTRACT");
+const OFFER_COUNTER: Symbol = symbol_short!("OCOUNT");
+const OFFER_PREFIX: Symbol = symbol_short!("OFFER");
+const CONTRACT_OFFERS_PREFIX: Symbol = symbol_short!("CTOFFERS");
+const LICENSE_COUNTER: Symbol = symbol_short!("LCOUNT");
+const LICENSE_PREFIX: Symbol = symbol_short!("LICENSE");
+const HOLDER_PREFIX: Symbol = symbol_short!("HOLDER");
+const NONCE_PREFIX: Symbol = symbol_short!("NONCE");
+const GROUP_COUNTER: Symbol = symbol_short!("GCOUNT");
+const GROUP_PREFIX: Symbol = symbol_short!("GROUP");
+const TEMPLATE_COUNTER: Symbol = symbol_short!("TCOUNT");
+const TEMPLATE_PREFIX: Symbol = symbol_short!("TEMPLATE");
+const OWNER_TEMPLATES_PREFIX: Symbol = symbol_short!("OWNTMPL");
+const RECOMMENDED_PREFIX: Symbol = symbol_short!("RECTMPL");
+const ADDRESS_PREFIX: Symbol = symbol_short!("ADDRESS");
+const HASH_PREFIX: Symbol = symbol_short!("HASH");
+const RELEASE_PREFIX: Symbol = symbol_short!("RELEASE");
+const POSITION: Symbol = symbol_short!("POS");
+const OBSERVED_PREFIX: Symbol = symbol_short!("OBSERVED");
+const KEEP_ALIVE_CONFIG: Symbol = symbol_short!("KACONFIG");
+const KEEP_ALIVE_PREFIX: Symbol = symbol_short!("KEEPALIVE");
+const KEEP_ALIVE_POOL: Symbol = symbol_short!("KAPOOL");
+const OWNER_PREFIX: Symbol = symbol_short!("OWNER");
+const GLOBAL_POLICY: Symbol = symbol_short!("GPOLICY");
+const DEPLOYER_POLICY_PREFIX: Symbol = symbol_short!("DPOLICY");
+const INTENT_COUNTER: Symbol = symbol_short!("ICOUNT");
+const INTENT_PREFIX: Symbol = symbol_short!("INTENT");
+const SPONSORSHIP_COUNTER: Symbol = symbol_short!("SCOUNT");
+const SPONSORSHIP_PREFIX: Symbol = symbol_short!("SPONSOR");
+const SPONSOR_PREFIX: Symbol = symbol_short!("SPONSORS");
+const SPONSOR_USAGE_PREFIX: Symbol = symbol_short!("SPUSAGE");
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage().instance().get(&ADMIN).unwrap()
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&ADMIN, admin);
+}
+
+pub fn get_registry(env: &Env) -> Address {
+    env.storage().instance().get(&REGISTRY).unwrap()
+}
+
+pub fn set_registry(env: &Env, registry: &Address) {
+    env.storage().instance().set(&REGISTRY, registry);
+}
+
+pub fn get_platform_fee(env: &Env) -> Option<PlatformFee> {
+    env.storage().instance().get(&PLATFORM_FEE)
+}
+
+pub fn set_platform_fee(env: &Env, fee: &PlatformFee) {
+    env.storage().instance().set(&PLATFORM_FEE, fee);
+}
 
 pub fn get_counter(env: &Env) -> u32 {
     env.storage()
@@ -32,6 +95,16 @@ pub fn get_deployment(env: &Env, deployment_id: u32) -> Option<DeploymentRecord>
     env.storage().instance().get(&deployment_id)
 }
 
+pub fn get_deployment_id_by_address(env: &Env, address: &Address) -> Option<u32> {
+    let key = (ADDRESS_PREFIX, address);
+    env.storage().instance().get(&key)
+}
+
+pub fn set_deployment_address(env: &Env, address: &Address, deployment_id: u32) {
+    let key = (ADDRESS_PREFIX, address);
+    env.storage().instance().set(&key, &deployment_id);
+}
+
 pub fn get_all_deployment_ids(env: &Env) -> Vec<u32> {
     env.storage()
         .instance()
@@ -57,6 +130,27 @@ pub fn add_to_deployer(env: &Env, deployer: &Address, deployment_id: u32) {
     env.storage().instance().set(&key, &deployments);
 }
 
+pub fn get_owner_deployments(env: &Env, owner: &Address) -> Vec<u32> {
+    let key = (OWNER_PREFIX, owner);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_owner(env: &Env, owner: &Address, deployment_id: u32) {
+    let mut deployments = get_owner_deployments(env, owner);
+    deployments.push_back(deployment_id);
+    let key = (OWNER_PREFIX, owner);
+    env.storage().instance().set(&key, &deployments);
+}
+
+pub fn remove_from_owner(env: &Env, owner: &Address, deployment_id: u32) {
+    let mut deployments = get_owner_deployments(env, owner);
+    if let Some(index) = deployments.first_index_of(deployment_id) {
+        deployments.remove(index);
+        let key = (OWNER_PREFIX, owner);
+        env.storage().instance().set(&key, &deployments);
+    }
+}
+
 pub fn get_contract_deployments(env: &Env, contract_id: u32) -> Vec<u32> {
     let key = (CONTRACT_PREFIX, contract_id);
     env.storage().instance().get(&key).unwrap_or(Vec::new(env))
@@ -68,3 +162,443 @@ pub fn add_to_contract_deployments(env: &Env, contract_id: u32, deployment_id: u
     let key = (CONTRACT_PREFIX, contract_id);
     env.storage().instance().set(&key, &deployments);
 }
+
+// Indexes that grow with every deployment live in persistent storage, one
+// entry per item, rather than in the instance entry every call loads. A list
+// stores its length under its own key and item i under (list, i); sets of ids
+// also map each id to its index so it can be swap-removed
+
+fn list_len(env: &Env, list: &Val) -> u32 {
+    env.storage().persistent().get(list).unwrap_or(0)
+}
+
+fn list_get<T: TryFromVal<Env, Val>>(env: &Env, list: &Val, index: u32) -> Option<T> {
+    env.storage().persistent().get(&(*list, index))
+}
+
+fn list_push<T: IntoVal<Env, Val>>(env: &Env, list: &Val, item: &T) {
+    let len = list_len(env, list);
+    env.storage().persistent().set(&(*list, len), item);
+    env.storage().persistent().set(list, &(len + 1));
+}
+
+/// Load `limit` items of a list starting at index `start`
+fn list_page<T>(env: &Env, list: &Val, start: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let end = start.saturating_add(limit).min(list_len(env, list));
+    let mut items = Vec::new(env);
+
+    for index in start..end {
+        if let Some(item) = list_get(env, list, index) {
+            items.push_back(item);
+        }
+    }
+
+    items
+}
+
+fn set_add(env: &Env, list: &Val, id: u32) {
+    let position = (*list, POSITION, id);
+    env.storage()
+        .persistent()
+        .set(&position, &list_len(env, list));
+    list_push(env, list, &id);
+}
+
+/// Remove an id from a set, moving the last id into its slot
+fn set_remove(env: &Env, list: &Val, id: u32) {
+    let position = (*list, POSITION, id);
+    if let Some(index) = env.storage().persistent().get::<_, u32>(&position) {
+        let last = list_len(env, list) - 1;
+        if index != last {
+            let moved: u32 = list_get(env, list, last).unwrap();
+            env.storage().persistent().set(&(*list, index), &moved);
+            env.storage()
+                .persistent()
+                .set(&(*list, POSITION, moved), &index);
+        }
+        env.storage().persistent().remove(&(*list, last));
+        env.storage().persistent().set(list, &last);
+        env.storage().persistent().remove(&position);
+    }
+}
+
+fn hash_list(env: &Env, wasm_hash: &BytesN<32>) -> Val {
+    (HASH_PREFIX, wasm_hash.clone()).into_val(env)
+}
+
+fn release_list(env: &Env, contract_id: u32, release: &String) -> Val {
+    (RELEASE_PREFIX, contract_id, release.clone()).into_val(env)
+}
+
+pub fn get_hash_deployment_count(env: &Env, wasm_hash: &BytesN<32>) -> u32 {
+    list_len(env, &hash_list(env, wasm_hash))
+}
+
+pub fn get_hash_deployment_ids(
+    env: &Env,
+    wasm_hash: &BytesN<32>,
+    start: u32,
+    limit: u32,
+) -> Vec<u32> {
+    list_page(env, &hash_list(env, wasm_hash), start, limit)
+}
+
+pub fn add_to_hash_deployments(env: &Env, wasm_hash: &BytesN<32>, deployment_id: u32) {
+    set_add(env, &hash_list(env, wasm_hash), deployment_id);
+}
+
+pub fn remove_from_hash_deployments(env: &Env, wasm_hash: &BytesN<32>, deployment_id: u32) {
+    set_remove(env, &hash_list(env, wasm_hash), deployment_id);
+}
+
+pub fn get_release_deployment_count(env: &Env, contract_id: u32, release: &String) -> u32 {
+    list_len(env, &release_list(env, contract_id, release))
+}
+
+pub fn get_release_deployment_ids(
+    env: &Env,
+    contract_id: u32,
+    release: &String,
+    start: u32,
+    limit: u32,
+) -> Vec<u32> {
+    list_page(env, &release_list(env, contract_id, release), start, limit)
+}
+
+pub fn add_to_release_deployments(
+    env: &Env,
+    contract_id: u32,
+    release: &String,
+    deployment_id: u32,
+) {
+    set_add(env, &release_list(env, contract_id, release), deployment_id);
+}
+
+pub fn remove_from_release_deployments(
+    env: &Env,
+    contract_id: u32,
+    release: &String,
+    deployment_id: u32,
+) {
+    set_remove(env, &release_list(env, contract_id, release), deployment_id);
+}
+
+pub fn get_observed_hashes(env: &Env, deployment_id: u32) -> Vec<ObservedHash> {
+    let list = (OBSERVED_PREFIX, deployment_id).into_val(env);
+    list_page(env, &list, 0, list_len(env, &list))
+}
+
+pub fn add_observed_hash(env: &Env, deployment_id: u32, observed: &ObservedHash) {
+    list_push(
+        env,
+        &(OBSERVED_PREFIX, deployment_id).into_val(env),
+        observed,
+    );
+}
+
+/// Load the records for `limit` ids starting at index `start`
+pub fn get_deployment_page(
+    env: &Env,
+    deployment_ids: &Vec<u32>,
+    start: u32,
+    limit: u32,
+) -> Vec<DeploymentRecord> {
+    let end = start.saturating_add(limit).min(deployment_ids.len());
+    let mut records = Vec::new(env);
+
+    for index in start..end {
+        let id = deployment_ids.get_unchecked(index);
+        if let Some(record) = get_deployment(env, id) {
+            records.push_back(record);
+        }
+    }
+
+    records
+}
+
+pub fn increment_offer_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&OFFER_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage().instance().set(&OFFER_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn save_offer(env: &Env, offer: &LicenseOffer) {
+    let key = (OFFER_PREFIX, offer.offer_id);
+    env.storage().instance().set(&key, offer);
+}
+
+pub fn get_offer(env: &Env, offer_id: u32) -> Option<LicenseOffer> {
+    let key = (OFFER_PREFIX, offer_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn get_contract_offers(env: &Env, contract_id: u32) -> Vec<u32> {
+    let key = (CONTRACT_OFFERS_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_contract_offers(env: &Env, contract_id: u32, offer_id: u32) {
+    let mut offers = get_contract_offers(env, contract_id);
+    offers.push_back(offer_id);
+    let key = (CONTRACT_OFFERS_PREFIX, contract_id);
+    env.storage().instance().set(&key, &offers);
+}
+
+pub fn increment_license_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&LICENSE_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage().instance().set(&LICENSE_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn save_license(env: &Env, license: &License) {
+    let key = (LICENSE_PREFIX, license.license_id);
+    env.storage().instance().set(&key, license);
+}
+
+pub fn get_license(env: &Env, license_id: u32) -> Option<License> {
+    let key = (LICENSE_PREFIX, license_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn get_holder_licenses(env: &Env, holder: &Address) -> Vec<u32> {
+    let key = (HOLDER_PREFIX, holder);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_holder(env: &Env, holder: &Address, license_id: u32) {
+    let mut licenses = get_holder_licenses(env, holder);
+    licenses.push_back(license_id);
+    let key = (HOLDER_PREFIX, holder);
+    env.storage().instance().set(&key, &licenses);
+}
+
+pub fn get_deployer_nonce(env: &Env, deployer: &Address) -> u64 {
+    let key = (NONCE_PREFIX, deployer);
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn set_deployer_nonce(env: &Env, deployer: &Address, nonce: u64) {
+    let key = (NONCE_PREFIX, deployer);
+    env.storage().instance().set(&key, &nonce);
+}
+
+pub fn increment_group_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&GROUP_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage().instance().set(&GROUP_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn get_group_deployments(env: &Env, group_id: u32) -> Vec<u32> {
+    let key = (GROUP_PREFIX, group_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_group(env: &Env, group_id: u32, deployment_id: u32) {
+    let mut deployments = get_group_deployments(env, group_id);
+    deployments.push_back(deployment_id);
+    let key = (GROUP_PREFIX, group_id);
+    env.storage().instance().set(&key, &deployments);
+}
+
+pub fn increment_template_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&TEMPLATE_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage()
+        .instance()
+        .set(&TEMPLATE_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn save_template(env: &Env, template: &DeploymentTemplate) {
+    let key = (TEMPLATE_PREFIX, template.template_id);
+    env.storage().instance().set(&key, template);
+}
+
+pub fn get_template(env: &Env, template_id: u32) -> Option<DeploymentTemplate> {
+    let key = (TEMPLATE_PREFIX, template_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn remove_template(env: &Env, template_id: u32) {
+    let key = (TEMPLATE_PREFIX, template_id);
+    env.storage().instance().remove(&key);
+}
+
+pub fn get_owner_templates(env: &Env, owner: &Address) -> Vec<u32> {
+    let key = (OWNER_TEMPLATES_PREFIX, owner);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_owner_templates(env: &Env, owner: &Address, template_id: u32) {
+    let mut templates = get_owner_templates(env, owner);
+    templates.push_back(template_id);
+    let key = (OWNER_TEMPLATES_PREFIX, owner);
+    env.storage().instance().set(&key, &templates);
+}
+
+pub fn remove_from_owner_templates(env: &Env, owner: &Address, template_id: u32) {
+    let mut templates = get_owner_templates(env, owner);
+    if let Some(index) = templates.first_index_of(template_id) {
+        templates.remove(index);
+        let key = (OWNER_TEMPLATES_PREFIX, owner);
+        env.storage().instance().set(&key, &templates);
+    }
+}
+
+pub fn get_recommended_templates(env: &Env, contract_id: u32) -> Vec<u32> {
+    let key = (RECOMMENDED_PREFIX, contract_id);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_recommended(env: &Env, contract_id: u32, template_id: u32) {
+    let mut templates = get_recommended_templates(env, contract_id);
+    templates.push_back(template_id);
+    let key = (RECOMMENDED_PREFIX, contract_id);
+    env.storage().instance().set(&key, &templates);
+}
+
+pub fn remove_from_recommended(env: &Env, contract_id: u32, template_id: u32) {
+    let mut templates = get_recommended_templates(env, contract_id);
+    if let Some(index) = templates.first_index_of(template_id) {
+        templates.remove(index);
+        let key = (RECOMMENDED_PREFIX, contract_id);
+        env.storage().instance().set(&key, &templates);
+    }
+}
+
+pub fn get_keep_alive_config(env: &Env) -> Option<KeepAliveConfig> {
+    env.storage().instance().get(&KEEP_ALIVE_CONFIG)
+}
+
+pub fn set_keep_alive_config(env: &Env, config: &KeepAliveConfig) {
+    env.storage().instance().set(&KEEP_ALIVE_CONFIG, config);
+}
+
+pub fn get_keep_alive(env: &Env, deployment_id: u32) -> Option<KeepAlive> {
+    let key = (KEEP_ALIVE_PREFIX, deployment_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn set_keep_alive(env: &Env, deployment_id: u32, keep_alive: &KeepAlive) {
+    let key = (KEEP_ALIVE_PREFIX, deployment_id);
+    env.storage().instance().set(&key, keep_alive);
+}
+
+pub fn remove_keep_alive(env: &Env, deployment_id: u32) {
+    let key = (KEEP_ALIVE_PREFIX, deployment_id);
+    env.storage().instance().remove(&key);
+}
+
+pub fn get_keep_alive_pool_size(env: &Env) -> u32 {
+    list_len(env, &KEEP_ALIVE_POOL.into_val(env))
+}
+
+pub fn get_keep_alive_pool_entry(env: &Env, index: u32) -> Option<u32> {
+    list_get(env, &KEEP_ALIVE_POOL.into_val(env), index)
+}
+
+pub fn add_to_keep_alive_pool(env: &Env, deployment_id: u32) {
+    set_add(env, &KEEP_ALIVE_POOL.into_val(env), deployment_id);
+}
+
+pub fn remove_from_keep_alive_pool(env: &Env, deployment_id: u32) {
+    set_remove(env, &KEEP_ALIVE_POOL.into_val(env), deployment_id);
+}
+
+pub fn get_global_policy(env: &Env) -> Option<DeployPolicy> {
+    env.storage().instance().get(&GLOBAL_POLICY)
+}
+
+pub fn set_global_policy(env: &Env, policy: &Option<DeployPolicy>) {
+    match policy {
+        Some(policy) => env.storage().instance().set(&GLOBAL_POLICY, policy),
+        None => env.storage().instance().remove(&GLOBAL_POLICY),
+    }
+}
+
+pub fn get_deployer_policy(env: &Env, deployer: &Address) -> Option<DeployPolicy> {
+    let key = (DEPLOYER_POLICY_PREFIX, deployer);
+    env.storage().instance().get(&key)
+}
+
+pub fn set_deployer_policy(env: &Env, deployer: &Address, policy: &Option<DeployPolicy>) {
+    let key = (DEPLOYER_POLICY_PREFIX, deployer);
+    match policy {
+        Some(policy) => env.storage().instance().set(&key, policy),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+pub fn increment_intent_counter(env: &Env) -> u32 {
+    let counter: u32 = env.storage().instance().get(&INTENT_COUNTER).unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage().instance().set(&INTENT_COUNTER, &new_counter);
+    new_counter
+}
+
+// Intents pile up with every proposal, so they live in persistent storage
+// rather than in the instance entry every call loads
+
+pub fn save_intent(env: &Env, intent: &DeploymentIntent) {
+    let key = (INTENT_PREFIX, intent.intent_id);
+    env.storage().persistent().set(&key, intent);
+}
+
+pub fn get_intent(env: &Env, intent_id: u32) -> Option<DeploymentIntent> {
+    let key = (INTENT_PREFIX, intent_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn increment_sponsorship_counter(env: &Env) -> u32 {
+    let counter: u32 = env
+        .storage()
+        .instance()
+        .get(&SPONSORSHIP_COUNTER)
+        .unwrap_or(0);
+    let new_counter = counter + 1;
+    env.storage()
+        .instance()
+        .set(&SPONSORSHIP_COUNTER, &new_counter);
+    new_counter
+}
+
+pub fn save_sponsorship(env: &Env, sponsorship: &Sponsorship) {
+    let key = (SPONSORSHIP_PREFIX, sponsorship.sponsorship_id);
+    env.storage().instance().set(&key, sponsorship);
+}
+
+pub fn get_sponsorship(env: &Env, sponsorship_id: u32) -> Option<Sponsorship> {
+    let key = (SPONSORSHIP_PREFIX, sponsorship_id);
+    env.storage().instance().get(&key)
+}
+
+pub fn get_sponsor_sponsorships(env: &Env, sponsor: &Address) -> Vec<u32> {
+    let key = (SPONSOR_PREFIX, sponsor);
+    env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_sponsor(env: &Env, sponsor: &Address, sponsorship_id: u32) {
+    let mut sponsorships = get_sponsor_sponsorships(env, sponsor);
+    sponsorships.push_back(sponsorship_id);
+    let key = (SPONSOR_PREFIX, sponsor);
+    env.storage().instance().set(&key, &sponsorships);
+}
+
+pub fn get_sponsor_usage(env: &Env, sponsorship_id: u32) -> Vec<SponsoredDeployment> {
+    let list = (SPONSOR_USAGE_PREFIX, sponsorship_id).into_val(env);
+    list_page(env, &list, 0, list_len(env, &list))
+}
+
+pub fn add_sponsor_usage(env: &Env, sponsorship_id: u32, usage: &SponsoredDeployment) {
+    list_push(
+        env,
+        &(SPONSOR_USAGE_PREFIX, sponsorship_id).into_val(env),
+        usage,
+    );
+}
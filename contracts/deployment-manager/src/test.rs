@@ -1,18 +1,83 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, vec, Env};
+use contract_registry::{
+    Category, ContractRegistry, ContractRegistryClient, ForkedFrom, PublishParams,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Env, IntoVal, String, Symbol,
+};
+
+mod sample_contract {
+    soroban_sdk::contractimport!(file = "fixtures/sample_contract.wasm");
+}
+
+mod admin_contract {
+    soroban_sdk::contractimport!(file = "fixtures/admin_contract.wasm");
+}
+
+mod legacy_contract {
+    soroban_sdk::contractimport!(file = "fixtures/legacy_contract.wasm");
+}
+
+mod upgradeable_v1 {
+    soroban_sdk::contractimport!(file = "fixtures/upgradeable_v1.wasm");
+}
+
+mod upgradeable_v2 {
+    soroban_sdk::contractimport!(file = "fixtures/upgradeable_v2.wasm");
+}
+
+fn create_manager(env: &Env) -> (DeploymentManagerClient<'_>, ContractRegistryClient<'_>) {
+    let admin = Address::generate(env);
+    let registry_id = env.register(ContractRegistry, (&admin,));
+    let manager_id = env.register(DeploymentManager, (&admin, &registry_id));
+    let registry = ContractRegistryClient::new(env, &registry_id);
+    registry
+        .mock_all_auths()
+        .set_deployment_manager(&manager_id);
+    (DeploymentManagerClient::new(env, &manager_id), registry)
+}
+
+fn upload_sample_wasm(env: &Env) -> BytesN<32> {
+    env.deployer().upload_contract_wasm(sample_contract::WASM)
+}
+
+fn create_token(env: &Env, holder: &Address, amount: i128) -> Address {
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(env, &token_id).mint(holder, &amount);
+    token_id
+}
+
+fn publish_listing(env: &Env, registry: &ContractRegistryClient, author: &Address) -> u32 {
+    let params = PublishParams {
+        wasm_hash: upload_sample_wasm(env),
+        name: String::from_str(env, "SampleContract"),
+        description: String::from_str(env, "Fixture contract for deployment tests"),
+        version: String::from_str(env, "1.0.0"),
+        category: Category::Utility,
+        tags: vec![env],
+        source_url: String::from_str(env, "https://github.com/test/sample"),
+        documentation_url: String::from_str(env, "https://docs.test.com"),
+        license: String::from_str(env, "MIT"),
+        forked_from: ForkedFrom::Original,
+    };
+    registry.publish_contract(author, &params)
+}
 
 #[test]
 fn test_deploy_from_wasm_success() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt = BytesN::from_array(&env, &[2u8; 32]);
     let contract_registry_id = 1u32;
 
@@ -31,13 +96,12 @@ fn test_deploy_from_wasm_success() {
 #[test]
 fn test_get_deployment() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt = BytesN::from_array(&env, &[2u8; 32]);
     let contract_registry_id = 1u32;
 
@@ -60,8 +124,7 @@ fn test_get_deployment() {
 #[should_panic]
 fn test_get_deployment_not_found() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     let fake_id = 999u32;
     client.get_deployment(&fake_id);
@@ -70,13 +133,12 @@ fn test_get_deployment_not_found() {
 #[test]
 fn test_get_deployment_history() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt1 = BytesN::from_array(&env, &[2u8; 32]);
     let salt2 = BytesN::from_array(&env, &[3u8; 32]);
     let contract_id1 = 1u32;
@@ -96,23 +158,34 @@ fn test_get_deployment_history() {
 #[test]
 fn test_get_contract_deployments() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer1 = Address::generate(&env);
     let deployer2 = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt1 = BytesN::from_array(&env, &[2u8; 32]);
     let salt2 = BytesN::from_array(&env, &[3u8; 32]);
     let contract_registry_id = 1u32;
 
     // Deploy same contract twice by different deployers
     let init_args = vec![&env];
-    client.deploy_from_wasm(&contract_registry_id, &deployer1, &wasm_hash, &salt1, &init_args);
+    client.deploy_from_wasm(
+        &contract_registry_id,
+        &deployer1,
+        &wasm_hash,
+        &salt1,
+        &init_args,
+    );
 
-    client.deploy_from_wasm(&contract_registry_id, &deployer2, &wasm_hash, &salt2, &init_args);
+    client.deploy_from_wasm(
+        &contract_registry_id,
+        &deployer2,
+        &wasm_hash,
+        &salt2,
+        &init_args,
+    );
 
     // Get contract deployments
     let deployments = client.get_contract_deployments(&contract_registry_id);
@@ -122,13 +195,12 @@ fn test_get_contract_deployments() {
 #[test]
 fn test_get_all_deployments() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt1 = BytesN::from_array(&env, &[2u8; 32]);
     let salt2 = BytesN::from_array(&env, &[3u8; 32]);
     let salt3 = BytesN::from_array(&env, &[4u8; 32]);
@@ -152,8 +224,7 @@ fn test_get_all_deployments() {
 #[test]
 fn test_get_total_deployments() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
@@ -161,19 +232,31 @@ fn test_get_total_deployments() {
     assert_eq!(client.get_total_deployments(), 0);
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let salt = BytesN::from_array(&env, &[2u8; 32]);
     let contract_registry_id = 1u32;
 
     // Deploy a contract
     let init_args = vec![&env];
-    client.deploy_from_wasm(&contract_registry_id, &deployer, &wasm_hash, &salt, &init_args);
+    client.deploy_from_wasm(
+        &contract_registry_id,
+        &deployer,
+        &wasm_hash,
+        &salt,
+        &init_args,
+    );
 
     assert_eq!(client.get_total_deployments(), 1);
 
     // Deploy another
     let salt2 = BytesN::from_array(&env, &[3u8; 32]);
-    client.deploy_from_wasm(&contract_registry_id, &deployer, &wasm_hash, &salt2, &init_args);
+    client.deploy_from_wasm(
+        &contract_registry_id,
+        &deployer,
+        &wasm_hash,
+        &salt2,
+        &init_args,
+    );
 
     assert_eq!(client.get_total_deployments(), 2);
 }
@@ -181,20 +264,25 @@ fn test_get_total_deployments() {
 #[test]
 fn test_multiple_deployments_same_contract() {
     let env = Env::default();
-    let contract_id = env.register(DeploymentManager, ());
-    let client = DeploymentManagerClient::new(&env, &contract_id);
+    let (client, _) = create_manager(&env);
 
     env.mock_all_auths();
 
     let deployer = Address::generate(&env);
-    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let wasm_hash = upload_sample_wasm(&env);
     let contract_registry_id = 1u32;
 
     // Deploy same contract 3 times with different salts
     let init_args = vec![&env];
     for i in 0..3 {
         let salt = BytesN::from_array(&env, &[i as u8; 32]);
-        client.deploy_from_wasm(&contract_registry_id, &deployer, &wasm_hash, &salt, &init_args);
+        client.deploy_from_wasm(
+            &contract_registry_id,
+            &deployer,
+            &wasm_hash,
+            &salt,
+            &init_args,
+        );
     }
 
     // Verify all deployments recorded
@@ -204,3 +292,1681 @@ fn test_multiple_deployments_same_contract() {
     let deployer_history = client.get_deployment_history(&deployer);
     assert_eq!(deployer_history.len(), 3);
 }
+
+#[test]
+fn test_deploy_pays_royalties() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let co_author = Address::generate(&env);
+    let platform = Address::generate(&env);
+    let deployer = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(&env, &token_id).mint(&deployer, &1000);
+    let token = token::Client::new(&env, &token_id);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    registry.set_deploy_price(
+        &listing_id,
+        &Some(contract_registry::DeployPrice {
+            token: token_id.clone(),
+            amount: 1000,
+        }),
+    );
+    registry.set_revenue_split(
+        &listing_id,
+        &vec![
+            &env,
+            contract_registry::RevenueShare {
+                recipient: co_author.clone(),
+                share_bps: 2500,
+            },
+        ],
+    );
+    client.set_platform_fee(&platform, &1000);
+
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+
+    // 10% platform fee, 25% of the rest to the co-author, remainder to the author
+    assert_eq!(token.balance(&deployer), 0);
+    assert_eq!(token.balance(&platform), 100);
+    assert_eq!(token.balance(&co_author), 225);
+    assert_eq!(token.balance(&author), 675);
+}
+
+#[test]
+#[should_panic]
+fn test_deploy_royalty_insufficient_balance() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(&env, &token_id).mint(&deployer, &10);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    registry.set_deploy_price(
+        &listing_id,
+        &Some(contract_registry::DeployPrice {
+            token: token_id,
+            amount: 1000,
+        }),
+    );
+
+    // The whole deployment fails when the deployer cannot pay
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+}
+
+#[test]
+#[should_panic]
+fn test_set_platform_fee_too_high() {
+    let env = Env::default();
+    let (client, _) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let platform = Address::generate(&env);
+    client.set_platform_fee(&platform, &10_001);
+}
+
+#[test]
+fn test_licensed_listing_requires_license() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let token_id = create_token(&env, &deployer, 500);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    let offer_id =
+        client.create_license_offer(&listing_id, &token_id, &500, &0, &LicenseScope::PerDeployer);
+
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+
+    // Without a license the deployment is refused
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::LicenseRequired)));
+    assert!(!client.has_valid_license(&listing_id, &deployer));
+
+    // After buying a perpetual per-deployer license it can deploy repeatedly
+    let license_id = client.purchase_license(&deployer, &offer_id);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&author), 500);
+
+    client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    let salt2 = BytesN::from_array(&env, &[3u8; 32]);
+    client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt2, &vec![&env]);
+
+    let licenses = client.get_licenses(&deployer);
+    assert_eq!(licenses.len(), 1);
+    assert_eq!(licenses.get(0).unwrap().license_id, license_id);
+    assert_eq!(licenses.get(0).unwrap().expires_at, None);
+}
+
+#[test]
+fn test_per_instance_license_used_once() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let token_id = create_token(&env, &deployer, 100);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    let offer_id =
+        client.create_license_offer(&listing_id, &token_id, &100, &0, &LicenseScope::PerInstance);
+    client.purchase_license(&deployer, &offer_id);
+
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id =
+        client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+
+    let license = client.get_licenses(&deployer).get(0).unwrap();
+    assert_eq!(license.deployment_id, Some(deployment_id));
+
+    // The license is bound to the first instance
+    let salt2 = BytesN::from_array(&env, &[3u8; 32]);
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt2, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::LicenseRequired)));
+}
+
+#[test]
+fn test_time_limited_license_expires() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let token_id = create_token(&env, &deployer, 100);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    let offer_id = client.create_license_offer(
+        &listing_id,
+        &token_id,
+        &100,
+        &3600,
+        &LicenseScope::PerDeployer,
+    );
+    client.purchase_license(&deployer, &offer_id);
+    assert!(client.has_valid_license(&listing_id, &deployer));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3600);
+    assert!(!client.has_valid_license(&listing_id, &deployer));
+
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::LicenseRequired)));
+
+    // Durations that would overflow a purchase's expiry are rejected
+    let result = client.try_create_license_offer(
+        &listing_id,
+        &token_id,
+        &100,
+        &u64::MAX,
+        &LicenseScope::PerDeployer,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_inactive_offer_cannot_be_purchased() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_id = create_token(&env, &buyer, 100);
+
+    let listing_id = publish_listing(&env, &registry, &author);
+    let offer_id =
+        client.create_license_offer(&listing_id, &token_id, &100, &0, &LicenseScope::PerDeployer);
+    client.set_license_offer_active(&offer_id, &false);
+
+    let result = client.try_purchase_license(&buyer, &offer_id);
+    assert_eq!(result, Err(Ok(Error::OfferInactive)));
+}
+
+fn set_admin_schema(env: &Env, registry: &ContractRegistryClient, listing_id: u32) {
+    registry.set_constructor_schema(
+        &listing_id,
+        &vec![
+            env,
+            contract_registry::ConstructorParam {
+                name: String::from_str(env, "admin"),
+                param_type: contract_registry::ParamType::Address,
+                description: String::from_str(env, "Contract admin"),
+                default_value: vec![env],
+            },
+            contract_registry::ConstructorParam {
+                name: String::from_str(env, "limit"),
+                param_type: contract_registry::ParamType::U32,
+                description: String::from_str(env, "Item limit"),
+                default_value: vec![env, 10u32.into_val(env)],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_check_init_args() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+
+    // Without a schema anything goes
+    assert_eq!(client.check_init_args(&listing_id, &vec![&env]), None);
+
+    set_admin_schema(&env, &registry, listing_id);
+
+    // Missing required argument
+    assert_eq!(client.check_init_args(&listing_id, &vec![&env]), Some(0));
+    // Wrong type
+    let args = vec![&env, 5u32.into_val(&env)];
+    assert_eq!(client.check_init_args(&listing_id, &args), Some(0));
+    let args = vec![
+        &env,
+        admin.into_val(&env),
+        Symbol::new(&env, "five").into_val(&env),
+    ];
+    assert_eq!(client.check_init_args(&listing_id, &args), Some(1));
+    // Too many arguments
+    let args = vec![
+        &env,
+        admin.into_val(&env),
+        5u32.into_val(&env),
+        6u32.into_val(&env),
+    ];
+    assert_eq!(client.check_init_args(&listing_id, &args), Some(2));
+    // Trailing default may be omitted
+    let args = vec![&env, admin.into_val(&env)];
+    assert_eq!(client.check_init_args(&listing_id, &args), None);
+}
+
+#[test]
+fn test_deploy_fills_schema_defaults() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    set_admin_schema(&env, &registry, listing_id);
+    publish_admin_release(&env, &registry, listing_id);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(admin_contract::WASM);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id = client.deploy_with_admin(&listing_id, &deployer, &wasm_hash, &salt, &admin);
+
+    let record = client.get_deployment(&deployment_id);
+    let instance = admin_contract::Client::new(&env, &record.deployed_contract_address);
+    assert_eq!(instance.admin(), admin);
+    assert_eq!(instance.limit(), 10);
+}
+
+#[test]
+fn test_deploy_rejects_invalid_init_args() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    set_admin_schema(&env, &registry, listing_id);
+    publish_admin_release(&env, &registry, listing_id);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(admin_contract::WASM);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_deploy_from_registry() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    let release = String::from_str(&env, "1.0.0");
+
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id =
+        client.deploy_from_registry(&listing_id, &release, &deployer, &salt, &vec![&env]);
+
+    // The hash comes from the registry, not the caller
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.wasm_hash, upload_sample_wasm(&env));
+    assert_eq!(record.release, Some(release));
+}
+
+#[test]
+fn test_deploy_from_registry_rejections() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    let release = String::from_str(&env, "1.0.0");
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let init_args = vec![&env];
+
+    let result = client.try_deploy_from_registry(&99, &release, &deployer, &salt, &init_args);
+    assert_eq!(result, Err(Ok(Error::ContractNotFound)));
+
+    let unknown = String::from_str(&env, "2.0.0");
+    let result =
+        client.try_deploy_from_registry(&listing_id, &unknown, &deployer, &salt, &init_args);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotFound)));
+
+    registry.set_quarantined(&listing_id, &true);
+    let result =
+        client.try_deploy_from_registry(&listing_id, &release, &deployer, &salt, &init_args);
+    assert_eq!(result, Err(Ok(Error::ListingQuarantined)));
+    registry.set_quarantined(&listing_id, &false);
+
+    registry.yank_release(&listing_id, &release);
+    let result =
+        client.try_deploy_from_registry(&listing_id, &release, &deployer, &salt, &init_args);
+    assert_eq!(result, Err(Ok(Error::ReleaseYanked)));
+}
+
+#[test]
+fn test_deploy_increments_registry_count() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    let release = String::from_str(&env, "1.0.0");
+
+    let salt1 = BytesN::from_array(&env, &[2u8; 32]);
+    let salt2 = BytesN::from_array(&env, &[3u8; 32]);
+    client.deploy_from_registry(&listing_id, &release, &deployer, &salt1, &vec![&env]);
+    client.deploy_from_wasm(
+        &listing_id,
+        &deployer,
+        &upload_sample_wasm(&env),
+        &salt2,
+        &vec![&env],
+    );
+
+    assert_eq!(registry.get_contract(&listing_id).total_deployments, 2);
+}
+
+#[test]
+fn test_deploy_attributes_hash_to_listing() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let token_id = create_token(&env, &author, 0);
+    let listing_id = publish_listing(&env, &registry, &author);
+    registry.set_deploy_price(
+        &listing_id,
+        &Some(contract_registry::DeployPrice {
+            token: token_id.clone(),
+            amount: 1000,
+        }),
+    );
+    client.create_license_offer(&listing_id, &token_id, &100, &0, &LicenseScope::PerDeployer);
+
+    // Code the listing never released is neither licensed, charged nor counted
+    let other_hash = env.deployer().upload_contract_wasm(admin_contract::WASM);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let init_args = vec![&env, author.into_val(&env), 1u32.into_val(&env)];
+    let deployment_id =
+        client.deploy_from_wasm(&listing_id, &deployer, &other_hash, &salt, &init_args);
+    assert_eq!(client.get_deployment(&deployment_id).contract_id, UNLISTED);
+    assert_eq!(registry.get_contract(&listing_id).total_deployments, 0);
+
+    // The listing's own code still requires a license
+    let salt = BytesN::from_array(&env, &[3u8; 32]);
+    let result = client.try_deploy_from_wasm(
+        &listing_id,
+        &deployer,
+        &upload_sample_wasm(&env),
+        &salt,
+        &vec![&env],
+    );
+    assert_eq!(result, Err(Ok(Error::LicenseRequired)));
+
+    // Ids unknown to the registry are kept but not counted
+    let deployment_id = client.deploy_from_wasm(
+        &42,
+        &deployer,
+        &upload_sample_wasm(&env),
+        &salt,
+        &vec![&env],
+    );
+    assert_eq!(client.get_deployment(&deployment_id).contract_id, 42);
+}
+
+#[test]
+fn test_deploy_by_hash_checks_release_status() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+
+    // Deploying a listing's hash directly gets the same checks as by version
+    registry.set_quarantined(&listing_id, &true);
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::ListingQuarantined)));
+    registry.set_quarantined(&listing_id, &false);
+
+    registry.yank_release(&listing_id, &String::from_str(&env, "1.0.0"));
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::ReleaseYanked)));
+
+    assert_eq!(registry.get_contract(&listing_id).total_deployments, 0);
+    assert_eq!(client.get_total_deployments(), 0);
+}
+
+#[test]
+fn test_predict_address() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let wasm_hash = upload_sample_wasm(&env);
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+
+    let predicted = client.predict_address(&deployer, &salt);
+    assert!(!client.is_address_taken(&deployer, &salt));
+
+    let deployment_id =
+        client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.deployed_contract_address, predicted);
+    assert!(client.is_address_taken(&deployer, &salt));
+
+    // Reusing the salt is reported instead of trapping
+    let result =
+        client.try_deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::AddressTaken)));
+}
+
+#[test]
+fn test_deploy_next_derives_salts() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let release = String::from_str(&env, "1.0.0");
+    let wasm_hash = upload_sample_wasm(&env);
+
+    // Occupy the address for nonce 1 with a caller-provided salt
+    let taken_salt = client.derive_salt(&deployer, &listing_id, &1);
+    client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &taken_salt, &vec![&env]);
+
+    let first = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    let second = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    let first = client.get_deployment(&first);
+    let second = client.get_deployment(&second);
+    assert_eq!(first.nonce, Some(0));
+    assert_eq!(second.nonce, Some(2));
+    assert_eq!(client.get_deployer_nonce(&deployer), 3);
+
+    // The salt can be reproduced from the record
+    assert_eq!(
+        client.derive_salt(&deployer, &listing_id, &second.nonce.unwrap()),
+        second.salt
+    );
+    assert_ne!(
+        first.deployed_contract_address,
+        second.deployed_contract_address
+    );
+}
+
+fn publish_admin_release(env: &Env, registry: &ContractRegistryClient, listing_id: u32) {
+    let wasm_hash = env.deployer().upload_contract_wasm(admin_contract::WASM);
+    registry.publish_release(&listing_id, &String::from_str(env, "2.0.0"), &wasm_hash);
+}
+
+#[test]
+fn test_deploy_batch_links_addresses() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    publish_admin_release(&env, &registry, listing_id);
+
+    let steps = vec![
+        &env,
+        BatchStep {
+            contract_id: listing_id,
+            release: String::from_str(&env, "1.0.0"),
+            init_args: vec![&env],
+            links: vec![&env],
+        },
+        BatchStep {
+            contract_id: listing_id,
+            release: String::from_str(&env, "2.0.0"),
+            init_args: vec![&env, ().into_val(&env), 7u32.into_val(&env)],
+            links: vec![
+                &env,
+                StepLink {
+                    arg_index: 0,
+                    step: 0,
+                },
+            ],
+        },
+    ];
+    let group_id = client.deploy_batch(&deployer, &steps);
+
+    let records = client.get_group_deployments(&group_id);
+    assert_eq!(records.len(), 2);
+    let first = records.get(0).unwrap();
+    let second = records.get(1).unwrap();
+    assert_eq!(first.group_id, Some(group_id));
+    assert_eq!(second.group_id, Some(group_id));
+
+    // The second contract was constructed with the first one's address
+    let instance = admin_contract::Client::new(&env, &second.deployed_contract_address);
+    assert_eq!(instance.admin(), first.deployed_contract_address);
+    assert_eq!(instance.limit(), 7);
+}
+
+#[test]
+fn test_deploy_batch_rolls_back_on_failure() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+
+    let good_step = BatchStep {
+        contract_id: listing_id,
+        release: String::from_str(&env, "1.0.0"),
+        init_args: vec![&env],
+        links: vec![&env],
+    };
+
+    // Second step links to a step that has not run yet
+    let steps = vec![
+        &env,
+        good_step.clone(),
+        BatchStep {
+            links: vec![
+                &env,
+                StepLink {
+                    arg_index: 0,
+                    step: 1,
+                },
+            ],
+            init_args: vec![&env, ().into_val(&env)],
+            ..good_step.clone()
+        },
+    ];
+    let result = client.try_deploy_batch(&deployer, &steps);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    // Unknown release in the last step
+    let steps = vec![
+        &env,
+        good_step.clone(),
+        BatchStep {
+            release: String::from_str(&env, "9.9.9"),
+            ..good_step
+        },
+    ];
+    let result = client.try_deploy_batch(&deployer, &steps);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotFound)));
+
+    assert_eq!(client.get_total_deployments(), 0);
+    assert_eq!(client.get_deployer_nonce(&deployer), 0);
+    assert_eq!(registry.get_contract(&listing_id).total_deployments, 0);
+}
+
+fn admin_template_params(env: &Env, listing_id: u32, admin: &Address) -> TemplateParams {
+    TemplateParams {
+        name: String::from_str(env, "Default admin"),
+        contract_id: listing_id,
+        release: String::from_str(env, "2.0.0"),
+        init_args: vec![env, admin.into_val(env), 5u32.into_val(env)],
+        salt_strategy: SaltStrategy::Derived,
+    }
+}
+
+#[test]
+fn test_deploy_from_template() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let user = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    publish_admin_release(&env, &registry, listing_id);
+
+    let recommended_id =
+        client.create_template(&author, &admin_template_params(&env, listing_id, &author));
+    let own_id = client.create_template(&user, &admin_template_params(&env, listing_id, &user));
+
+    assert!(client.get_template(&recommended_id).recommended);
+    assert!(!client.get_template(&own_id).recommended);
+    assert_eq!(client.get_recommended_templates(&listing_id).len(), 1);
+    assert_eq!(client.get_templates(&user).len(), 1);
+
+    // Derived salts let the same template be deployed repeatedly
+    let first = client.deploy_from_template(&recommended_id, &user, &None);
+    let second = client.deploy_from_template(&recommended_id, &user, &None);
+    assert_ne!(first, second);
+
+    let record = client.get_deployment(&second);
+    let instance = admin_contract::Client::new(&env, &record.deployed_contract_address);
+    assert_eq!(instance.admin(), author);
+    assert_eq!(instance.limit(), 5);
+
+    // Derived templates don't take a salt
+    let salt = BytesN::from_array(&env, &[4u8; 32]);
+    let result = client.try_deploy_from_template(&recommended_id, &user, &Some(salt.clone()));
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    // Switching to provided salts requires one
+    let mut params = admin_template_params(&env, listing_id, &user);
+    params.salt_strategy = SaltStrategy::Provided;
+    client.update_template(&own_id, &params);
+    let result = client.try_deploy_from_template(&own_id, &user, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    let deployment_id = client.deploy_from_template(&own_id, &user, &Some(salt.clone()));
+    assert_eq!(client.get_deployment(&deployment_id).salt, salt);
+}
+
+#[test]
+fn test_delete_template() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    publish_admin_release(&env, &registry, listing_id);
+
+    let template_id =
+        client.create_template(&author, &admin_template_params(&env, listing_id, &author));
+    client.delete_template(&template_id);
+
+    assert_eq!(client.get_templates(&author).len(), 0);
+    assert_eq!(client.get_recommended_templates(&listing_id).len(), 0);
+    let result = client.try_deploy_from_template(&template_id, &author, &None);
+    assert_eq!(result, Err(Ok(Error::TemplateNotFound)));
+}
+
+#[test]
+fn test_redeploy_from_record() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    set_admin_schema(&env, &registry, listing_id);
+    publish_admin_release(&env, &registry, listing_id);
+    let wasm_hash = env.deployer().upload_contract_wasm(admin_contract::WASM);
+
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let original = client.deploy_from_wasm(
+        &listing_id,
+        &deployer,
+        &wasm_hash,
+        &salt,
+        &vec![&env, admin.into_val(&env)],
+    );
+
+    // The record keeps the arguments the instance was constructed with
+    let record = client.get_deployment(&original);
+    assert_eq!(record.init_args.len(), 2);
+
+    let new_salt = BytesN::from_array(&env, &[3u8; 32]);
+    let copy = client.redeploy(&original, &new_salt);
+    let copy_record = client.get_deployment(&copy);
+    assert_eq!(copy_record.wasm_hash, record.wasm_hash);
+    assert_eq!(copy_record.init_args, record.init_args);
+    assert_ne!(
+        copy_record.deployed_contract_address,
+        record.deployed_contract_address
+    );
+
+    let original_instance = admin_contract::Client::new(&env, &record.deployed_contract_address);
+    let copy_instance = admin_contract::Client::new(&env, &copy_record.deployed_contract_address);
+    assert_eq!(copy_instance.admin(), original_instance.admin());
+    assert_eq!(copy_instance.limit(), original_instance.limit());
+
+    let result = client.try_redeploy(&original, &new_salt);
+    assert_eq!(result, Err(Ok(Error::AddressTaken)));
+}
+
+fn publish_legacy_release(env: &Env, registry: &ContractRegistryClient, listing_id: u32) {
+    let wasm_hash = env.deployer().upload_contract_wasm(legacy_contract::WASM);
+    registry.publish_release(&listing_id, &String::from_str(env, "3.0.0"), &wasm_hash);
+}
+
+#[test]
+fn test_deploy_and_call_initializes() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    publish_legacy_release(&env, &registry, listing_id);
+
+    let calls = vec![
+        &env,
+        PostDeployCall {
+            function: Symbol::new(&env, "initialize"),
+            args: vec![&env, admin.into_val(&env), 3u32.into_val(&env)],
+            expected: vec![&env],
+        },
+        PostDeployCall {
+            function: Symbol::new(&env, "admin"),
+            args: vec![&env],
+            expected: vec![&env, admin.into_val(&env)],
+        },
+    ];
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id = client.deploy_and_call(
+        &listing_id,
+        &String::from_str(&env, "3.0.0"),
+        &deployer,
+        &salt,
+        &vec![&env],
+        &calls,
+    );
+
+    let record = client.get_deployment(&deployment_id);
+    let instance = legacy_contract::Client::new(&env, &record.deployed_contract_address);
+    assert_eq!(instance.admin(), admin);
+    assert_eq!(instance.limit(), 3);
+}
+
+#[test]
+fn test_deploy_and_call_aborts_on_failed_checks() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    publish_legacy_release(&env, &registry, listing_id);
+    let release = String::from_str(&env, "3.0.0");
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+
+    // Reading the admin before initialize traps
+    let calls = vec![
+        &env,
+        PostDeployCall {
+            function: Symbol::new(&env, "admin"),
+            args: vec![&env],
+            expected: vec![&env],
+        },
+    ];
+    let result =
+        client.try_deploy_and_call(&listing_id, &release, &deployer, &salt, &vec![&env], &calls);
+    assert_eq!(result, Err(Ok(Error::PostDeployCallFailed)));
+
+    // Unexpected return value
+    let calls = vec![
+        &env,
+        PostDeployCall {
+            function: Symbol::new(&env, "limit"),
+            args: vec![&env],
+            expected: vec![&env, 5u32.into_val(&env)],
+        },
+    ];
+    let result =
+        client.try_deploy_and_call(&listing_id, &release, &deployer, &salt, &vec![&env], &calls);
+    assert_eq!(result, Err(Ok(Error::PostDeployCheckFailed)));
+
+    assert_eq!(client.get_total_deployments(), 0);
+    assert!(!client.is_address_taken(&deployer, &salt));
+}
+
+#[test]
+fn test_deploy_from_bytes_unlisted() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let wasm = Bytes::from_slice(&env, admin_contract::WASM);
+    let admin = Address::generate(&env);
+
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id = client.deploy_from_bytes(
+        &deployer,
+        &wasm,
+        &salt,
+        &vec![&env, admin.into_val(&env), 1u32.into_val(&env)],
+    );
+
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.contract_id, UNLISTED);
+    assert_eq!(record.wasm_hash, BytesN::from(env.crypto().sha256(&wasm)));
+    assert_eq!(client.get_contract_deployments(&UNLISTED).len(), 1);
+    assert_eq!(client.get_deployment_history(&deployer).len(), 1);
+
+    let instance = admin_contract::Client::new(&env, &record.deployed_contract_address);
+    assert_eq!(instance.admin(), admin);
+
+    // Nothing is counted against registry listings
+    assert_eq!(registry.get_contract(&listing_id).total_deployments, 0);
+}
+
+#[test]
+fn test_get_deployment_by_address() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id = client.deploy_from_wasm(
+        &listing_id,
+        &deployer,
+        &upload_sample_wasm(&env),
+        &salt,
+        &vec![&env],
+    );
+
+    let address = client.predict_address(&deployer, &salt);
+    let record = client.get_deployment_by_address(&address);
+    assert_eq!(record.deployment_id, deployment_id);
+    assert_eq!(record.deployer, deployer);
+
+    // Contracts created outside the manager have no provenance
+    let result = client.try_get_deployment_by_address(&registry.address);
+    assert_eq!(result, Err(Ok(Error::ContractNotFound)));
+}
+
+#[test]
+fn test_query_deployments_by_hash_and_release() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let release = String::from_str(&env, "1.0.0");
+    let wasm_hash = upload_sample_wasm(&env);
+
+    for _ in 0..3 {
+        client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    }
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    let by_hash = client.deploy_from_wasm(&listing_id, &deployer, &wasm_hash, &salt, &vec![&env]);
+
+    // Deployments by hash are indexed under the release that published it
+    assert_eq!(
+        client.get_deployment(&by_hash).release,
+        Some(release.clone())
+    );
+    assert_eq!(client.get_hash_deployment_count(&wasm_hash), 4);
+    assert_eq!(
+        client.get_release_deployment_count(&listing_id, &release),
+        4
+    );
+
+    let page = client.get_hash_deployments(&wasm_hash, &2, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().deployment_id, by_hash);
+
+    let page = client.get_release_deployments(&listing_id, &release, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().deployment_id, 1);
+
+    let page = client.get_release_deployments(&listing_id, &release, &5, &2);
+    assert_eq!(page.len(), 0);
+}
+
+fn deploy_upgradeable(
+    env: &Env,
+    client: &DeploymentManagerClient,
+    registry: &ContractRegistryClient,
+) -> (u32, u32) {
+    let listing_id = publish_listing(env, registry, &Address::generate(env));
+    let v1 = env.deployer().upload_contract_wasm(upgradeable_v1::WASM);
+    let v2 = env.deployer().upload_contract_wasm(upgradeable_v2::WASM);
+    registry.publish_release(&listing_id, &String::from_str(env, "1.1.0"), &v1);
+    registry.publish_release(&listing_id, &String::from_str(env, "1.2.0"), &v2);
+
+    // The manager is the instance's admin, so it can run upgrades
+    let deployment_id = client.deploy_from_registry(
+        &listing_id,
+        &String::from_str(env, "1.1.0"),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[2u8; 32]),
+        &vec![env, client.address.into_val(env)],
+    );
+    (listing_id, deployment_id)
+}
+
+#[test]
+fn test_managed_upgrade_and_rollback() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let (listing_id, deployment_id) = deploy_upgradeable(&env, &client, &registry);
+    let record = client.get_deployment(&deployment_id);
+    let instance = upgradeable_v1::Client::new(&env, &record.deployed_contract_address);
+    let v1 = record.wasm_hash.clone();
+    let v1_release = String::from_str(&env, "1.1.0");
+    let v2_release = String::from_str(&env, "1.2.0");
+
+    // Instances are not managed until the deployer opts in
+    let result = client.try_upgrade_deployment(&deployment_id, &v2_release);
+    assert_eq!(result, Err(Ok(Error::UpgradeNotManaged)));
+
+    client.set_managed_upgrades(&deployment_id, &true);
+    client.upgrade_deployment(&deployment_id, &v2_release);
+    assert_eq!(instance.version(), 2);
+
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.release, Some(v2_release.clone()));
+    assert_eq!(record.upgrades.len(), 1);
+    assert_eq!(record.upgrades.get(0).unwrap().from_hash, v1);
+    assert_eq!(client.get_hash_deployment_count(&v1), 0);
+    assert_eq!(
+        client.get_release_deployment_count(&listing_id, &v2_release),
+        1
+    );
+
+    // Code changed outside the manager since the upgrade can't be rolled back
+    instance.upgrade(&v1);
+    let result = client.try_rollback_deployment(&deployment_id);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+    instance.upgrade(&record.wasm_hash);
+
+    // The restored code must meet the deploy policy like an upgrade's
+    client.set_deploy_policy(&Some(DeployPolicy {
+        require_published: false,
+        min_verification: VerificationLevel::Unverified,
+        max_severity: Severity::Medium,
+    }));
+    let author = registry.get_contract(&listing_id).author;
+    let advisory_id = registry.publish_advisory(
+        &author,
+        &listing_id,
+        &v1,
+        &contract_registry::Severity::High,
+        &String::from_str(&env, "Upgrade authority can be hijacked"),
+    );
+    let result = client.try_rollback_deployment(&deployment_id);
+    assert_eq!(result, Err(Ok(Error::HashHasAdvisories)));
+    registry.resolve_advisory(&author, &advisory_id);
+
+    client.rollback_deployment(&deployment_id);
+    assert_eq!(instance.version(), 1);
+    assert_eq!(client.get_observed_hashes(&deployment_id).len(), 3);
+
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.wasm_hash, v1);
+    assert_eq!(record.release, Some(v1_release));
+    assert_eq!(record.upgrades.len(), 2);
+    assert!(record.upgrades.get(1).unwrap().rollback);
+
+    // A rollback can't itself be rolled back
+    let result = client.try_rollback_deployment(&deployment_id);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_upgrades_move_deployments_between_indexes() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let (listing_id, first) = deploy_upgradeable(&env, &client, &registry);
+    let v1 = client.get_deployment(&first).wasm_hash;
+    let v1_release = String::from_str(&env, "1.1.0");
+    let v2_release = String::from_str(&env, "1.2.0");
+    let second = client.deploy_from_registry(
+        &listing_id,
+        &v1_release,
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[3u8; 32]),
+        &vec![&env, client.address.into_val(&env)],
+    );
+    let third = client.deploy_from_registry(
+        &listing_id,
+        &v1_release,
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[4u8; 32]),
+        &vec![&env, client.address.into_val(&env)],
+    );
+
+    client.set_managed_upgrades(&first, &true);
+    client.upgrade_deployment(&first, &v2_release);
+
+    // The remaining deployments stay listed once, in any order
+    let page = client.get_hash_deployments(&v1, &0, &10);
+    assert_eq!(page.len(), 2);
+    let ids: Vec<u32> = vec![
+        &env,
+        page.get(0).unwrap().deployment_id,
+        page.get(1).unwrap().deployment_id,
+    ];
+    assert!(ids.contains(second) && ids.contains(third));
+    let page = client.get_release_deployments(&listing_id, &v2_release, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().deployment_id, first);
+
+    client.set_managed_upgrades(&third, &true);
+    client.upgrade_deployment(&third, &v2_release);
+    let page = client.get_release_deployments(&listing_id, &v1_release, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().deployment_id, second);
+    assert_eq!(
+        client.get_release_deployment_count(&listing_id, &v2_release),
+        2
+    );
+}
+
+#[test]
+fn test_managed_upgrade_rejects_yanked_release() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let (listing_id, deployment_id) = deploy_upgradeable(&env, &client, &registry);
+    client.set_managed_upgrades(&deployment_id, &true);
+
+    let v2_release = String::from_str(&env, "1.2.0");
+    registry.yank_release(&listing_id, &v2_release);
+    let result = client.try_upgrade_deployment(&deployment_id, &v2_release);
+    assert_eq!(result, Err(Ok(Error::ReleaseYanked)));
+
+    // Upgrading to the code the instance already runs is rejected
+    let v1 = client.get_deployment(&deployment_id).wasm_hash;
+    let v1_copy = String::from_str(&env, "1.1.1");
+    registry.publish_release(&listing_id, &v1_copy, &v1);
+    let result = client.try_upgrade_deployment(&deployment_id, &v1_copy);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    // So are downgrades and the current release
+    for version in ["1.0.0", "1.1.0"] {
+        let release = String::from_str(&env, version);
+        let result = client.try_upgrade_deployment(&deployment_id, &release);
+        assert_eq!(result, Err(Ok(Error::ReleaseNotNewer)));
+    }
+}
+
+#[test]
+fn test_sync_deployment_tracks_outside_upgrades() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let (listing_id, deployment_id) = deploy_upgradeable(&env, &client, &registry);
+    let record = client.get_deployment(&deployment_id);
+    let v1 = record.wasm_hash.clone();
+
+    // Nothing changed yet; the history starts with the deployed code
+    assert_eq!(client.sync_deployment(&deployment_id), v1);
+    let observed = client.get_observed_hashes(&deployment_id);
+    assert_eq!(observed.len(), 1);
+    assert_eq!(observed.get(0).unwrap().wasm_hash, v1);
+
+    // The admin upgrades the instance directly, bypassing the manager
+    let v2 = env.deployer().upload_contract_wasm(upgradeable_v2::WASM);
+    upgradeable_v1::Client::new(&env, &record.deployed_contract_address).upgrade(&v2);
+
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.sync_deployment(&deployment_id), v2);
+
+    let record = client.get_deployment(&deployment_id);
+    let v2_release = String::from_str(&env, "1.2.0");
+    assert_eq!(record.wasm_hash, v2);
+    assert_eq!(record.release, Some(v2_release.clone()));
+    assert_eq!(
+        client.get_release_deployment_count(&listing_id, &v2_release),
+        1
+    );
+
+    let observed = client.get_observed_hashes(&deployment_id);
+    assert_eq!(observed.len(), 2);
+    let entry = observed.get(1).unwrap();
+    assert_eq!(entry.wasm_hash, v2);
+    assert_eq!(entry.release, Some(v2_release));
+    assert_eq!(entry.observed_at, 500);
+}
+
+#[test]
+fn test_keep_alive_pool() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let token_id = create_token(&env, &deployer, 1_000);
+    let token = token::Client::new(&env, &token_id);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let deployment_id = client.deploy_next(
+        &listing_id,
+        &String::from_str(&env, "1.0.0"),
+        &deployer,
+        &vec![&env],
+    );
+
+    let result = client.try_fund_keep_alive(&deployment_id, &100);
+    assert_eq!(result, Err(Ok(Error::KeepAliveNotConfigured)));
+
+    client.set_keep_alive_config(&KeepAliveConfig {
+        token: token_id.clone(),
+        fee: 40,
+        threshold: 100,
+        extend_to: 1_000,
+    });
+    client.fund_keep_alive(&deployment_id, &100);
+    assert_eq!(token.balance(&deployer), 900);
+
+    // Not due yet
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 0);
+
+    env.ledger().set_sequence_number(950);
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 1);
+    assert_eq!(token.balance(&keeper), 40);
+
+    let keep_alive = client.get_keep_alive(&deployment_id).unwrap();
+    assert_eq!(keep_alive.balance, 60);
+    assert_eq!(keep_alive.extended_at, 950);
+
+    // Extended instances aren't due again until close to expiring
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 0);
+    env.ledger().set_sequence_number(1_900);
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 1);
+
+    // The remaining 20 doesn't cover another fee
+    env.ledger().set_sequence_number(2_850);
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 0);
+
+    assert_eq!(client.leave_keep_alive(&deployment_id), 20);
+    assert_eq!(token.balance(&deployer), 920);
+    assert_eq!(client.get_keep_alive(&deployment_id), None);
+    assert_eq!(client.get_keep_alive_pool_size(), 0);
+}
+
+#[test]
+fn test_keep_alive_pool_pages_and_decommission() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let token_id = create_token(&env, &deployer, 1_000);
+    let token = token::Client::new(&env, &token_id);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let release = String::from_str(&env, "1.0.0");
+    client.set_keep_alive_config(&KeepAliveConfig {
+        token: token_id.clone(),
+        fee: 10,
+        threshold: 100,
+        extend_to: 1_000,
+    });
+
+    let mut deployments = Vec::new(&env);
+    for _ in 0..3 {
+        let deployment_id = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+        client.fund_keep_alive(&deployment_id, &100);
+        deployments.push_back(deployment_id);
+    }
+    assert_eq!(client.get_keep_alive_pool_size(), 3);
+
+    // Keepers work through the pool a page at a time
+    env.ledger().set_sequence_number(950);
+    assert_eq!(client.extend_instances(&keeper, &0, &2), 2);
+    assert_eq!(client.extend_instances(&keeper, &0, &2), 0);
+    assert_eq!(client.extend_instances(&keeper, &2, &2), 1);
+    assert_eq!(client.extend_instances(&keeper, &3, &2), 0);
+    assert_eq!(token.balance(&keeper), 30);
+
+    // Decommissioned instances leave the pool but keep their deposit
+    let retired = deployments.get(0).unwrap();
+    client.set_deployment_status(&retired, &DeploymentStatus::Decommissioned);
+    assert_eq!(client.get_keep_alive_pool_size(), 2);
+    let result = client.try_fund_keep_alive(&retired, &50);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    env.ledger().set_sequence_number(1_900);
+    assert_eq!(client.extend_instances(&keeper, &0, &10), 2);
+    assert_eq!(client.get_keep_alive(&retired).unwrap().balance, 90);
+    assert_eq!(client.leave_keep_alive(&retired), 90);
+}
+
+#[test]
+fn test_deployment_info_and_filters() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let release = String::from_str(&env, "1.0.0");
+    let staging = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    let production = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    let project = String::from_str(&env, "vault");
+    let info = DeploymentInfo {
+        labels: vec![&env, String::from_str(&env, "token")],
+        environment: DeploymentEnvironment::Staging,
+        note: String::from_str(&env, "Testnet rehearsal"),
+        project: project.clone(),
+    };
+    client.set_deployment_info(&staging, &info);
+    client.set_deployment_info(
+        &production,
+        &DeploymentInfo {
+            environment: DeploymentEnvironment::Production,
+            ..info.clone()
+        },
+    );
+
+    assert_eq!(client.get_deployment(&staging).info, info);
+    assert_eq!(client.get_project_deployments(&deployer, &project).len(), 2);
+
+    let records = client.get_environment_deployments(&deployer, &DeploymentEnvironment::Production);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records.get(0).unwrap().deployment_id, production);
+
+    let records =
+        client.get_environment_deployments(&deployer, &DeploymentEnvironment::Unspecified);
+    assert_eq!(records.len(), 1);
+
+    let result = client.try_set_deployment_info(
+        &staging,
+        &DeploymentInfo {
+            labels: vec![&env, String::from_str(&env, "")],
+            ..info
+        },
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+fn test_deployment_lifecycle() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+    let release = String::from_str(&env, "1.0.0");
+    let first = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    let second = client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    // Hidden records drop out of public listings but stay with their owner
+    client.set_deployment_hidden(&first, &true);
+    assert_eq!(client.get_all_deployments().len(), 1);
+    assert_eq!(client.get_contract_deployments(&listing_id).len(), 1);
+    assert_eq!(client.get_deployment_history(&deployer).len(), 1);
+    assert_eq!(client.get_owned_deployments(&deployer).len(), 2);
+
+    client.set_deployment_status(&first, &DeploymentStatus::Archived);
+    assert_eq!(
+        client.get_deployment(&first).status,
+        DeploymentStatus::Archived
+    );
+
+    client.transfer_deployment(&second, &multisig);
+    let record = client.get_deployment(&second);
+    assert_eq!(record.owner, multisig);
+    assert_eq!(record.deployer, deployer);
+    assert_eq!(client.get_owned_deployments(&deployer).len(), 1);
+    assert_eq!(client.get_owned_deployments(&multisig).len(), 1);
+
+    client.set_deployment_status(&second, &DeploymentStatus::Decommissioned);
+    let result = client.try_set_deployment_status(&second, &DeploymentStatus::Active);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_deployment_requires_owner() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry.mock_all_auths(), &Address::generate(&env));
+    let deployment_id = client.mock_all_auths().deploy_next(
+        &listing_id,
+        &String::from_str(&env, "1.0.0"),
+        &deployer,
+        &vec![&env],
+    );
+
+    // No authorization from the owner
+    client.transfer_deployment(&deployment_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_deploy_policy() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let deployer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    let release = String::from_str(&env, "1.0.0");
+    let sample_hash = upload_sample_wasm(&env);
+
+    // Unpublished hashes are rejected once the global policy requires publication
+    client.set_deploy_policy(&Some(DeployPolicy {
+        require_published: true,
+        min_verification: VerificationLevel::Unverified,
+        max_severity: Severity::Critical,
+    }));
+    let wasm = Bytes::from_slice(&env, admin_contract::WASM);
+    let admin_args = vec![&env, deployer.into_val(&env), 1u32.into_val(&env)];
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let result = client.try_deploy_from_bytes(&deployer, &wasm, &salt, &admin_args);
+    assert_eq!(result, Err(Ok(Error::HashNotPublished)));
+    client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    // The deployer opts into stricter rules on top of the global policy
+    client.set_deployer_policy(
+        &deployer,
+        &Some(DeployPolicy {
+            require_published: true,
+            min_verification: VerificationLevel::Community,
+            max_severity: Severity::Low,
+        }),
+    );
+    let result = client.try_deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::HashNotVerified)));
+
+    registry.verify_contract(&listing_id, &Address::generate(&env));
+    client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    let advisory_id = registry.publish_advisory(
+        &author,
+        &listing_id,
+        &sample_hash,
+        &contract_registry::Severity::Medium,
+        &String::from_str(&env, "Storage can be overwritten"),
+    );
+    let result = client.try_deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+    assert_eq!(result, Err(Ok(Error::HashHasAdvisories)));
+
+    // Other deployers only answer to the global policy
+    client.deploy_next(&listing_id, &release, &Address::generate(&env), &vec![&env]);
+
+    registry.resolve_advisory(&author, &advisory_id);
+    client.deploy_next(&listing_id, &release, &deployer, &vec![&env]);
+
+    client.set_deploy_policy(&None);
+    client.set_deployer_policy(&deployer, &None);
+    client.deploy_from_bytes(&deployer, &wasm, &salt, &admin_args);
+}
+
+fn intent_params(env: &Env, listing_id: u32, approvers: Vec<Address>) -> IntentParams {
+    IntentParams {
+        contract_id: listing_id,
+        release: String::from_str(env, "1.0.0"),
+        init_args: vec![env],
+        salt: BytesN::from_array(env, &[5u8; 32]),
+        approvers,
+        threshold: 2,
+        deadline: 1_000,
+    }
+}
+
+#[test]
+fn test_deployment_intent() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let proposer = Address::generate(&env);
+    let executor = Address::generate(&env);
+    let reviewer_a = Address::generate(&env);
+    let reviewer_b = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+
+    let token_id = create_token(&env, &executor, 1_000);
+    registry.set_deploy_price(
+        &listing_id,
+        &Some(contract_registry::DeployPrice {
+            token: token_id.clone(),
+            amount: 100,
+        }),
+    );
+
+    // Proposers can't approve their own intent
+    let approvers = vec![&env, reviewer_a.clone(), proposer.clone()];
+    let params = intent_params(&env, listing_id, approvers);
+    let result = client.try_propose_intent(&proposer, &params);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    let approvers = vec![&env, reviewer_a.clone(), reviewer_b.clone()];
+    let params = intent_params(&env, listing_id, approvers);
+    let intent_id = client.propose_intent(&proposer, &params);
+
+    client.approve_intent(&reviewer_a, &intent_id);
+    let result = client.try_execute_intent(&executor, &intent_id);
+    assert_eq!(result, Err(Ok(Error::IntentNotApproved)));
+
+    // Outsiders and repeat approvals don't count
+    let result = client.try_approve_intent(&Address::generate(&env), &intent_id);
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+    let result = client.try_approve_intent(&reviewer_a, &intent_id);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    client.approve_intent(&reviewer_b, &intent_id);
+    let deployment_id = client.execute_intent(&executor, &intent_id);
+
+    let record = client.get_deployment(&deployment_id);
+    assert_eq!(record.intent_id, Some(intent_id));
+    assert_eq!(record.deployer, proposer);
+    assert_eq!(record.owner, proposer);
+    let salt = client.derive_intent_salt(&proposer, &params.salt);
+    assert_ne!(salt, params.salt);
+    assert_eq!(
+        record.deployed_contract_address,
+        client.predict_address(&client.address, &salt)
+    );
+    assert_eq!(
+        client.get_intent(&intent_id).deployment_id,
+        Some(deployment_id)
+    );
+
+    // The executor paid the deploy price
+    assert_eq!(token::Client::new(&env, &token_id).balance(&author), 100);
+
+    let result = client.try_execute_intent(&executor, &intent_id);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    // Another proposer asking for the same salt gets a different address
+    let other = Address::generate(&env);
+    let other_intent = client.propose_intent(&other, &params);
+    client.approve_intent(&reviewer_a, &other_intent);
+    client.approve_intent(&reviewer_b, &other_intent);
+    let other_id = client.execute_intent(&executor, &other_intent);
+    assert_ne!(
+        client.get_deployment(&other_id).deployed_contract_address,
+        record.deployed_contract_address
+    );
+}
+
+#[test]
+fn test_deployment_intent_expires() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let reviewer = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &Address::generate(&env));
+
+    let mut params = intent_params(&env, listing_id, vec![&env, reviewer.clone()]);
+    let result = client.try_propose_intent(&Address::generate(&env), &params);
+    assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+    params.threshold = 1;
+    let intent_id = client.propose_intent(&Address::generate(&env), &params);
+    client.approve_intent(&reviewer, &intent_id);
+
+    env.ledger().set_timestamp(1_001);
+    let result = client.try_execute_intent(&Address::generate(&env), &intent_id);
+    assert_eq!(result, Err(Ok(Error::IntentExpired)));
+}
+
+#[test]
+fn test_sponsored_deployments() {
+    let env = Env::default();
+    let (client, registry) = create_manager(&env);
+
+    env.mock_all_auths();
+
+    let author = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let listing_id = publish_listing(&env, &registry, &author);
+    let release = String::from_str(&env, "1.0.0");
+
+    let token_id = create_token(&env, &sponsor, 1_000);
+    let token = token::Client::new(&env, &token_id);
+    registry.set_deploy_price(
+        &listing_id,
+        &Some(contract_registry::DeployPrice {
+            token: token_id.clone(),
+            amount: 150,
+        }),
+    );
+
+    let params = SponsorshipParams {
+        token: token_id.clone(),
+        deployers: vec![&env, partner.clone()],
+        listings: vec![&env, listing_id],
+    };
+    let sponsorship_id = client.create_sponsorship(&sponsor, &params, &400);
+    assert_eq!(token.balance(&sponsor), 600);
+
+    let salt = BytesN::from_array(&env, &[2u8; 32]);
+    let deployment_id = client.deploy_sponsored(
+        &sponsorship_id,
+        &listing_id,
+        &release,
+        &partner,
+        &salt,
+        &vec![&env],
+    );
+
+    // The author was paid from the budget, not by the partner
+    assert_eq!(token.balance(&author), 150);
+    assert_eq!(token.balance(&partner), 0);
+    assert_eq!(token.balance(&client.address), 250);
+
+    let sponsorship = client.get_sponsorship(&sponsorship_id);
+    assert_eq!(sponsorship.balance, 250);
+    assert_eq!(sponsorship.spent, 150);
+    assert_eq!(sponsorship.deployment_count, 1);
+
+    let usage = client.get_sponsorship_usage(&sponsorship_id);
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage.get(0).unwrap().deployment_id, deployment_id);
+    assert_eq!(usage.get(0).unwrap().amount, 150);
+
+    // Deployers outside the allowlist can't draw from the budget
+    let salt = BytesN::from_array(&env, &[3u8; 32]);
+    let outsider = Address::generate(&env);
+    let result = client.try_deploy_sponsored(
+        &sponsorship_id,
+        &listing_id,
+        &release,
+        &outsider,
+        &salt,
+        &vec![&env],
+    );
+    assert_eq!(result, Err(Ok(Error::UnauthorizedAccess)));
+
+    client.deploy_sponsored(
+        &sponsorship_id,
+        &listing_id,
+        &release,
+        &partner,
+        &salt,
+        &vec![&env],
+    );
+
+    // 100 left doesn't cover another deployment
+    let salt = BytesN::from_array(&env, &[4u8; 32]);
+    let result = client.try_deploy_sponsored(
+        &sponsorship_id,
+        &listing_id,
+        &release,
+        &partner,
+        &salt,
+        &vec![&env],
+    );
+    assert_eq!(result, Err(Ok(Error::SponsorshipExhausted)));
+
+    assert_eq!(client.withdraw_sponsorship(&sponsorship_id), 100);
+    assert_eq!(token.balance(&sponsor), 700);
+    assert_eq!(client.get_sponsorship(&sponsorship_id).balance, 0);
+    assert_eq!(client.get_sponsorships(&sponsor).len(), 1);
+}
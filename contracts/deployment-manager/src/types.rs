@@ -1,4 +1,19 @@
-use soroban_sdk::{contracterror, contracttype, Address, BytesN};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, String, Symbol, Val, Vec};
+
+/// `contract_id` of deployments that are not registry listings
+pub const UNLISTED: u32 = 0;
+
+/// Most approvers an intent can name
+pub const MAX_INTENT_APPROVERS: u32 = 10;
+
+/// Most deployers a sponsorship can cover
+pub const MAX_SPONSORED_DEPLOYERS: u32 = 50;
+
+/// Limits on the deployer-maintained DeploymentInfo
+pub const MAX_LABELS: u32 = 10;
+pub const MAX_LABEL_LEN: u32 = 32;
+pub const MAX_NOTE_LEN: u32 = 500;
+pub const MAX_PROJECT_LEN: u32 = 64;
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -10,6 +25,380 @@ pub struct DeploymentRecord {
     pub deployed_at: u64,
     pub wasm_hash: BytesN<32>,
     pub salt: BytesN<32>,
+    pub release: Option<String>, // Registry release that published the code, if any
+    pub nonce: Option<u64>,      // Nonce the salt was derived from, when derived by the manager
+    pub group_id: Option<u32>,   // Batch the deployment was part of
+    pub init_args: Vec<Val>,     // Constructor arguments, after schema defaults were applied
+    pub managed: bool,           // Upgrades are run by the manager on the deployer's behalf
+    pub upgrades: Vec<UpgradeEntry>, // Code changes made through the manager, oldest first
+    pub info: DeploymentInfo,
+    pub owner: Address, // Manages the record; starts as the deployer
+    pub status: DeploymentStatus,
+    pub hidden: bool,           // Left out of public listings
+    pub intent_id: Option<u32>, // Approved intent the deployment executed
+}
+
+/// Lifecycle stage of a deployment record
+/// Decommissioned is final
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum DeploymentStatus {
+    Active = 0,
+    Archived = 1,
+    Decommissioned = 2,
+}
+
+/// Environment a deployment serves
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum DeploymentEnvironment {
+    Unspecified = 0,
+    Dev = 1,
+    Staging = 2,
+    Production = 3,
+}
+
+/// Deployer-maintained description of a deployment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentInfo {
+    pub labels: Vec<String>,
+    pub environment: DeploymentEnvironment,
+    pub note: String,
+    pub project: String, // Empty when not part of a project
+}
+
+impl DeploymentInfo {
+    pub fn empty(env: &Env) -> Self {
+        DeploymentInfo {
+            labels: Vec::new(env),
+            environment: DeploymentEnvironment::Unspecified,
+            note: String::from_str(env, ""),
+            project: String::from_str(env, ""),
+        }
+    }
+}
+
+/// One code change of a managed instance
+/// wasm_hash and release are the current code; a rollback restores the entry it undoes
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpgradeEntry {
+    pub from_hash: BytesN<32>,
+    pub from_release: Option<String>,
+    pub to_hash: BytesN<32>,
+    pub to_release: Option<String>,
+    pub upgraded_at: u64,
+    pub rollback: bool,
+}
+
+/// One listing release deployed as part of a batch
+/// Salts are derived by the manager as in deploy_next
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchStep {
+    pub contract_id: u32,
+    pub release: String,
+    pub init_args: Vec<Val>,
+    pub links: Vec<StepLink>,
+}
+
+/// Replace constructor argument `arg_index` with the address deployed by an earlier `step`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepLink {
+    pub arg_index: u32,
+    pub step: u32,
+}
+
+/// Requirements a WASM hash must meet before it is deployed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployPolicy {
+    pub require_published: bool, // Released by at least one registry listing
+    pub min_verification: VerificationLevel, // Lowest verification of the hash allowed
+    pub max_severity: Severity,  // Highest unresolved advisory severity allowed
+}
+
+/// How strongly a build was verified (mirrors the ContractRegistry type)
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum VerificationLevel {
+    Unverified = 0,
+    Community = 1,
+    Audited = 2,
+}
+
+/// Advisory severity (mirrors the ContractRegistry type)
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum Severity {
+    None = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+    Critical = 4,
+}
+
+/// What the registry knows about a WASM hash (mirrors the ContractRegistry type)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashReport {
+    pub listings: Vec<u32>,
+    pub verification: VerificationLevel,
+    pub highest_severity: Severity,
+}
+
+/// A published version of a listing's code (mirrors the ContractRegistry type)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Release {
+    pub version: String,
+    pub wasm_hash: BytesN<32>,
+    pub published_at: u64,
+    pub yanked: bool,
+}
+
+/// Fully specified deployment proposed for approval
+/// approvers: Addresses allowed to approve, threshold of which must approve
+/// deadline: Timestamp after which the intent can no longer be approved or executed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntentParams {
+    pub contract_id: u32,
+    pub release: String,
+    pub init_args: Vec<Val>,
+    pub salt: BytesN<32>,
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+    pub deadline: u64,
+}
+
+/// Deployment waiting for, or executed after, multi-party approval
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentIntent {
+    pub intent_id: u32,
+    pub proposer: Address,
+    pub contract_id: u32,
+    pub release: String,
+    pub init_args: Vec<Val>,
+    pub salt: BytesN<32>, // As proposed; execution deploys with derive_intent_salt(proposer, salt)
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+    pub deadline: u64,
+    pub approvals: Vec<Address>,
+    pub deployment_id: Option<u32>, // Set once executed
+}
+
+/// Who a sponsorship pays for
+/// listings: Listings it covers, or empty for any listing
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SponsorshipParams {
+    pub token: Address,
+    pub deployers: Vec<Address>,
+    pub listings: Vec<u32>,
+}
+
+/// Budget a sponsor set aside for other deployers' deploy prices
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sponsorship {
+    pub sponsorship_id: u32,
+    pub sponsor: Address,
+    pub token: Address,
+    pub deployers: Vec<Address>,
+    pub listings: Vec<u32>,
+    pub balance: i128,
+    pub spent: i128,
+    pub deployment_count: u32,
+}
+
+/// One deployment paid from a sponsorship
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SponsoredDeployment {
+    pub deployment_id: u32,
+    pub deployer: Address,
+    pub contract_id: u32,
+    pub amount: i128,
+    pub deployed_at: u64,
+}
+
+/// Terms of the keep-alive pool (set by the admin)
+/// Instances are extended to `extend_to` ledgers once fewer than `threshold` remain,
+/// and each extension pays `fee` of `token` from the instance's deposit to the caller
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeepAliveConfig {
+    pub token: Address,
+    pub fee: i128,
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Keep-alive deposit of a deployment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeepAlive {
+    pub balance: i128,
+    pub extended_at: u32, // Ledger of the last extension
+}
+
+/// Code an instance ran, recorded at deployment, on managed upgrades and by syncs
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObservedHash {
+    pub wasm_hash: BytesN<32>,
+    pub release: Option<String>, // Release of the record's listing that published the hash
+    pub observed_at: u64,
+}
+
+/// Invocation on a freshly deployed instance, e.g. a legacy `initialize`
+/// expected: Empty to accept any return value, or the single value it must return
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PostDeployCall {
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    pub expected: Vec<Val>,
+}
+
+/// How deployments from a template get their salt
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum SaltStrategy {
+    Derived = 0,  // Derived by the manager from the deployer's nonce, as in deploy_next
+    Provided = 1, // Passed by the deployer on every deployment
+}
+
+/// Template parameters set by its owner
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateParams {
+    pub name: String,
+    pub contract_id: u32,
+    pub release: String,
+    pub init_args: Vec<Val>,
+    pub salt_strategy: SaltStrategy,
+}
+
+/// Saved deployment configuration for a listing release
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentTemplate {
+    pub template_id: u32,
+    pub owner: Address,
+    pub name: String,
+    pub contract_id: u32,
+    pub release: String,
+    pub init_args: Vec<Val>,
+    pub salt_strategy: SaltStrategy,
+    pub recommended: bool, // Owned by the listing's author
+}
+
+/// Share of every deploy price kept by the platform
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlatformFee {
+    pub recipient: Address,
+    pub fee_bps: u32,
+}
+
+/// Whether a license covers every deployment by its holder or a single one
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum LicenseScope {
+    PerDeployer = 0,
+    PerInstance = 1,
+}
+
+/// License terms an author sells for a listing
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LicenseOffer {
+    pub offer_id: u32,
+    pub contract_id: u32,
+    pub token: Address,
+    pub price: i128,
+    pub duration: u64, // Seconds of validity, 0 = perpetual
+    pub scope: LicenseScope,
+    pub active: bool,
+}
+
+/// A license bought from an offer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct License {
+    pub license_id: u32,
+    pub offer_id: u32,
+    pub contract_id: u32,
+    pub holder: Address,
+    pub scope: LicenseScope,
+    pub purchased_at: u64,
+    pub expires_at: Option<u64>,
+    pub deployment_id: Option<u32>, // Set once a per-instance license is used
+}
+
+// Mirrors of the ContractRegistry payout types (see registry.rs)
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployPrice {
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevenueShare {
+    pub recipient: Address,
+    pub share_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutTerms {
+    pub author: Address,
+    pub shares: Vec<RevenueShare>,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum ParamType {
+    Any = 0,
+    Bool = 1,
+    U32 = 2,
+    I32 = 3,
+    U64 = 4,
+    I64 = 5,
+    U128 = 6,
+    I128 = 7,
+    U256 = 8,
+    I256 = 9,
+    Address = 10,
+    String = 11,
+    Symbol = 12,
+    Bytes = 13,
+    Vec = 14,
+    Map = 15,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorParam {
+    pub name: String,
+    pub param_type: ParamType,
+    pub description: String,
+    pub default_value: Vec<Val>, // Empty if required, otherwise the single default
 }
 
 #[contracterror]
@@ -21,4 +410,27 @@ pub enum Error {
     InvalidParameters = 3,
     InvalidWasmHash = 4,
     UnauthorizedAccess = 5,
+    LicenseRequired = 6,
+    OfferNotFound = 7,
+    OfferInactive = 8,
+    ReleaseNotFound = 9,
+    ReleaseYanked = 10,
+    ListingQuarantined = 11,
+    AddressTaken = 12,
+    TemplateNotFound = 13,
+    PostDeployCallFailed = 14,
+    PostDeployCheckFailed = 15,
+    UpgradeNotManaged = 16,
+    UpgradeFailed = 17,
+    KeepAliveNotConfigured = 18,
+    HashNotPublished = 19,
+    HashNotVerified = 20,
+    HashHasAdvisories = 21,
+    IntentNotFound = 22,
+    IntentExpired = 23,
+    IntentNotApproved = 24,
+    SponsorshipNotFound = 25,
+    SponsorshipExhausted = 26,
+    ReleaseNotNewer = 27,
+    PaymentMismatch = 28,
 }
@@ -0,0 +1,121 @@
+use soroban_sdk::{symbol_short, vec, BytesN, Env, Executable, IntoVal, String, Symbol};
+
+use crate::types::{DeploymentRecord, Error, ObservedHash, UpgradeEntry, UNLISTED};
+use crate::{registry, storage, DeploymentSynced};
+
+/// Entry point managed instances expose for code upgrades
+/// It must accept the manager as the authority allowed to upgrade
+const UPGRADE_FN: Symbol = symbol_short!("upgrade");
+
+/// Switch a managed instance to new code and record the change
+/// The hash and release indexes follow the instance to its new code
+pub fn apply(
+    env: &Env,
+    mut record: DeploymentRecord,
+    to_hash: BytesN<32>,
+    to_release: Option<String>,
+    rollback: bool,
+) -> Result<(), Error> {
+    if !record.managed {
+        return Err(Error::UpgradeNotManaged);
+    }
+    if to_hash == record.wasm_hash {
+        return Err(Error::InvalidParameters);
+    }
+
+    let args = vec![env, to_hash.into_val(env)];
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &record.deployed_contract_address,
+        &UPGRADE_FN,
+        args,
+    );
+    if !matches!(result, Ok(Ok(()))) {
+        return Err(Error::UpgradeFailed);
+    }
+
+    move_indexes(env, &record, &to_hash, &to_release);
+    observe(env, record.deployment_id, &to_hash, &to_release);
+
+    record.upgrades.push_back(UpgradeEntry {
+        from_hash: record.wasm_hash.clone(),
+        from_release: record.release.clone(),
+        to_hash: to_hash.clone(),
+        to_release: to_release.clone(),
+        upgraded_at: env.ledger().timestamp(),
+        rollback,
+    });
+    record.wasm_hash = to_hash;
+    record.release = to_release;
+    storage::save_deployment(env, record.deployment_id, &record);
+
+    Ok(())
+}
+
+/// Get the WASM hash an instance currently runs
+pub fn current_hash(record: &DeploymentRecord) -> Result<BytesN<32>, Error> {
+    match record.deployed_contract_address.executable() {
+        Some(Executable::Wasm(wasm_hash)) => Ok(wasm_hash),
+        _ => Err(Error::ContractNotFound),
+    }
+}
+
+/// Add the code an instance now runs to its observed hash history
+pub fn observe(env: &Env, deployment_id: u32, wasm_hash: &BytesN<32>, release: &Option<String>) {
+    storage::add_observed_hash(
+        env,
+        deployment_id,
+        &ObservedHash {
+            wasm_hash: wasm_hash.clone(),
+            release: release.clone(),
+            observed_at: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Update a record to the code its instance currently runs
+/// Returns the current WASM hash
+pub fn sync(env: &Env, mut record: DeploymentRecord) -> Result<BytesN<32>, Error> {
+    let wasm_hash = current_hash(&record)?;
+    if wasm_hash == record.wasm_hash {
+        return Ok(wasm_hash);
+    }
+
+    let release = if record.contract_id == UNLISTED {
+        None
+    } else {
+        registry::find_release_by_hash(env, record.contract_id, &wasm_hash)
+    };
+
+    move_indexes(env, &record, &wasm_hash, &release);
+    observe(env, record.deployment_id, &wasm_hash, &release);
+
+    record.wasm_hash = wasm_hash.clone();
+    record.release = release;
+    storage::save_deployment(env, record.deployment_id, &record);
+
+    DeploymentSynced {
+        deployment_id: record.deployment_id,
+        wasm_hash: wasm_hash.clone(),
+    }
+    .publish(env);
+
+    Ok(wasm_hash)
+}
+
+/// Move a deployment between the hash and release indexes
+fn move_indexes(
+    env: &Env,
+    record: &DeploymentRecord,
+    to_hash: &BytesN<32>,
+    to_release: &Option<String>,
+) {
+    let deployment_id = record.deployment_id;
+    storage::remove_from_hash_deployments(env, &record.wasm_hash, deployment_id);
+    storage::add_to_hash_deployments(env, to_hash, deployment_id);
+    if let Some(release) = &record.release {
+        storage::remove_from_release_deployments(env, record.contract_id, release, deployment_id);
+    }
+    if let Some(release) = to_release {
+        storage::add_to_release_deployments(env, record.contract_id, release, deployment_id);
+    }
+}
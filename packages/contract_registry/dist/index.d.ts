@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
-import type { u32, u64, Option } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -14,6 +14,7 @@ export interface PublishParams {
     category: Category;
     description: string;
     documentation_url: string;
+    forked_from: ForkedFrom;
     license: string;
     name: string;
     source_url: string;
@@ -26,10 +27,12 @@ export interface ContractMetadata {
     category: Category;
     description: string;
     documentation_url: string;
+    forked_from: ForkedFrom;
     id: u32;
     license: string;
     name: string;
     published_at: u64;
+    quarantined: boolean;
     source_url: string;
     tags: Array<string>;
     total_deployments: u32;
@@ -38,6 +41,143 @@ export interface ContractMetadata {
     version: string;
     wasm_hash: Buffer;
 }
+/**
+ * A published version of a contract's code
+ */
+export interface Release {
+    published_at: u64;
+    version: string;
+    wasm_hash: Buffer;
+    yanked: boolean;
+}
+/**
+ * The listing release a contract was derived from
+ */
+export interface ForkSource {
+    contract_id: u32;
+    release: string;
+}
+/**
+ * Whether a contract is original work or a modified copy of another listing
+ * An enum rather than Option<ForkSource>: the SDK's testutils can't convert
+ * optional struct fields of contract types
+ */
+export type ForkedFrom = {
+    tag: "Original";
+    values: void;
+} | {
+    tag: "Fork";
+    values: readonly [ForkSource];
+};
+/**
+ * Price charged to deployers each time the contract is deployed
+ */
+export interface DeployPrice {
+    amount: i128;
+    token: string;
+}
+/**
+ * Portion of author revenue paid to a co-author, in basis points
+ */
+export interface RevenueShare {
+    recipient: string;
+    share_bps: u32;
+}
+/**
+ * Who gets paid for a contract
+ * (the author receives whatever the co-author shares leave over)
+ */
+export interface PayoutTerms {
+    author: string;
+    shares: Array<RevenueShare>;
+}
+/**
+ * A listing this contract needs alongside it, with the versions it works with
+ */
+export interface Dependency {
+    contract_id: u32;
+    version_req: string;
+}
+/**
+ * Type of a constructor parameter
+ */
+export declare enum ParamType {
+    Any = 0,
+    Bool = 1,
+    U32 = 2,
+    I32 = 3,
+    U64 = 4,
+    I64 = 5,
+    U128 = 6,
+    I128 = 7,
+    U256 = 8,
+    I256 = 9,
+    Address = 10,
+    String = 11,
+    Symbol = 12,
+    Bytes = 13,
+    Vec = 14,
+    Map = 15
+}
+/**
+ * One constructor parameter, in the order the constructor takes them
+ */
+export interface ConstructorParam {
+    default_value: Array<any>;
+    description: string;
+    name: string;
+    param_type: ParamType;
+}
+/**
+ * Running tip totals in one token, for a listing or an author
+ */
+export interface TipTotals {
+    supporter_count: u32;
+    tip_count: u32;
+    total_amount: i128;
+}
+/**
+ * How serious a security advisory is
+ * None is only used in reports, for hashes without open advisories
+ */
+export declare enum Severity {
+    None = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+    Critical = 4
+}
+/**
+ * Security advisory against one build of a listing
+ */
+export interface Advisory {
+    advisory_id: u32;
+    by_admin: boolean;
+    contract_id: u32;
+    description: string;
+    published_at: u64;
+    resolved: boolean;
+    severity: Severity;
+    wasm_hash: Buffer;
+}
+/**
+ * How strongly a build was verified
+ * Anyone can give a Community verification; Audited ones come from auditors
+ * approved by the admin
+ */
+export declare enum VerificationLevel {
+    Unverified = 0,
+    Community = 1,
+    Audited = 2
+}
+/**
+ * What the registry knows about a WASM hash
+ */
+export interface HashReport {
+    highest_severity: Severity;
+    listings: Array<u32>;
+    verification: VerificationLevel;
+}
 export declare enum Category {
     DeFi = 0,
     NFT = 1,
@@ -66,6 +206,42 @@ export declare const Errors: {
     6: {
         message: string;
     };
+    7: {
+        message: string;
+    };
+    8: {
+        message: string;
+    };
+    9: {
+        message: string;
+    };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    14: {
+        message: string;
+    };
+    15: {
+        message: string;
+    };
+    16: {
+        message: string;
+    };
+    17: {
+        message: string;
+    };
+    18: {
+        message: string;
+    };
 };
 export interface Client {
     /**
@@ -113,11 +289,14 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get metadata for a specific contract
+     * Construct and simulate a publish_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Publish a new version of a contract's code (author only)
+     * The new release becomes the listing's current version
      */
-    get_contract: ({ contract_id }: {
+    publish_release: ({ contract_id, version, wasm_hash }: {
         contract_id: u32;
+        version: string;
+        wasm_hash: Buffer;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -131,12 +310,15 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Result<ContractMetadata>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_all_contracts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get all published contracts
+     * Construct and simulate a yank_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Withdraw a release so it can no longer be deployed (author only)
      */
-    get_all_contracts: (options?: {
+    yank_release: ({ contract_id, version }: {
+        contract_id: u32;
+        version: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -149,13 +331,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a search_by_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Search contracts by category
+     * Construct and simulate a set_quarantined transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Block (or unblock) every release of a contract from deployment (admin only)
      */
-    search_by_category: ({ category }: {
-        category: Category;
+    set_quarantined: ({ contract_id, quarantined }: {
+        contract_id: u32;
+        quarantined: boolean;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -169,13 +352,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a search_by_tag transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Search contracts by tag
+     * Construct and simulate a get_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific release of a contract
      */
-    search_by_tag: ({ tag }: {
-        tag: string;
+    get_release: ({ contract_id, version }: {
+        contract_id: u32;
+        version: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -189,14 +373,253 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    }) => Promise<AssembledTransaction<Result<Release>>>;
     /**
-     * Construct and simulate a verify_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Verify a contract (auditors only - for MVP, anyone can verify for testing)
+     * Construct and simulate a get_releases transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all releases of a contract, oldest first
      */
-    verify_contract: ({ contract_id, auditor }: {
+    get_releases: ({ contract_id }: {
         contract_id: u32;
-        auditor: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Release>>>;
+    /**
+     * Construct and simulate a find_release_by_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Find the release of a listing that published a WASM hash
+     * Returns None if no release of the listing uses the hash
+     */
+    find_release_by_hash: ({ contract_id, wasm_hash }: {
+        contract_id: u32;
+        wasm_hash: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a resolve_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the WASM hash of a release that may be deployed
+     * Fails for unknown, yanked or quarantined releases
+     */
+    resolve_release: ({ contract_id, version }: {
+        contract_id: u32;
+        version: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Buffer>>>;
+    /**
+     * Construct and simulate a publish_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Publish a security advisory against one of a listing's builds
+     * Only the listing's author or the registry admin can publish advisories
+     * An author's advisory only counts against the hash as a whole when their
+     * listing was the first to release it, so copies can't flag the original
+     */
+    publish_advisory: ({ publisher, contract_id, wasm_hash, severity, description }: {
+        publisher: string;
+        contract_id: u32;
+        wasm_hash: Buffer;
+        severity: Severity;
+        description: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a resolve_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Mark an advisory as resolved (listing author or registry admin only)
+     */
+    resolve_advisory: ({ publisher, advisory_id }: {
+        publisher: string;
+        advisory_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_advisories transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all advisories published against a listing, including resolved ones
+     */
+    get_advisories: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Advisory>>>;
+    /**
+     * Construct and simulate a get_hash_report transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Summarize what the registry knows about a WASM hash: the listings that
+     * released it, how strongly it was verified, and its worst open advisory
+     * Only advisories from the admin or from the listing that first released
+     * the hash count; the rest stay visible through get_advisories
+     */
+    get_hash_report: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<HashReport>>;
+    /**
+     * Construct and simulate a set_deploy_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set (or clear) the price deployers pay per deployment (author only)
+     */
+    set_deploy_price: ({ contract_id, price }: {
+        contract_id: u32;
+        price: Option<DeployPrice>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_revenue_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Split author revenue with co-authors (author only)
+     * Shares are in basis points; the author keeps whatever is left
+     */
+    set_revenue_split: ({ contract_id, shares }: {
+        contract_id: u32;
+        shares: Array<RevenueShare>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_deploy_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the deploy price of a contract, if the author set one
+     */
+    get_deploy_price: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<DeployPrice>>>;
+    /**
+     * Construct and simulate a get_payout_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the author and revenue split of a contract
+     */
+    get_payout_terms: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<PayoutTerms>>>;
+    /**
+     * Construct and simulate a set_dependencies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Declare the listings a contract depends on (author only)
+     * Replaces any previously declared dependencies
+     */
+    set_dependencies: ({ contract_id, dependencies }: {
+        contract_id: u32;
+        dependencies: Array<Dependency>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -211,9 +634,415 @@ export interface Client {
          */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_dependencies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the listings a contract depends on
+     */
+    get_dependencies: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Dependency>>>;
+    /**
+     * Construct and simulate a get_dependents transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the contracts that declare a dependency on this one
+     */
+    get_dependents: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    /**
+     * Construct and simulate a set_constructor_schema transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Describe the constructor arguments of a contract (author only)
+     * Parameters with defaults may only follow required ones, so deployers
+     * can omit trailing arguments
+     */
+    set_constructor_schema: ({ contract_id, params }: {
+        contract_id: u32;
+        params: Array<ConstructorParam>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_constructor_schema transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the constructor schema of a contract, if the author attached one
+     */
+    get_constructor_schema: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Array<ConstructorParam>>>>;
+    /**
+     * Construct and simulate a set_tip_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allow or disallow a token for tips (admin only)
+     */
+    set_tip_token: ({ token, allowed }: {
+        token: string;
+        allowed: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<void>>;
+    /**
+     * Construct and simulate a get_tip_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the tokens tips can be paid in
+     */
+    get_tip_tokens: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a tip_author transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Tip the author of a contract (split with co-authors like other revenue)
+     * Only tokens allowed by the admin are accepted
+     */
+    tip_author: ({ tipper, contract_id, token, amount }: {
+        tipper: string;
+        contract_id: u32;
+        token: string;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_listing_tips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get tip totals received by a contract in a token
+     */
+    get_listing_tips: ({ contract_id, token }: {
+        contract_id: u32;
+        token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<TipTotals>>;
+    /**
+     * Construct and simulate a get_author_tips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get tip totals received by an author across their contracts in a token
+     */
+    get_author_tips: ({ author, token }: {
+        author: string;
+        token: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<TipTotals>>;
+    /**
+     * Construct and simulate a get_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get metadata for a specific contract
+     */
+    get_contract: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<ContractMetadata>>>;
+    /**
+     * Construct and simulate a get_all_contracts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all published contracts
+     */
+    get_all_contracts: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    /**
+     * Construct and simulate a search_by_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Search contracts by category
+     */
+    search_by_category: ({ category }: {
+        category: Category;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    /**
+     * Construct and simulate a get_forks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the direct forks of a contract
+     */
+    get_forks: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    /**
+     * Construct and simulate a get_fork_ancestry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the chain of releases a contract was derived from, closest first
+     */
+    get_fork_ancestry: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Array<ForkSource>>>>;
+    /**
+     * Construct and simulate a search_by_tag transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Search contracts by tag
+     */
+    search_by_tag: ({ tag }: {
+        tag: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+    /**
+     * Construct and simulate a set_auditor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Approve or remove an auditor (admin only)
+     */
+    set_auditor: ({ auditor, allowed }: {
+        auditor: string;
+        allowed: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<void>>;
+    /**
+     * Construct and simulate a get_auditors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the auditors approved by the admin
+     */
+    get_auditors: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a verify_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Verify a contract's current release (for MVP, anyone can verify for testing)
+     * The release's hash is Audited when verified by an approved auditor and
+     * Community otherwise; publishing a new release clears the listing's flag
+     */
+    verify_contract: ({ contract_id, auditor }: {
+        contract_id: u32;
+        auditor: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_deployment_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the DeploymentManager allowed to report deployments (admin only)
+     */
+    set_deployment_manager: ({ manager }: {
+        manager: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<void>>;
+    /**
+     * Construct and simulate a get_deployment_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the configured DeploymentManager, if any
+     */
+    get_deployment_manager: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
     /**
      * Construct and simulate a increment_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Increment deployment count (called by DeploymentManager)
+     * Increment deployment count (called by the configured DeploymentManager only)
      */
     increment_deployment_count: ({ contract_id }: {
         contract_id: u32;
@@ -253,6 +1082,10 @@ export interface Client {
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
     static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin }: {
+        admin: string;
+    }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -266,11 +1099,42 @@ export declare class Client extends ContractClient {
     readonly fromJSON: {
         publish_contract: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         update_metadata: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        publish_release: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        yank_release: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_quarantined: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_release: (json: string) => AssembledTransaction<Result<Release, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_releases: (json: string) => AssembledTransaction<Release[]>;
+        find_release_by_hash: (json: string) => AssembledTransaction<string | undefined>;
+        resolve_release: (json: string) => AssembledTransaction<Result<Buffer, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        publish_advisory: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        resolve_advisory: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_advisories: (json: string) => AssembledTransaction<Advisory[]>;
+        get_hash_report: (json: string) => AssembledTransaction<HashReport>;
+        set_deploy_price: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_revenue_split: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_deploy_price: (json: string) => AssembledTransaction<DeployPrice | undefined>;
+        get_payout_terms: (json: string) => AssembledTransaction<Result<PayoutTerms, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_dependencies: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_dependencies: (json: string) => AssembledTransaction<Dependency[]>;
+        get_dependents: (json: string) => AssembledTransaction<ContractMetadata[]>;
+        set_constructor_schema: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_constructor_schema: (json: string) => AssembledTransaction<ConstructorParam[] | undefined>;
+        set_tip_token: (json: string) => AssembledTransaction<void>;
+        get_tip_tokens: (json: string) => AssembledTransaction<string[]>;
+        tip_author: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_listing_tips: (json: string) => AssembledTransaction<TipTotals>;
+        get_author_tips: (json: string) => AssembledTransaction<TipTotals>;
         get_contract: (json: string) => AssembledTransaction<Result<ContractMetadata, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_all_contracts: (json: string) => AssembledTransaction<ContractMetadata[]>;
         search_by_category: (json: string) => AssembledTransaction<ContractMetadata[]>;
+        get_forks: (json: string) => AssembledTransaction<ContractMetadata[]>;
+        get_fork_ancestry: (json: string) => AssembledTransaction<Result<ForkSource[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         search_by_tag: (json: string) => AssembledTransaction<ContractMetadata[]>;
+        set_auditor: (json: string) => AssembledTransaction<void>;
+        get_auditors: (json: string) => AssembledTransaction<string[]>;
         verify_contract: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_deployment_manager: (json: string) => AssembledTransaction<void>;
+        get_deployment_manager: (json: string) => AssembledTransaction<string | undefined>;
         increment_deployment_count: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_contract_count: (json: string) => AssembledTransaction<number>;
     };
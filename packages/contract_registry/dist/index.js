@@ -13,6 +13,51 @@ export const networks = {
         contractId: "CAPTDA3MLS7UEL64BK4CG4BNN7L4QSE7POTZ6W2PKLEJZCHWRHHAENSQ",
     }
 };
+/**
+ * Type of a constructor parameter
+ */
+export var ParamType;
+(function (ParamType) {
+    ParamType[ParamType["Any"] = 0] = "Any";
+    ParamType[ParamType["Bool"] = 1] = "Bool";
+    ParamType[ParamType["U32"] = 2] = "U32";
+    ParamType[ParamType["I32"] = 3] = "I32";
+    ParamType[ParamType["U64"] = 4] = "U64";
+    ParamType[ParamType["I64"] = 5] = "I64";
+    ParamType[ParamType["U128"] = 6] = "U128";
+    ParamType[ParamType["I128"] = 7] = "I128";
+    ParamType[ParamType["U256"] = 8] = "U256";
+    ParamType[ParamType["I256"] = 9] = "I256";
+    ParamType[ParamType["Address"] = 10] = "Address";
+    ParamType[ParamType["String"] = 11] = "String";
+    ParamType[ParamType["Symbol"] = 12] = "Symbol";
+    ParamType[ParamType["Bytes"] = 13] = "Bytes";
+    ParamType[ParamType["Vec"] = 14] = "Vec";
+    ParamType[ParamType["Map"] = 15] = "Map";
+})(ParamType || (ParamType = {}));
+/**
+ * How serious a security advisory is
+ * None is only used in reports, for hashes without open advisories
+ */
+export var Severity;
+(function (Severity) {
+    Severity[Severity["None"] = 0] = "None";
+    Severity[Severity["Low"] = 1] = "Low";
+    Severity[Severity["Medium"] = 2] = "Medium";
+    Severity[Severity["High"] = 3] = "High";
+    Severity[Severity["Critical"] = 4] = "Critical";
+})(Severity || (Severity = {}));
+/**
+ * How strongly a build was verified
+ * Anyone can give a Community verification; Audited ones come from auditors
+ * approved by the admin
+ */
+export var VerificationLevel;
+(function (VerificationLevel) {
+    VerificationLevel[VerificationLevel["Unverified"] = 0] = "Unverified";
+    VerificationLevel[VerificationLevel["Community"] = 1] = "Community";
+    VerificationLevel[VerificationLevel["Audited"] = 2] = "Audited";
+})(VerificationLevel || (VerificationLevel = {}));
 export var Category;
 (function (Category) {
     Category[Category["DeFi"] = 0] = "DeFi";
@@ -29,43 +74,143 @@ export const Errors = {
     3: { message: "UnauthorizedUpdate" },
     4: { message: "InvalidMetadata" },
     5: { message: "InvalidWasmHash" },
-    6: { message: "UnauthorizedVerification" }
+    6: { message: "UnauthorizedVerification" },
+    7: { message: "InvalidRoyalty" },
+    8: { message: "InvalidAmount" },
+    9: { message: "InvalidDependency" },
+    10: { message: "DependencyNotFound" },
+    11: { message: "InvalidForkSource" },
+    12: { message: "InvalidSchema" },
+    13: { message: "ReleaseNotFound" },
+    14: { message: "ReleaseAlreadyExists" },
+    15: { message: "ReleaseYanked" },
+    16: { message: "ContractQuarantined" },
+    17: { message: "AdvisoryNotFound" },
+    18: { message: "TokenNotAllowed" }
 };
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ admin }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAADVB1Ymxpc2hQYXJhbXMAAAAAAAAJAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAAEAAAAAAAAAAHbGljZW5zZQAAAAAQAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAKc291cmNlX3VybAAAAAAAEAAAAAAAAAAEdGFncwAAA+oAAAAQAAAAAAAAAAd2ZXJzaW9uAAAAABAAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
-            "AAAAAQAAAAAAAAAAAAAAEENvbnRyYWN0TWV0YWRhdGEAAAAPAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAARZG9jdW1lbnRhdGlvbl91cmwAAAAAAAAQAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAHbGljZW5zZQAAAAAQAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAMcHVibGlzaGVkX2F0AAAABgAAAAAAAAAKc291cmNlX3VybAAAAAAAEAAAAAAAAAAEdGFncwAAA+oAAAAQAAAAAAAAABF0b3RhbF9kZXBsb3ltZW50cwAAAAAAAAQAAAAAAAAACnVwZGF0ZWRfYXQAAAAAAAYAAAAAAAAACHZlcmlmaWVkAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAADVB1Ymxpc2hQYXJhbXMAAAAAAAAKAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAAEAAAAAAAAAALZm9ya2VkX2Zyb20AAAAH0AAAAApGb3JrZWRGcm9tAAAAAAAAAAAAB2xpY2Vuc2UAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACnNvdXJjZV91cmwAAAAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+            "AAAAAQAAAAAAAAAAAAAAEENvbnRyYWN0TWV0YWRhdGEAAAARAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAARZG9jdW1lbnRhdGlvbl91cmwAAAAAAAAQAAAAAAAAAAtmb3JrZWRfZnJvbQAAAAfQAAAACkZvcmtlZEZyb20AAAAAAAAAAAACaWQAAAAAAAQAAAAAAAAAB2xpY2Vuc2UAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAC3F1YXJhbnRpbmVkAAAAAAEAAAAAAAAACnNvdXJjZV91cmwAAAAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEAAAAAAAAAARdG90YWxfZGVwbG95bWVudHMAAAAAAAAEAAAAAAAAAAp1cGRhdGVkX2F0AAAAAAAGAAAAAAAAAAh2ZXJpZmllZAAAAAEAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+            "AAAAAQAAAChBIHB1Ymxpc2hlZCB2ZXJzaW9uIG9mIGEgY29udHJhY3QncyBjb2RlAAAAAAAAAAdSZWxlYXNlAAAAAAQAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABnlhbmtlZAAAAAAAAQ==",
+            "AAAAAQAAAC9UaGUgbGlzdGluZyByZWxlYXNlIGEgY29udHJhY3Qgd2FzIGRlcml2ZWQgZnJvbQAAAAAAAAAACkZvcmtTb3VyY2UAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAB3JlbGVhc2UAAAAAEA==",
+            "AAAAAgAAALxXaGV0aGVyIGEgY29udHJhY3QgaXMgb3JpZ2luYWwgd29yayBvciBhIG1vZGlmaWVkIGNvcHkgb2YgYW5vdGhlciBsaXN0aW5nCkFuIGVudW0gcmF0aGVyIHRoYW4gT3B0aW9uPEZvcmtTb3VyY2U+OiB0aGUgU0RLJ3MgdGVzdHV0aWxzIGNhbid0IGNvbnZlcnQKb3B0aW9uYWwgc3RydWN0IGZpZWxkcyBvZiBjb250cmFjdCB0eXBlcwAAAAAAAAAKRm9ya2VkRnJvbQAAAAAAAgAAAAAAAAAAAAAACE9yaWdpbmFsAAAAAQAAAAAAAAAERm9yawAAAAEAAAfQAAAACkZvcmtTb3VyY2UAAA==",
+            "AAAAAQAAAD1QcmljZSBjaGFyZ2VkIHRvIGRlcGxveWVycyBlYWNoIHRpbWUgdGhlIGNvbnRyYWN0IGlzIGRlcGxveWVkAAAAAAAAAAAAAAtEZXBsb3lQcmljZQAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEw==",
+            "AAAAAQAAAD5Qb3J0aW9uIG9mIGF1dGhvciByZXZlbnVlIHBhaWQgdG8gYSBjby1hdXRob3IsIGluIGJhc2lzIHBvaW50cwAAAAAAAAAAAAxSZXZlbnVlU2hhcmUAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAlzaGFyZV9icHMAAAAAAAAE",
+            "AAAAAQAAAFtXaG8gZ2V0cyBwYWlkIGZvciBhIGNvbnRyYWN0Cih0aGUgYXV0aG9yIHJlY2VpdmVzIHdoYXRldmVyIHRoZSBjby1hdXRob3Igc2hhcmVzIGxlYXZlIG92ZXIpAAAAAAAAAAALUGF5b3V0VGVybXMAAAAAAgAAAAAAAAAGYXV0aG9yAAAAAAATAAAAAAAAAAZzaGFyZXMAAAAAA+oAAAfQAAAADFJldmVudWVTaGFyZQ==",
+            "AAAAAQAAAEtBIGxpc3RpbmcgdGhpcyBjb250cmFjdCBuZWVkcyBhbG9uZ3NpZGUgaXQsIHdpdGggdGhlIHZlcnNpb25zIGl0IHdvcmtzIHdpdGgAAAAAAAAAAApEZXBlbmRlbmN5AAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAt2ZXJzaW9uX3JlcQAAAAAQ",
+            "AAAAAwAAAB9UeXBlIG9mIGEgY29uc3RydWN0b3IgcGFyYW1ldGVyAAAAAAAAAAAJUGFyYW1UeXBlAAAAAAAAEAAAAAAAAAADQW55AAAAAAAAAAAAAAAABEJvb2wAAAABAAAAAAAAAANVMzIAAAAAAgAAAAAAAAADSTMyAAAAAAMAAAAAAAAAA1U2NAAAAAAEAAAAAAAAAANJNjQAAAAABQAAAAAAAAAEVTEyOAAAAAYAAAAAAAAABEkxMjgAAAAHAAAAAAAAAARVMjU2AAAACAAAAAAAAAAESTI1NgAAAAkAAAAAAAAAB0FkZHJlc3MAAAAACgAAAAAAAAAGU3RyaW5nAAAAAAALAAAAAAAAAAZTeW1ib2wAAAAAAAwAAAAAAAAABUJ5dGVzAAAAAAAADQAAAAAAAAADVmVjAAAAAA4AAAAAAAAAA01hcAAAAAAP",
+            "AAAAAQAAAEJPbmUgY29uc3RydWN0b3IgcGFyYW1ldGVyLCBpbiB0aGUgb3JkZXIgdGhlIGNvbnN0cnVjdG9yIHRha2VzIHRoZW0AAAAAAAAAAAAQQ29uc3RydWN0b3JQYXJhbQAAAAQAAAAAAAAADWRlZmF1bHRfdmFsdWUAAAAAAAPqAAAAAAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACnBhcmFtX3R5cGUAAAAAB9AAAAAJUGFyYW1UeXBlAAAA",
+            "AAAAAQAAADtSdW5uaW5nIHRpcCB0b3RhbHMgaW4gb25lIHRva2VuLCBmb3IgYSBsaXN0aW5nIG9yIGFuIGF1dGhvcgAAAAAAAAAACVRpcFRvdGFscwAAAAAAAAMAAAAAAAAAD3N1cHBvcnRlcl9jb3VudAAAAAAEAAAAAAAAAAl0aXBfY291bnQAAAAAAAAEAAAAAAAAAAx0b3RhbF9hbW91bnQAAAAL",
+            "AAAAAwAAAGNIb3cgc2VyaW91cyBhIHNlY3VyaXR5IGFkdmlzb3J5IGlzCk5vbmUgaXMgb25seSB1c2VkIGluIHJlcG9ydHMsIGZvciBoYXNoZXMgd2l0aG91dCBvcGVuIGFkdmlzb3JpZXMAAAAAAAAAAAhTZXZlcml0eQAAAAUAAAAAAAAABE5vbmUAAAAAAAAAAAAAAANMb3cAAAAAAQAAAAAAAAAGTWVkaXVtAAAAAAACAAAAAAAAAARIaWdoAAAAAwAAAAAAAAAIQ3JpdGljYWwAAAAE",
+            "AAAAAQAAADBTZWN1cml0eSBhZHZpc29yeSBhZ2FpbnN0IG9uZSBidWlsZCBvZiBhIGxpc3RpbmcAAAAAAAAACEFkdmlzb3J5AAAACAAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAAAAAAIYnlfYWRtaW4AAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAxwdWJsaXNoZWRfYXQAAAAGAAAAAAAAAAhyZXNvbHZlZAAAAAEAAAAAAAAACHNldmVyaXR5AAAH0AAAAAhTZXZlcml0eQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+            "AAAAAwAAAIFIb3cgc3Ryb25nbHkgYSBidWlsZCB3YXMgdmVyaWZpZWQKQW55b25lIGNhbiBnaXZlIGEgQ29tbXVuaXR5IHZlcmlmaWNhdGlvbjsgQXVkaXRlZCBvbmVzIGNvbWUgZnJvbSBhdWRpdG9ycwphcHByb3ZlZCBieSB0aGUgYWRtaW4AAAAAAAAAAAAAEVZlcmlmaWNhdGlvbkxldmVsAAAAAAAAAwAAAAAAAAAKVW52ZXJpZmllZAAAAAAAAAAAAAAAAAAJQ29tbXVuaXR5AAAAAAAAAQAAAAAAAAAHQXVkaXRlZAAAAAAC",
+            "AAAAAQAAAClXaGF0IHRoZSByZWdpc3RyeSBrbm93cyBhYm91dCBhIFdBU00gaGFzaAAAAAAAAAAAAAAKSGFzaFJlcG9ydAAAAAAAAwAAAAAAAAAQaGlnaGVzdF9zZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAADHZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAA=",
             "AAAAAwAAAAAAAAAAAAAACENhdGVnb3J5AAAABwAAAAAAAAAERGVGaQAAAAAAAAAAAAAAA05GVAAAAAABAAAAAAAAAANEQU8AAAAAAgAAAAAAAAAGR2FtaW5nAAAAAAADAAAAAAAAAAdVdGlsaXR5AAAAAAQAAAAAAAAABk9yYWNsZQAAAAAABQAAAAAAAAAFT3RoZXIAAAAAAAAG",
-            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABgAAAAAAAAAVQ29udHJhY3RBbHJlYWR5RXhpc3RzAAAAAAAAAQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAElVuYXV0aG9yaXplZFVwZGF0ZQAAAAAAAwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAAAQAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAFAAAAAAAAABhVbmF1dGhvcml6ZWRWZXJpZmljYXRpb24AAAAG",
+            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEgAAAAAAAAAVQ29udHJhY3RBbHJlYWR5RXhpc3RzAAAAAAAAAQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAElVuYXV0aG9yaXplZFVwZGF0ZQAAAAAAAwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAAAQAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAFAAAAAAAAABhVbmF1dGhvcml6ZWRWZXJpZmljYXRpb24AAAAGAAAAAAAAAA5JbnZhbGlkUm95YWx0eQAAAAAABwAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAgAAAAAAAAAEUludmFsaWREZXBlbmRlbmN5AAAAAAAACQAAAAAAAAASRGVwZW5kZW5jeU5vdEZvdW5kAAAAAAAKAAAAAAAAABFJbnZhbGlkRm9ya1NvdXJjZQAAAAAAAAsAAAAAAAAADUludmFsaWRTY2hlbWEAAAAAAAAMAAAAAAAAAA9SZWxlYXNlTm90Rm91bmQAAAAADQAAAAAAAAAUUmVsZWFzZUFscmVhZHlFeGlzdHMAAAAOAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAADwAAAAAAAAATQ29udHJhY3RRdWFyYW50aW5lZAAAAAAQAAAAAAAAABBBZHZpc29yeU5vdEZvdW5kAAAAEQAAAAAAAAAPVG9rZW5Ob3RBbGxvd2VkAAAAABI=",
             "AAAABQAAAAAAAAAAAAAACVB1Ymxpc2hlZAAAAAAAAAEAAAAJcHVibGlzaGVkAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAC",
             "AAAABQAAAAAAAAAAAAAAB1VwZGF0ZWQAAAAAAQAAAAd1cGRhdGVkAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAg==",
-            "AAAABQAAAAAAAAAAAAAACFZlcmlmaWVkAAAAAQAAAAh2ZXJpZmllZAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAEFJlbGVhc2VQdWJsaXNoZWQAAAABAAAAEXJlbGVhc2VfcHVibGlzaGVkAAAAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAADVJlbGVhc2VZYW5rZWQAAAAAAAABAAAADnJlbGVhc2VfeWFua2VkAAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAC1F1YXJhbnRpbmVkAAAAAAEAAAALcXVhcmFudGluZWQAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAAAAAAC3F1YXJhbnRpbmVkAAAAAAEAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAEUFkdmlzb3J5UHVibGlzaGVkAAAAAAAAAQAAABJhZHZpc29yeV9wdWJsaXNoZWQAAAAAAAMAAAAAAAAAC2Fkdmlzb3J5X2lkAAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAIc2V2ZXJpdHkAAAfQAAAACFNldmVyaXR5AAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAEEFkdmlzb3J5UmVzb2x2ZWQAAAABAAAAEWFkdmlzb3J5X3Jlc29sdmVkAAAAAAAAAQAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAACFZlcmlmaWVkAAAAAQAAAAh2ZXJpZmllZAAAAAMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAAAAAAAVsZXZlbAAAAAAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAAAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAEFJveWFsdGllc1VwZGF0ZWQAAAABAAAAEXJveWFsdGllc191cGRhdGVkAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAAE0RlcGVuZGVuY2llc1VwZGF0ZWQAAAAAAQAAABRkZXBlbmRlbmNpZXNfdXBkYXRlZAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAADVNjaGVtYVVwZGF0ZWQAAAAAAAABAAAADnNjaGVtYV91cGRhdGVkAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAABlRpcHBlZAAAAAAAAQAAAAZ0aXBwZWQAAAAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAZ0aXBwZXIAAAAAABMAAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAA9zdXBwb3J0ZXJfY291bnQAAAAABAAAAAAAAAAC",
             "AAAABQAAAAAAAAAAAAAACERlcGxveWVkAAAAAQAAAAhkZXBsb3llZAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAABF0b3RhbF9kZXBsb3ltZW50cwAAAAAAAAQAAAAAAAAAAg==",
+            "AAAAAAAAADFDb25maWd1cmUgdGhlIHJlZ2lzdHJ5IGFkbWluIChtb2RlcmF0ZXMgbGlzdGluZ3MpAAAAAAAADV9fY29uc3RydWN0b3IAAAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
             "AAAAAAAAACZQdWJsaXNoIGEgbmV3IGNvbnRyYWN0IHRvIHRoZSByZWdpc3RyeQAAAAAAEHB1Ymxpc2hfY29udHJhY3QAAAACAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAAA1QdWJsaXNoUGFyYW1zAAAAAAAAAQAAA+kAAAAEAAAAAw==",
             "AAAAAAAAADZVcGRhdGUgbWV0YWRhdGEgZm9yIGFuIGV4aXN0aW5nIGNvbnRyYWN0IChhdXRob3Igb25seSkAAAAAAA91cGRhdGVfbWV0YWRhdGEAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAALZGVzY3JpcHRpb24AAAAD6AAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAD6AAAABAAAAAAAAAABHRhZ3MAAAPoAAAD6gAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAG5QdWJsaXNoIGEgbmV3IHZlcnNpb24gb2YgYSBjb250cmFjdCdzIGNvZGUgKGF1dGhvciBvbmx5KQpUaGUgbmV3IHJlbGVhc2UgYmVjb21lcyB0aGUgbGlzdGluZydzIGN1cnJlbnQgdmVyc2lvbgAAAAAAD3B1Ymxpc2hfcmVsZWFzZQAAAAADAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAd2ZXJzaW9uAAAAABAAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAEBXaXRoZHJhdyBhIHJlbGVhc2Ugc28gaXQgY2FuIG5vIGxvbmdlciBiZSBkZXBsb3llZCAoYXV0aG9yIG9ubHkpAAAADHlhbmtfcmVsZWFzZQAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAEtCbG9jayAob3IgdW5ibG9jaykgZXZlcnkgcmVsZWFzZSBvZiBhIGNvbnRyYWN0IGZyb20gZGVwbG95bWVudCAoYWRtaW4gb25seSkAAAAAD3NldF9xdWFyYW50aW5lZAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtxdWFyYW50aW5lZAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAACRHZXQgYSBzcGVjaWZpYyByZWxlYXNlIG9mIGEgY29udHJhY3QAAAALZ2V0X3JlbGVhc2UAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAQAAA+kAAAfQAAAAB1JlbGVhc2UAAAAAAw==",
+            "AAAAAAAAACxHZXQgYWxsIHJlbGVhc2VzIG9mIGEgY29udHJhY3QsIG9sZGVzdCBmaXJzdAAAAAxnZXRfcmVsZWFzZXMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAAB1JlbGVhc2UA",
+            "AAAAAAAAAHBGaW5kIHRoZSByZWxlYXNlIG9mIGEgbGlzdGluZyB0aGF0IHB1Ymxpc2hlZCBhIFdBU00gaGFzaApSZXR1cm5zIE5vbmUgaWYgbm8gcmVsZWFzZSBvZiB0aGUgbGlzdGluZyB1c2VzIHRoZSBoYXNoAAAAFGZpbmRfcmVsZWFzZV9ieV9oYXNoAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6AAAABA=",
+            "AAAAAAAAAGVHZXQgdGhlIFdBU00gaGFzaCBvZiBhIHJlbGVhc2UgdGhhdCBtYXkgYmUgZGVwbG95ZWQKRmFpbHMgZm9yIHVua25vd24sIHlhbmtlZCBvciBxdWFyYW50aW5lZCByZWxlYXNlcwAAAAAAAA9yZXNvbHZlX3JlbGVhc2UAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+            "AAAAAAAAARNQdWJsaXNoIGEgc2VjdXJpdHkgYWR2aXNvcnkgYWdhaW5zdCBvbmUgb2YgYSBsaXN0aW5nJ3MgYnVpbGRzCk9ubHkgdGhlIGxpc3RpbmcncyBhdXRob3Igb3IgdGhlIHJlZ2lzdHJ5IGFkbWluIGNhbiBwdWJsaXNoIGFkdmlzb3JpZXMKQW4gYXV0aG9yJ3MgYWR2aXNvcnkgb25seSBjb3VudHMgYWdhaW5zdCB0aGUgaGFzaCBhcyBhIHdob2xlIHdoZW4gdGhlaXIKbGlzdGluZyB3YXMgdGhlIGZpcnN0IHRvIHJlbGVhc2UgaXQsIHNvIGNvcGllcyBjYW4ndCBmbGFnIHRoZSBvcmlnaW5hbAAAAAAQcHVibGlzaF9hZHZpc29yeQAAAAUAAAAAAAAACXB1Ymxpc2hlcgAAAAAAABMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAhzZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAERNYXJrIGFuIGFkdmlzb3J5IGFzIHJlc29sdmVkIChsaXN0aW5nIGF1dGhvciBvciByZWdpc3RyeSBhZG1pbiBvbmx5KQAAABByZXNvbHZlX2Fkdmlzb3J5AAAAAgAAAAAAAAAJcHVibGlzaGVyAAAAAAAAEwAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAEdHZXQgYWxsIGFkdmlzb3JpZXMgcHVibGlzaGVkIGFnYWluc3QgYSBsaXN0aW5nLCBpbmNsdWRpbmcgcmVzb2x2ZWQgb25lcwAAAAAOZ2V0X2Fkdmlzb3JpZXMAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6gAAB9AAAAAIQWR2aXNvcnk=",
+            "AAAAAAAAARFTdW1tYXJpemUgd2hhdCB0aGUgcmVnaXN0cnkga25vd3MgYWJvdXQgYSBXQVNNIGhhc2g6IHRoZSBsaXN0aW5ncyB0aGF0CnJlbGVhc2VkIGl0LCBob3cgc3Ryb25nbHkgaXQgd2FzIHZlcmlmaWVkLCBhbmQgaXRzIHdvcnN0IG9wZW4gYWR2aXNvcnkKT25seSBhZHZpc29yaWVzIGZyb20gdGhlIGFkbWluIG9yIGZyb20gdGhlIGxpc3RpbmcgdGhhdCBmaXJzdCByZWxlYXNlZAp0aGUgaGFzaCBjb3VudDsgdGhlIHJlc3Qgc3RheSB2aXNpYmxlIHRocm91Z2ggZ2V0X2Fkdmlzb3JpZXMAAAAAAAAPZ2V0X2hhc2hfcmVwb3J0AAAAAAEAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAB9AAAAAKSGFzaFJlcG9ydAAA",
+            "AAAAAAAAAENTZXQgKG9yIGNsZWFyKSB0aGUgcHJpY2UgZGVwbG95ZXJzIHBheSBwZXIgZGVwbG95bWVudCAoYXV0aG9yIG9ubHkpAAAAABBzZXRfZGVwbG95X3ByaWNlAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAPoAAAH0AAAAAtEZXBsb3lQcmljZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAHBTcGxpdCBhdXRob3IgcmV2ZW51ZSB3aXRoIGNvLWF1dGhvcnMgKGF1dGhvciBvbmx5KQpTaGFyZXMgYXJlIGluIGJhc2lzIHBvaW50czsgdGhlIGF1dGhvciBrZWVwcyB3aGF0ZXZlciBpcyBsZWZ0AAAAEXNldF9yZXZlbnVlX3NwbGl0AAAAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAxSZXZlbnVlU2hhcmUAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAADlHZXQgdGhlIGRlcGxveSBwcmljZSBvZiBhIGNvbnRyYWN0LCBpZiB0aGUgYXV0aG9yIHNldCBvbmUAAAAAAAAQZ2V0X2RlcGxveV9wcmljZQAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6AAAB9AAAAALRGVwbG95UHJpY2UA",
+            "AAAAAAAAAC5HZXQgdGhlIGF1dGhvciBhbmQgcmV2ZW51ZSBzcGxpdCBvZiBhIGNvbnRyYWN0AAAAAAAQZ2V0X3BheW91dF90ZXJtcwAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6QAAB9AAAAALUGF5b3V0VGVybXMAAAAAAw==",
+            "AAAAAAAAAGZEZWNsYXJlIHRoZSBsaXN0aW5ncyBhIGNvbnRyYWN0IGRlcGVuZHMgb24gKGF1dGhvciBvbmx5KQpSZXBsYWNlcyBhbnkgcHJldmlvdXNseSBkZWNsYXJlZCBkZXBlbmRlbmNpZXMAAAAAABBzZXRfZGVwZW5kZW5jaWVzAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAMZGVwZW5kZW5jaWVzAAAD6gAAB9AAAAAKRGVwZW5kZW5jeQAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAACZHZXQgdGhlIGxpc3RpbmdzIGEgY29udHJhY3QgZGVwZW5kcyBvbgAAAAAAEGdldF9kZXBlbmRlbmNpZXMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAACkRlcGVuZGVuY3kAAA==",
+            "AAAAAAAAADdHZXQgdGhlIGNvbnRyYWN0cyB0aGF0IGRlY2xhcmUgYSBkZXBlbmRlbmN5IG9uIHRoaXMgb25lAAAAAA5nZXRfZGVwZW5kZW50cwAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
+            "AAAAAAAAAJ9EZXNjcmliZSB0aGUgY29uc3RydWN0b3IgYXJndW1lbnRzIG9mIGEgY29udHJhY3QgKGF1dGhvciBvbmx5KQpQYXJhbWV0ZXJzIHdpdGggZGVmYXVsdHMgbWF5IG9ubHkgZm9sbG93IHJlcXVpcmVkIG9uZXMsIHNvIGRlcGxveWVycwpjYW4gb21pdCB0cmFpbGluZyBhcmd1bWVudHMAAAAAFnNldF9jb25zdHJ1Y3Rvcl9zY2hlbWEAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABnBhcmFtcwAAAAAD6gAAB9AAAAAQQ29uc3RydWN0b3JQYXJhbQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAERHZXQgdGhlIGNvbnN0cnVjdG9yIHNjaGVtYSBvZiBhIGNvbnRyYWN0LCBpZiB0aGUgYXV0aG9yIGF0dGFjaGVkIG9uZQAAABZnZXRfY29uc3RydWN0b3Jfc2NoZW1hAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+gAAAPqAAAH0AAAABBDb25zdHJ1Y3RvclBhcmFt",
+            "AAAAAAAAAC9BbGxvdyBvciBkaXNhbGxvdyBhIHRva2VuIGZvciB0aXBzIChhZG1pbiBvbmx5KQAAAAANc2V0X3RpcF90b2tlbgAAAAAAAAIAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAHYWxsb3dlZAAAAAABAAAAAA==",
+            "AAAAAAAAACJHZXQgdGhlIHRva2VucyB0aXBzIGNhbiBiZSBwYWlkIGluAAAAAAAOZ2V0X3RpcF90b2tlbnMAAAAAAAAAAAABAAAD6gAAABM=",
+            "AAAAAAAAAHVUaXAgdGhlIGF1dGhvciBvZiBhIGNvbnRyYWN0IChzcGxpdCB3aXRoIGNvLWF1dGhvcnMgbGlrZSBvdGhlciByZXZlbnVlKQpPbmx5IHRva2VucyBhbGxvd2VkIGJ5IHRoZSBhZG1pbiBhcmUgYWNjZXB0ZWQAAAAAAAAKdGlwX2F1dGhvcgAAAAAABAAAAAAAAAAGdGlwcGVyAAAAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAADBHZXQgdGlwIHRvdGFscyByZWNlaXZlZCBieSBhIGNvbnRyYWN0IGluIGEgdG9rZW4AAAAQZ2V0X2xpc3RpbmdfdGlwcwAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAfQAAAACVRpcFRvdGFscwAAAA==",
+            "AAAAAAAAAEZHZXQgdGlwIHRvdGFscyByZWNlaXZlZCBieSBhbiBhdXRob3IgYWNyb3NzIHRoZWlyIGNvbnRyYWN0cyBpbiBhIHRva2VuAAAAAAAPZ2V0X2F1dGhvcl90aXBzAAAAAAIAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAB9AAAAAJVGlwVG90YWxzAAAA",
             "AAAAAAAAACRHZXQgbWV0YWRhdGEgZm9yIGEgc3BlY2lmaWMgY29udHJhY3QAAAAMZ2V0X2NvbnRyYWN0AAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPpAAAH0AAAABBDb250cmFjdE1ldGFkYXRhAAAAAw==",
             "AAAAAAAAABtHZXQgYWxsIHB1Ymxpc2hlZCBjb250cmFjdHMAAAAAEWdldF9hbGxfY29udHJhY3RzAAAAAAAAAAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
             "AAAAAAAAABxTZWFyY2ggY29udHJhY3RzIGJ5IGNhdGVnb3J5AAAAEnNlYXJjaF9ieV9jYXRlZ29yeQAAAAAAAQAAAAAAAAAIY2F0ZWdvcnkAAAfQAAAACENhdGVnb3J5AAAAAQAAA+oAAAfQAAAAEENvbnRyYWN0TWV0YWRhdGE=",
+            "AAAAAAAAACJHZXQgdGhlIGRpcmVjdCBmb3JrcyBvZiBhIGNvbnRyYWN0AAAAAAAJZ2V0X2ZvcmtzAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
+            "AAAAAAAAAERHZXQgdGhlIGNoYWluIG9mIHJlbGVhc2VzIGEgY29udHJhY3Qgd2FzIGRlcml2ZWQgZnJvbSwgY2xvc2VzdCBmaXJzdAAAABFnZXRfZm9ya19hbmNlc3RyeQAAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAACkZvcmtTb3VyY2UAAAAAAAM=",
             "AAAAAAAAABdTZWFyY2ggY29udHJhY3RzIGJ5IHRhZwAAAAANc2VhcmNoX2J5X3RhZwAAAAAAAAEAAAAAAAAAA3RhZwAAAAAQAAAAAQAAA+oAAAfQAAAAEENvbnRyYWN0TWV0YWRhdGE=",
-            "AAAAAAAAAEpWZXJpZnkgYSBjb250cmFjdCAoYXVkaXRvcnMgb25seSAtIGZvciBNVlAsIGFueW9uZSBjYW4gdmVyaWZ5IGZvciB0ZXN0aW5nKQAAAAAAD3ZlcmlmeV9jb250cmFjdAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdhdWRpdG9yAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-            "AAAAAAAAADhJbmNyZW1lbnQgZGVwbG95bWVudCBjb3VudCAoY2FsbGVkIGJ5IERlcGxveW1lbnRNYW5hZ2VyKQAAABppbmNyZW1lbnRfZGVwbG95bWVudF9jb3VudAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAClBcHByb3ZlIG9yIHJlbW92ZSBhbiBhdWRpdG9yIChhZG1pbiBvbmx5KQAAAAAAAAtzZXRfYXVkaXRvcgAAAAACAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAB2FsbG93ZWQAAAAAAQAAAAA=",
+            "AAAAAAAAACZHZXQgdGhlIGF1ZGl0b3JzIGFwcHJvdmVkIGJ5IHRoZSBhZG1pbgAAAAAADGdldF9hdWRpdG9ycwAAAAAAAAABAAAD6gAAABM=",
+            "AAAAAAAAANtWZXJpZnkgYSBjb250cmFjdCdzIGN1cnJlbnQgcmVsZWFzZSAoZm9yIE1WUCwgYW55b25lIGNhbiB2ZXJpZnkgZm9yIHRlc3RpbmcpClRoZSByZWxlYXNlJ3MgaGFzaCBpcyBBdWRpdGVkIHdoZW4gdmVyaWZpZWQgYnkgYW4gYXBwcm92ZWQgYXVkaXRvciBhbmQKQ29tbXVuaXR5IG90aGVyd2lzZTsgcHVibGlzaGluZyBhIG5ldyByZWxlYXNlIGNsZWFycyB0aGUgbGlzdGluZydzIGZsYWcAAAAAD3ZlcmlmeV9jb250cmFjdAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdhdWRpdG9yAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAERTZXQgdGhlIERlcGxveW1lbnRNYW5hZ2VyIGFsbG93ZWQgdG8gcmVwb3J0IGRlcGxveW1lbnRzIChhZG1pbiBvbmx5KQAAABZzZXRfZGVwbG95bWVudF9tYW5hZ2VyAAAAAAABAAAAAAAAAAdtYW5hZ2VyAAAAABMAAAAA",
+            "AAAAAAAAACxHZXQgdGhlIGNvbmZpZ3VyZWQgRGVwbG95bWVudE1hbmFnZXIsIGlmIGFueQAAABZnZXRfZGVwbG95bWVudF9tYW5hZ2VyAAAAAAAAAAAAAQAAA+gAAAAT",
+            "AAAAAAAAAExJbmNyZW1lbnQgZGVwbG95bWVudCBjb3VudCAoY2FsbGVkIGJ5IHRoZSBjb25maWd1cmVkIERlcGxveW1lbnRNYW5hZ2VyIG9ubHkpAAAAGmluY3JlbWVudF9kZXBsb3ltZW50X2NvdW50AAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
             "AAAAAAAAAB1HZXQgdG90YWwgbnVtYmVyIG9mIGNvbnRyYWN0cwAAAAAAABJnZXRfY29udHJhY3RfY291bnQAAAAAAAAAAAABAAAABA=="]), options);
         this.options = options;
     }
     fromJSON = {
         publish_contract: (this.txFromJSON),
         update_metadata: (this.txFromJSON),
+        publish_release: (this.txFromJSON),
+        yank_release: (this.txFromJSON),
+        set_quarantined: (this.txFromJSON),
+        get_release: (this.txFromJSON),
+        get_releases: (this.txFromJSON),
+        find_release_by_hash: (this.txFromJSON),
+        resolve_release: (this.txFromJSON),
+        publish_advisory: (this.txFromJSON),
+        resolve_advisory: (this.txFromJSON),
+        get_advisories: (this.txFromJSON),
+        get_hash_report: (this.txFromJSON),
+        set_deploy_price: (this.txFromJSON),
+        set_revenue_split: (this.txFromJSON),
+        get_deploy_price: (this.txFromJSON),
+        get_payout_terms: (this.txFromJSON),
+        set_dependencies: (this.txFromJSON),
+        get_dependencies: (this.txFromJSON),
+        get_dependents: (this.txFromJSON),
+        set_constructor_schema: (this.txFromJSON),
+        get_constructor_schema: (this.txFromJSON),
+        set_tip_token: (this.txFromJSON),
+        get_tip_tokens: (this.txFromJSON),
+        tip_author: (this.txFromJSON),
+        get_listing_tips: (this.txFromJSON),
+        get_author_tips: (this.txFromJSON),
         get_contract: (this.txFromJSON),
         get_all_contracts: (this.txFromJSON),
         search_by_category: (this.txFromJSON),
+        get_forks: (this.txFromJSON),
+        get_fork_ancestry: (this.txFromJSON),
         search_by_tag: (this.txFromJSON),
+        set_auditor: (this.txFromJSON),
+        get_auditors: (this.txFromJSON),
         verify_contract: (this.txFromJSON),
+        set_deployment_manager: (this.txFromJSON),
+        get_deployment_manager: (this.txFromJSON),
         increment_deployment_count: (this.txFromJSON),
         get_contract_count: (this.txFromJSON)
     };
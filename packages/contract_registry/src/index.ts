@@ -41,6 +41,7 @@ export interface PublishParams {
   category: Category;
   description: string;
   documentation_url: string;
+  forked_from: ForkedFrom;
   license: string;
   name: string;
   source_url: string;
@@ -54,10 +55,12 @@ export interface ContractMetadata {
   category: Category;
   description: string;
   documentation_url: string;
+  forked_from: ForkedFrom;
   id: u32;
   license: string;
   name: string;
   published_at: u64;
+  quarantined: boolean;
   source_url: string;
   tags: Array<string>;
   total_deployments: u32;
@@ -67,6 +70,153 @@ export interface ContractMetadata {
   wasm_hash: Buffer;
 }
 
+/**
+ * A published version of a contract's code
+ */
+export interface Release {
+  published_at: u64;
+  version: string;
+  wasm_hash: Buffer;
+  yanked: boolean;
+}
+
+/**
+ * The listing release a contract was derived from
+ */
+export interface ForkSource {
+  contract_id: u32;
+  release: string;
+}
+
+/**
+ * Whether a contract is original work or a modified copy of another listing
+ * An enum rather than Option<ForkSource>: the SDK's testutils can't convert
+ * optional struct fields of contract types
+ */
+export type ForkedFrom =
+  | { tag: "Original"; values: void }
+  | { tag: "Fork"; values: readonly [ForkSource] };
+
+/**
+ * Price charged to deployers each time the contract is deployed
+ */
+export interface DeployPrice {
+  amount: i128;
+  token: string;
+}
+
+/**
+ * Portion of author revenue paid to a co-author, in basis points
+ */
+export interface RevenueShare {
+  recipient: string;
+  share_bps: u32;
+}
+
+/**
+ * Who gets paid for a contract
+ * (the author receives whatever the co-author shares leave over)
+ */
+export interface PayoutTerms {
+  author: string;
+  shares: Array<RevenueShare>;
+}
+
+/**
+ * A listing this contract needs alongside it, with the versions it works with
+ */
+export interface Dependency {
+  contract_id: u32;
+  version_req: string;
+}
+
+/**
+ * Type of a constructor parameter
+ */
+export enum ParamType {
+  Any = 0,
+  Bool = 1,
+  U32 = 2,
+  I32 = 3,
+  U64 = 4,
+  I64 = 5,
+  U128 = 6,
+  I128 = 7,
+  U256 = 8,
+  I256 = 9,
+  Address = 10,
+  String = 11,
+  Symbol = 12,
+  Bytes = 13,
+  Vec = 14,
+  Map = 15,
+}
+
+/**
+ * One constructor parameter, in the order the constructor takes them
+ */
+export interface ConstructorParam {
+  default_value: Array<any>;
+  description: string;
+  name: string;
+  param_type: ParamType;
+}
+
+/**
+ * Running tip totals in one token, for a listing or an author
+ */
+export interface TipTotals {
+  supporter_count: u32;
+  tip_count: u32;
+  total_amount: i128;
+}
+
+/**
+ * How serious a security advisory is
+ * None is only used in reports, for hashes without open advisories
+ */
+export enum Severity {
+  None = 0,
+  Low = 1,
+  Medium = 2,
+  High = 3,
+  Critical = 4,
+}
+
+/**
+ * Security advisory against one build of a listing
+ */
+export interface Advisory {
+  advisory_id: u32;
+  by_admin: boolean;
+  contract_id: u32;
+  description: string;
+  published_at: u64;
+  resolved: boolean;
+  severity: Severity;
+  wasm_hash: Buffer;
+}
+
+/**
+ * How strongly a build was verified
+ * Anyone can give a Community verification; Audited ones come from auditors
+ * approved by the admin
+ */
+export enum VerificationLevel {
+  Unverified = 0,
+  Community = 1,
+  Audited = 2,
+}
+
+/**
+ * What the registry knows about a WASM hash
+ */
+export interface HashReport {
+  highest_severity: Severity;
+  listings: Array<u32>;
+  verification: VerificationLevel;
+}
+
 export enum Category {
   DeFi = 0,
   NFT = 1,
@@ -84,6 +234,18 @@ export const Errors = {
   4: { message: "InvalidMetadata" },
   5: { message: "InvalidWasmHash" },
   6: { message: "UnauthorizedVerification" },
+  7: { message: "InvalidRoyalty" },
+  8: { message: "InvalidAmount" },
+  9: { message: "InvalidDependency" },
+  10: { message: "DependencyNotFound" },
+  11: { message: "InvalidForkSource" },
+  12: { message: "InvalidSchema" },
+  13: { message: "ReleaseNotFound" },
+  14: { message: "ReleaseAlreadyExists" },
+  15: { message: "ReleaseYanked" },
+  16: { message: "ContractQuarantined" },
+  17: { message: "AdvisoryNotFound" },
+  18: { message: "TokenNotAllowed" },
 };
 
 export interface Client {
@@ -146,10 +308,111 @@ export interface Client {
   ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a get_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get metadata for a specific contract
+   * Construct and simulate a publish_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish a new version of a contract's code (author only)
+   * The new release becomes the listing's current version
    */
-  get_contract: (
+  publish_release: (
+    {
+      contract_id,
+      version,
+      wasm_hash,
+    }: { contract_id: u32; version: string; wasm_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a yank_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a release so it can no longer be deployed (author only)
+   */
+  yank_release: (
+    { contract_id, version }: { contract_id: u32; version: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_quarantined transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Block (or unblock) every release of a contract from deployment (admin only)
+   */
+  set_quarantined: (
+    { contract_id, quarantined }: { contract_id: u32; quarantined: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific release of a contract
+   */
+  get_release: (
+    { contract_id, version }: { contract_id: u32; version: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Release>>>;
+
+  /**
+   * Construct and simulate a get_releases transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all releases of a contract, oldest first
+   */
+  get_releases: (
     { contract_id }: { contract_id: u32 },
     options?: {
       /**
@@ -167,35 +430,40 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<ContractMetadata>>>;
+  ) => Promise<AssembledTransaction<Array<Release>>>;
 
   /**
-   * Construct and simulate a get_all_contracts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all published contracts
+   * Construct and simulate a find_release_by_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Find the release of a listing that published a WASM hash
+   * Returns None if no release of the listing uses the hash
    */
-  get_all_contracts: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  find_release_by_hash: (
+    { contract_id, wasm_hash }: { contract_id: u32; wasm_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<string>>>;
 
   /**
-   * Construct and simulate a search_by_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Search contracts by category
+   * Construct and simulate a resolve_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the WASM hash of a release that may be deployed
+   * Fails for unknown, yanked or quarantined releases
    */
-  search_by_category: (
-    { category }: { category: Category },
+  resolve_release: (
+    { contract_id, version }: { contract_id: u32; version: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -212,14 +480,29 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+  ) => Promise<AssembledTransaction<Result<Buffer>>>;
 
   /**
-   * Construct and simulate a search_by_tag transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Search contracts by tag
+   * Construct and simulate a publish_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish a security advisory against one of a listing's builds
+   * Only the listing's author or the registry admin can publish advisories
+   * An author's advisory only counts against the hash as a whole when their
+   * listing was the first to release it, so copies can't flag the original
    */
-  search_by_tag: (
-    { tag }: { tag: string },
+  publish_advisory: (
+    {
+      publisher,
+      contract_id,
+      wasm_hash,
+      severity,
+      description,
+    }: {
+      publisher: string;
+      contract_id: u32;
+      wasm_hash: Buffer;
+      severity: Severity;
+      description: string;
+    },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -236,14 +519,114 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+  ) => Promise<AssembledTransaction<Result<u32>>>;
 
   /**
-   * Construct and simulate a verify_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Verify a contract (auditors only - for MVP, anyone can verify for testing)
+   * Construct and simulate a resolve_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark an advisory as resolved (listing author or registry admin only)
    */
-  verify_contract: (
-    { contract_id, auditor }: { contract_id: u32; auditor: string },
+  resolve_advisory: (
+    { publisher, advisory_id }: { publisher: string; advisory_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_advisories transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all advisories published against a listing, including resolved ones
+   */
+  get_advisories: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<Advisory>>>;
+
+  /**
+   * Construct and simulate a get_hash_report transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Summarize what the registry knows about a WASM hash: the listings that
+   * released it, how strongly it was verified, and its worst open advisory
+   * Only advisories from the admin or from the listing that first released
+   * the hash count; the rest stay visible through get_advisories
+   */
+  get_hash_report: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<HashReport>>;
+
+  /**
+   * Construct and simulate a set_deploy_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set (or clear) the price deployers pay per deployment (author only)
+   */
+  set_deploy_price: (
+    { contract_id, price }: { contract_id: u32; price: Option<DeployPrice> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_revenue_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Split author revenue with co-authors (author only)
+   * Shares are in basis points; the author keeps whatever is left
+   */
+  set_revenue_split: (
+    { contract_id, shares }: { contract_id: u32; shares: Array<RevenueShare> },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -262,9 +645,566 @@ export interface Client {
     },
   ) => Promise<AssembledTransaction<Result<void>>>;
 
+  /**
+   * Construct and simulate a get_deploy_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the deploy price of a contract, if the author set one
+   */
+  get_deploy_price: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<DeployPrice>>>;
+
+  /**
+   * Construct and simulate a get_payout_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the author and revenue split of a contract
+   */
+  get_payout_terms: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<PayoutTerms>>>;
+
+  /**
+   * Construct and simulate a set_dependencies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Declare the listings a contract depends on (author only)
+   * Replaces any previously declared dependencies
+   */
+  set_dependencies: (
+    {
+      contract_id,
+      dependencies,
+    }: { contract_id: u32; dependencies: Array<Dependency> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_dependencies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the listings a contract depends on
+   */
+  get_dependencies: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<Dependency>>>;
+
+  /**
+   * Construct and simulate a get_dependents transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contracts that declare a dependency on this one
+   */
+  get_dependents: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a set_constructor_schema transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Describe the constructor arguments of a contract (author only)
+   * Parameters with defaults may only follow required ones, so deployers
+   * can omit trailing arguments
+   */
+  set_constructor_schema: (
+    {
+      contract_id,
+      params,
+    }: { contract_id: u32; params: Array<ConstructorParam> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_constructor_schema transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the constructor schema of a contract, if the author attached one
+   */
+  get_constructor_schema: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<Array<ConstructorParam>>>>;
+
+  /**
+   * Construct and simulate a set_tip_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allow or disallow a token for tips (admin only)
+   */
+  set_tip_token: (
+    { token, allowed }: { token: string; allowed: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<void>>;
+
+  /**
+   * Construct and simulate a get_tip_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the tokens tips can be paid in
+   */
+  get_tip_tokens: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a tip_author transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tip the author of a contract (split with co-authors like other revenue)
+   * Only tokens allowed by the admin are accepted
+   */
+  tip_author: (
+    {
+      tipper,
+      contract_id,
+      token,
+      amount,
+    }: { tipper: string; contract_id: u32; token: string; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_listing_tips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get tip totals received by a contract in a token
+   */
+  get_listing_tips: (
+    { contract_id, token }: { contract_id: u32; token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<TipTotals>>;
+
+  /**
+   * Construct and simulate a get_author_tips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get tip totals received by an author across their contracts in a token
+   */
+  get_author_tips: (
+    { author, token }: { author: string; token: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<TipTotals>>;
+
+  /**
+   * Construct and simulate a get_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get metadata for a specific contract
+   */
+  get_contract: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a get_all_contracts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all published contracts
+   */
+  get_all_contracts: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a search_by_category transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Search contracts by category
+   */
+  search_by_category: (
+    { category }: { category: Category },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a get_forks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the direct forks of a contract
+   */
+  get_forks: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a get_fork_ancestry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the chain of releases a contract was derived from, closest first
+   */
+  get_fork_ancestry: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<ForkSource>>>>;
+
+  /**
+   * Construct and simulate a search_by_tag transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Search contracts by tag
+   */
+  search_by_tag: (
+    { tag }: { tag: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<ContractMetadata>>>;
+
+  /**
+   * Construct and simulate a set_auditor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve or remove an auditor (admin only)
+   */
+  set_auditor: (
+    { auditor, allowed }: { auditor: string; allowed: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<void>>;
+
+  /**
+   * Construct and simulate a get_auditors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the auditors approved by the admin
+   */
+  get_auditors: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a verify_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verify a contract's current release (for MVP, anyone can verify for testing)
+   * The release's hash is Audited when verified by an approved auditor and
+   * Community otherwise; publishing a new release clears the listing's flag
+   */
+  verify_contract: (
+    { contract_id, auditor }: { contract_id: u32; auditor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_deployment_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the DeploymentManager allowed to report deployments (admin only)
+   */
+  set_deployment_manager: (
+    { manager }: { manager: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<void>>;
+
+  /**
+   * Construct and simulate a get_deployment_manager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the configured DeploymentManager, if any
+   */
+  get_deployment_manager: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>;
+
   /**
    * Construct and simulate a increment_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Increment deployment count (called by DeploymentManager)
+   * Increment deployment count (called by the configured DeploymentManager only)
    */
   increment_deployment_count: (
     { contract_id }: { contract_id: u32 },
@@ -309,6 +1249,8 @@ export interface Client {
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin }: { admin: string },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -320,27 +1262,82 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       },
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options);
+    return ContractClient.deploy({ admin }, options);
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAQAAAAAAAAAAAAAADVB1Ymxpc2hQYXJhbXMAAAAAAAAJAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAAEAAAAAAAAAAHbGljZW5zZQAAAAAQAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAKc291cmNlX3VybAAAAAAAEAAAAAAAAAAEdGFncwAAA+oAAAAQAAAAAAAAAAd2ZXJzaW9uAAAAABAAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAg",
-        "AAAAAQAAAAAAAAAAAAAAEENvbnRyYWN0TWV0YWRhdGEAAAAPAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAARZG9jdW1lbnRhdGlvbl91cmwAAAAAAAAQAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAHbGljZW5zZQAAAAAQAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAMcHVibGlzaGVkX2F0AAAABgAAAAAAAAAKc291cmNlX3VybAAAAAAAEAAAAAAAAAAEdGFncwAAA+oAAAAQAAAAAAAAABF0b3RhbF9kZXBsb3ltZW50cwAAAAAAAAQAAAAAAAAACnVwZGF0ZWRfYXQAAAAAAAYAAAAAAAAACHZlcmlmaWVkAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+        "AAAAAQAAAAAAAAAAAAAADVB1Ymxpc2hQYXJhbXMAAAAAAAAKAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAAEAAAAAAAAAALZm9ya2VkX2Zyb20AAAAH0AAAAApGb3JrZWRGcm9tAAAAAAAAAAAAB2xpY2Vuc2UAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACnNvdXJjZV91cmwAAAAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+        "AAAAAQAAAAAAAAAAAAAAEENvbnRyYWN0TWV0YWRhdGEAAAARAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAARZG9jdW1lbnRhdGlvbl91cmwAAAAAAAAQAAAAAAAAAAtmb3JrZWRfZnJvbQAAAAfQAAAACkZvcmtlZEZyb20AAAAAAAAAAAACaWQAAAAAAAQAAAAAAAAAB2xpY2Vuc2UAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAC3F1YXJhbnRpbmVkAAAAAAEAAAAAAAAACnNvdXJjZV91cmwAAAAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEAAAAAAAAAARdG90YWxfZGVwbG95bWVudHMAAAAAAAAEAAAAAAAAAAp1cGRhdGVkX2F0AAAAAAAGAAAAAAAAAAh2ZXJpZmllZAAAAAEAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+        "AAAAAQAAAChBIHB1Ymxpc2hlZCB2ZXJzaW9uIG9mIGEgY29udHJhY3QncyBjb2RlAAAAAAAAAAdSZWxlYXNlAAAAAAQAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABnlhbmtlZAAAAAAAAQ==",
+        "AAAAAQAAAC9UaGUgbGlzdGluZyByZWxlYXNlIGEgY29udHJhY3Qgd2FzIGRlcml2ZWQgZnJvbQAAAAAAAAAACkZvcmtTb3VyY2UAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAB3JlbGVhc2UAAAAAEA==",
+        "AAAAAgAAALxXaGV0aGVyIGEgY29udHJhY3QgaXMgb3JpZ2luYWwgd29yayBvciBhIG1vZGlmaWVkIGNvcHkgb2YgYW5vdGhlciBsaXN0aW5nCkFuIGVudW0gcmF0aGVyIHRoYW4gT3B0aW9uPEZvcmtTb3VyY2U+OiB0aGUgU0RLJ3MgdGVzdHV0aWxzIGNhbid0IGNvbnZlcnQKb3B0aW9uYWwgc3RydWN0IGZpZWxkcyBvZiBjb250cmFjdCB0eXBlcwAAAAAAAAAKRm9ya2VkRnJvbQAAAAAAAgAAAAAAAAAAAAAACE9yaWdpbmFsAAAAAQAAAAAAAAAERm9yawAAAAEAAAfQAAAACkZvcmtTb3VyY2UAAA==",
+        "AAAAAQAAAD1QcmljZSBjaGFyZ2VkIHRvIGRlcGxveWVycyBlYWNoIHRpbWUgdGhlIGNvbnRyYWN0IGlzIGRlcGxveWVkAAAAAAAAAAAAAAtEZXBsb3lQcmljZQAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAAD5Qb3J0aW9uIG9mIGF1dGhvciByZXZlbnVlIHBhaWQgdG8gYSBjby1hdXRob3IsIGluIGJhc2lzIHBvaW50cwAAAAAAAAAAAAxSZXZlbnVlU2hhcmUAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAlzaGFyZV9icHMAAAAAAAAE",
+        "AAAAAQAAAFtXaG8gZ2V0cyBwYWlkIGZvciBhIGNvbnRyYWN0Cih0aGUgYXV0aG9yIHJlY2VpdmVzIHdoYXRldmVyIHRoZSBjby1hdXRob3Igc2hhcmVzIGxlYXZlIG92ZXIpAAAAAAAAAAALUGF5b3V0VGVybXMAAAAAAgAAAAAAAAAGYXV0aG9yAAAAAAATAAAAAAAAAAZzaGFyZXMAAAAAA+oAAAfQAAAADFJldmVudWVTaGFyZQ==",
+        "AAAAAQAAAEtBIGxpc3RpbmcgdGhpcyBjb250cmFjdCBuZWVkcyBhbG9uZ3NpZGUgaXQsIHdpdGggdGhlIHZlcnNpb25zIGl0IHdvcmtzIHdpdGgAAAAAAAAAAApEZXBlbmRlbmN5AAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAt2ZXJzaW9uX3JlcQAAAAAQ",
+        "AAAAAwAAAB9UeXBlIG9mIGEgY29uc3RydWN0b3IgcGFyYW1ldGVyAAAAAAAAAAAJUGFyYW1UeXBlAAAAAAAAEAAAAAAAAAADQW55AAAAAAAAAAAAAAAABEJvb2wAAAABAAAAAAAAAANVMzIAAAAAAgAAAAAAAAADSTMyAAAAAAMAAAAAAAAAA1U2NAAAAAAEAAAAAAAAAANJNjQAAAAABQAAAAAAAAAEVTEyOAAAAAYAAAAAAAAABEkxMjgAAAAHAAAAAAAAAARVMjU2AAAACAAAAAAAAAAESTI1NgAAAAkAAAAAAAAAB0FkZHJlc3MAAAAACgAAAAAAAAAGU3RyaW5nAAAAAAALAAAAAAAAAAZTeW1ib2wAAAAAAAwAAAAAAAAABUJ5dGVzAAAAAAAADQAAAAAAAAADVmVjAAAAAA4AAAAAAAAAA01hcAAAAAAP",
+        "AAAAAQAAAEJPbmUgY29uc3RydWN0b3IgcGFyYW1ldGVyLCBpbiB0aGUgb3JkZXIgdGhlIGNvbnN0cnVjdG9yIHRha2VzIHRoZW0AAAAAAAAAAAAQQ29uc3RydWN0b3JQYXJhbQAAAAQAAAAAAAAADWRlZmF1bHRfdmFsdWUAAAAAAAPqAAAAAAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACnBhcmFtX3R5cGUAAAAAB9AAAAAJUGFyYW1UeXBlAAAA",
+        "AAAAAQAAADtSdW5uaW5nIHRpcCB0b3RhbHMgaW4gb25lIHRva2VuLCBmb3IgYSBsaXN0aW5nIG9yIGFuIGF1dGhvcgAAAAAAAAAACVRpcFRvdGFscwAAAAAAAAMAAAAAAAAAD3N1cHBvcnRlcl9jb3VudAAAAAAEAAAAAAAAAAl0aXBfY291bnQAAAAAAAAEAAAAAAAAAAx0b3RhbF9hbW91bnQAAAAL",
+        "AAAAAwAAAGNIb3cgc2VyaW91cyBhIHNlY3VyaXR5IGFkdmlzb3J5IGlzCk5vbmUgaXMgb25seSB1c2VkIGluIHJlcG9ydHMsIGZvciBoYXNoZXMgd2l0aG91dCBvcGVuIGFkdmlzb3JpZXMAAAAAAAAAAAhTZXZlcml0eQAAAAUAAAAAAAAABE5vbmUAAAAAAAAAAAAAAANMb3cAAAAAAQAAAAAAAAAGTWVkaXVtAAAAAAACAAAAAAAAAARIaWdoAAAAAwAAAAAAAAAIQ3JpdGljYWwAAAAE",
+        "AAAAAQAAADBTZWN1cml0eSBhZHZpc29yeSBhZ2FpbnN0IG9uZSBidWlsZCBvZiBhIGxpc3RpbmcAAAAAAAAACEFkdmlzb3J5AAAACAAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAAAAAAIYnlfYWRtaW4AAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAxwdWJsaXNoZWRfYXQAAAAGAAAAAAAAAAhyZXNvbHZlZAAAAAEAAAAAAAAACHNldmVyaXR5AAAH0AAAAAhTZXZlcml0eQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+        "AAAAAwAAAIFIb3cgc3Ryb25nbHkgYSBidWlsZCB3YXMgdmVyaWZpZWQKQW55b25lIGNhbiBnaXZlIGEgQ29tbXVuaXR5IHZlcmlmaWNhdGlvbjsgQXVkaXRlZCBvbmVzIGNvbWUgZnJvbSBhdWRpdG9ycwphcHByb3ZlZCBieSB0aGUgYWRtaW4AAAAAAAAAAAAAEVZlcmlmaWNhdGlvbkxldmVsAAAAAAAAAwAAAAAAAAAKVW52ZXJpZmllZAAAAAAAAAAAAAAAAAAJQ29tbXVuaXR5AAAAAAAAAQAAAAAAAAAHQXVkaXRlZAAAAAAC",
+        "AAAAAQAAAClXaGF0IHRoZSByZWdpc3RyeSBrbm93cyBhYm91dCBhIFdBU00gaGFzaAAAAAAAAAAAAAAKSGFzaFJlcG9ydAAAAAAAAwAAAAAAAAAQaGlnaGVzdF9zZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAADHZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAA=",
         "AAAAAwAAAAAAAAAAAAAACENhdGVnb3J5AAAABwAAAAAAAAAERGVGaQAAAAAAAAAAAAAAA05GVAAAAAABAAAAAAAAAANEQU8AAAAAAgAAAAAAAAAGR2FtaW5nAAAAAAADAAAAAAAAAAdVdGlsaXR5AAAAAAQAAAAAAAAABk9yYWNsZQAAAAAABQAAAAAAAAAFT3RoZXIAAAAAAAAG",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABgAAAAAAAAAVQ29udHJhY3RBbHJlYWR5RXhpc3RzAAAAAAAAAQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAElVuYXV0aG9yaXplZFVwZGF0ZQAAAAAAAwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAAAQAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAFAAAAAAAAABhVbmF1dGhvcml6ZWRWZXJpZmljYXRpb24AAAAG",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEgAAAAAAAAAVQ29udHJhY3RBbHJlYWR5RXhpc3RzAAAAAAAAAQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAElVuYXV0aG9yaXplZFVwZGF0ZQAAAAAAAwAAAAAAAAAPSW52YWxpZE1ldGFkYXRhAAAAAAQAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAFAAAAAAAAABhVbmF1dGhvcml6ZWRWZXJpZmljYXRpb24AAAAGAAAAAAAAAA5JbnZhbGlkUm95YWx0eQAAAAAABwAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAgAAAAAAAAAEUludmFsaWREZXBlbmRlbmN5AAAAAAAACQAAAAAAAAASRGVwZW5kZW5jeU5vdEZvdW5kAAAAAAAKAAAAAAAAABFJbnZhbGlkRm9ya1NvdXJjZQAAAAAAAAsAAAAAAAAADUludmFsaWRTY2hlbWEAAAAAAAAMAAAAAAAAAA9SZWxlYXNlTm90Rm91bmQAAAAADQAAAAAAAAAUUmVsZWFzZUFscmVhZHlFeGlzdHMAAAAOAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAADwAAAAAAAAATQ29udHJhY3RRdWFyYW50aW5lZAAAAAAQAAAAAAAAABBBZHZpc29yeU5vdEZvdW5kAAAAEQAAAAAAAAAPVG9rZW5Ob3RBbGxvd2VkAAAAABI=",
         "AAAABQAAAAAAAAAAAAAACVB1Ymxpc2hlZAAAAAAAAAEAAAAJcHVibGlzaGVkAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAC",
         "AAAABQAAAAAAAAAAAAAAB1VwZGF0ZWQAAAAAAQAAAAd1cGRhdGVkAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAg==",
-        "AAAABQAAAAAAAAAAAAAACFZlcmlmaWVkAAAAAQAAAAh2ZXJpZmllZAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEFJlbGVhc2VQdWJsaXNoZWQAAAABAAAAEXJlbGVhc2VfcHVibGlzaGVkAAAAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVJlbGVhc2VZYW5rZWQAAAAAAAABAAAADnJlbGVhc2VfeWFua2VkAAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAC1F1YXJhbnRpbmVkAAAAAAEAAAALcXVhcmFudGluZWQAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAAAAAAC3F1YXJhbnRpbmVkAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEUFkdmlzb3J5UHVibGlzaGVkAAAAAAAAAQAAABJhZHZpc29yeV9wdWJsaXNoZWQAAAAAAAMAAAAAAAAAC2Fkdmlzb3J5X2lkAAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAIc2V2ZXJpdHkAAAfQAAAACFNldmVyaXR5AAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEEFkdmlzb3J5UmVzb2x2ZWQAAAABAAAAEWFkdmlzb3J5X3Jlc29sdmVkAAAAAAAAAQAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAACFZlcmlmaWVkAAAAAQAAAAh2ZXJpZmllZAAAAAMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAAAAAAAVsZXZlbAAAAAAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEFJveWFsdGllc1VwZGF0ZWQAAAABAAAAEXJveWFsdGllc191cGRhdGVkAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE0RlcGVuZGVuY2llc1VwZGF0ZWQAAAAAAQAAABRkZXBlbmRlbmNpZXNfdXBkYXRlZAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADVNjaGVtYVVwZGF0ZWQAAAAAAAABAAAADnNjaGVtYV91cGRhdGVkAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAABlRpcHBlZAAAAAAAAQAAAAZ0aXBwZWQAAAAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAAAZ0aXBwZXIAAAAAABMAAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAA9zdXBwb3J0ZXJfY291bnQAAAAABAAAAAAAAAAC",
         "AAAABQAAAAAAAAAAAAAACERlcGxveWVkAAAAAQAAAAhkZXBsb3llZAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAAAAAABF0b3RhbF9kZXBsb3ltZW50cwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAADFDb25maWd1cmUgdGhlIHJlZ2lzdHJ5IGFkbWluIChtb2RlcmF0ZXMgbGlzdGluZ3MpAAAAAAAADV9fY29uc3RydWN0b3IAAAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
         "AAAAAAAAACZQdWJsaXNoIGEgbmV3IGNvbnRyYWN0IHRvIHRoZSByZWdpc3RyeQAAAAAAEHB1Ymxpc2hfY29udHJhY3QAAAACAAAAAAAAAAZhdXRob3IAAAAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAAA1QdWJsaXNoUGFyYW1zAAAAAAAAAQAAA+kAAAAEAAAAAw==",
         "AAAAAAAAADZVcGRhdGUgbWV0YWRhdGEgZm9yIGFuIGV4aXN0aW5nIGNvbnRyYWN0IChhdXRob3Igb25seSkAAAAAAA91cGRhdGVfbWV0YWRhdGEAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAALZGVzY3JpcHRpb24AAAAD6AAAABAAAAAAAAAAEWRvY3VtZW50YXRpb25fdXJsAAAAAAAD6AAAABAAAAAAAAAABHRhZ3MAAAPoAAAD6gAAABAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAG5QdWJsaXNoIGEgbmV3IHZlcnNpb24gb2YgYSBjb250cmFjdCdzIGNvZGUgKGF1dGhvciBvbmx5KQpUaGUgbmV3IHJlbGVhc2UgYmVjb21lcyB0aGUgbGlzdGluZydzIGN1cnJlbnQgdmVyc2lvbgAAAAAAD3B1Ymxpc2hfcmVsZWFzZQAAAAADAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAd2ZXJzaW9uAAAAABAAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEBXaXRoZHJhdyBhIHJlbGVhc2Ugc28gaXQgY2FuIG5vIGxvbmdlciBiZSBkZXBsb3llZCAoYXV0aG9yIG9ubHkpAAAADHlhbmtfcmVsZWFzZQAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEtCbG9jayAob3IgdW5ibG9jaykgZXZlcnkgcmVsZWFzZSBvZiBhIGNvbnRyYWN0IGZyb20gZGVwbG95bWVudCAoYWRtaW4gb25seSkAAAAAD3NldF9xdWFyYW50aW5lZAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtxdWFyYW50aW5lZAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACRHZXQgYSBzcGVjaWZpYyByZWxlYXNlIG9mIGEgY29udHJhY3QAAAALZ2V0X3JlbGVhc2UAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAQAAA+kAAAfQAAAAB1JlbGVhc2UAAAAAAw==",
+        "AAAAAAAAACxHZXQgYWxsIHJlbGVhc2VzIG9mIGEgY29udHJhY3QsIG9sZGVzdCBmaXJzdAAAAAxnZXRfcmVsZWFzZXMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAAB1JlbGVhc2UA",
+        "AAAAAAAAAHBGaW5kIHRoZSByZWxlYXNlIG9mIGEgbGlzdGluZyB0aGF0IHB1Ymxpc2hlZCBhIFdBU00gaGFzaApSZXR1cm5zIE5vbmUgaWYgbm8gcmVsZWFzZSBvZiB0aGUgbGlzdGluZyB1c2VzIHRoZSBoYXNoAAAAFGZpbmRfcmVsZWFzZV9ieV9oYXNoAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6AAAABA=",
+        "AAAAAAAAAGVHZXQgdGhlIFdBU00gaGFzaCBvZiBhIHJlbGVhc2UgdGhhdCBtYXkgYmUgZGVwbG95ZWQKRmFpbHMgZm9yIHVua25vd24sIHlhbmtlZCBvciBxdWFyYW50aW5lZCByZWxlYXNlcwAAAAAAAA9yZXNvbHZlX3JlbGVhc2UAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+        "AAAAAAAAARNQdWJsaXNoIGEgc2VjdXJpdHkgYWR2aXNvcnkgYWdhaW5zdCBvbmUgb2YgYSBsaXN0aW5nJ3MgYnVpbGRzCk9ubHkgdGhlIGxpc3RpbmcncyBhdXRob3Igb3IgdGhlIHJlZ2lzdHJ5IGFkbWluIGNhbiBwdWJsaXNoIGFkdmlzb3JpZXMKQW4gYXV0aG9yJ3MgYWR2aXNvcnkgb25seSBjb3VudHMgYWdhaW5zdCB0aGUgaGFzaCBhcyBhIHdob2xlIHdoZW4gdGhlaXIKbGlzdGluZyB3YXMgdGhlIGZpcnN0IHRvIHJlbGVhc2UgaXQsIHNvIGNvcGllcyBjYW4ndCBmbGFnIHRoZSBvcmlnaW5hbAAAAAAQcHVibGlzaF9hZHZpc29yeQAAAAUAAAAAAAAACXB1Ymxpc2hlcgAAAAAAABMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAhzZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAERNYXJrIGFuIGFkdmlzb3J5IGFzIHJlc29sdmVkIChsaXN0aW5nIGF1dGhvciBvciByZWdpc3RyeSBhZG1pbiBvbmx5KQAAABByZXNvbHZlX2Fkdmlzb3J5AAAAAgAAAAAAAAAJcHVibGlzaGVyAAAAAAAAEwAAAAAAAAALYWR2aXNvcnlfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEdHZXQgYWxsIGFkdmlzb3JpZXMgcHVibGlzaGVkIGFnYWluc3QgYSBsaXN0aW5nLCBpbmNsdWRpbmcgcmVzb2x2ZWQgb25lcwAAAAAOZ2V0X2Fkdmlzb3JpZXMAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6gAAB9AAAAAIQWR2aXNvcnk=",
+        "AAAAAAAAARFTdW1tYXJpemUgd2hhdCB0aGUgcmVnaXN0cnkga25vd3MgYWJvdXQgYSBXQVNNIGhhc2g6IHRoZSBsaXN0aW5ncyB0aGF0CnJlbGVhc2VkIGl0LCBob3cgc3Ryb25nbHkgaXQgd2FzIHZlcmlmaWVkLCBhbmQgaXRzIHdvcnN0IG9wZW4gYWR2aXNvcnkKT25seSBhZHZpc29yaWVzIGZyb20gdGhlIGFkbWluIG9yIGZyb20gdGhlIGxpc3RpbmcgdGhhdCBmaXJzdCByZWxlYXNlZAp0aGUgaGFzaCBjb3VudDsgdGhlIHJlc3Qgc3RheSB2aXNpYmxlIHRocm91Z2ggZ2V0X2Fkdmlzb3JpZXMAAAAAAAAPZ2V0X2hhc2hfcmVwb3J0AAAAAAEAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAB9AAAAAKSGFzaFJlcG9ydAAA",
+        "AAAAAAAAAENTZXQgKG9yIGNsZWFyKSB0aGUgcHJpY2UgZGVwbG95ZXJzIHBheSBwZXIgZGVwbG95bWVudCAoYXV0aG9yIG9ubHkpAAAAABBzZXRfZGVwbG95X3ByaWNlAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAFcHJpY2UAAAAAAAPoAAAH0AAAAAtEZXBsb3lQcmljZQAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHBTcGxpdCBhdXRob3IgcmV2ZW51ZSB3aXRoIGNvLWF1dGhvcnMgKGF1dGhvciBvbmx5KQpTaGFyZXMgYXJlIGluIGJhc2lzIHBvaW50czsgdGhlIGF1dGhvciBrZWVwcyB3aGF0ZXZlciBpcyBsZWZ0AAAAEXNldF9yZXZlbnVlX3NwbGl0AAAAAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAxSZXZlbnVlU2hhcmUAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADlHZXQgdGhlIGRlcGxveSBwcmljZSBvZiBhIGNvbnRyYWN0LCBpZiB0aGUgYXV0aG9yIHNldCBvbmUAAAAAAAAQZ2V0X2RlcGxveV9wcmljZQAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6AAAB9AAAAALRGVwbG95UHJpY2UA",
+        "AAAAAAAAAC5HZXQgdGhlIGF1dGhvciBhbmQgcmV2ZW51ZSBzcGxpdCBvZiBhIGNvbnRyYWN0AAAAAAAQZ2V0X3BheW91dF90ZXJtcwAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6QAAB9AAAAALUGF5b3V0VGVybXMAAAAAAw==",
+        "AAAAAAAAAGZEZWNsYXJlIHRoZSBsaXN0aW5ncyBhIGNvbnRyYWN0IGRlcGVuZHMgb24gKGF1dGhvciBvbmx5KQpSZXBsYWNlcyBhbnkgcHJldmlvdXNseSBkZWNsYXJlZCBkZXBlbmRlbmNpZXMAAAAAABBzZXRfZGVwZW5kZW5jaWVzAAAAAgAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAMZGVwZW5kZW5jaWVzAAAD6gAAB9AAAAAKRGVwZW5kZW5jeQAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACZHZXQgdGhlIGxpc3RpbmdzIGEgY29udHJhY3QgZGVwZW5kcyBvbgAAAAAAEGdldF9kZXBlbmRlbmNpZXMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAACkRlcGVuZGVuY3kAAA==",
+        "AAAAAAAAADdHZXQgdGhlIGNvbnRyYWN0cyB0aGF0IGRlY2xhcmUgYSBkZXBlbmRlbmN5IG9uIHRoaXMgb25lAAAAAA5nZXRfZGVwZW5kZW50cwAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
+        "AAAAAAAAAJ9EZXNjcmliZSB0aGUgY29uc3RydWN0b3IgYXJndW1lbnRzIG9mIGEgY29udHJhY3QgKGF1dGhvciBvbmx5KQpQYXJhbWV0ZXJzIHdpdGggZGVmYXVsdHMgbWF5IG9ubHkgZm9sbG93IHJlcXVpcmVkIG9uZXMsIHNvIGRlcGxveWVycwpjYW4gb21pdCB0cmFpbGluZyBhcmd1bWVudHMAAAAAFnNldF9jb25zdHJ1Y3Rvcl9zY2hlbWEAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABnBhcmFtcwAAAAAD6gAAB9AAAAAQQ29uc3RydWN0b3JQYXJhbQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAERHZXQgdGhlIGNvbnN0cnVjdG9yIHNjaGVtYSBvZiBhIGNvbnRyYWN0LCBpZiB0aGUgYXV0aG9yIGF0dGFjaGVkIG9uZQAAABZnZXRfY29uc3RydWN0b3Jfc2NoZW1hAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+gAAAPqAAAH0AAAABBDb25zdHJ1Y3RvclBhcmFt",
+        "AAAAAAAAAC9BbGxvdyBvciBkaXNhbGxvdyBhIHRva2VuIGZvciB0aXBzIChhZG1pbiBvbmx5KQAAAAANc2V0X3RpcF90b2tlbgAAAAAAAAIAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAHYWxsb3dlZAAAAAABAAAAAA==",
+        "AAAAAAAAACJHZXQgdGhlIHRva2VucyB0aXBzIGNhbiBiZSBwYWlkIGluAAAAAAAOZ2V0X3RpcF90b2tlbnMAAAAAAAAAAAABAAAD6gAAABM=",
+        "AAAAAAAAAHVUaXAgdGhlIGF1dGhvciBvZiBhIGNvbnRyYWN0IChzcGxpdCB3aXRoIGNvLWF1dGhvcnMgbGlrZSBvdGhlciByZXZlbnVlKQpPbmx5IHRva2VucyBhbGxvd2VkIGJ5IHRoZSBhZG1pbiBhcmUgYWNjZXB0ZWQAAAAAAAAKdGlwX2F1dGhvcgAAAAAABAAAAAAAAAAGdGlwcGVyAAAAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADBHZXQgdGlwIHRvdGFscyByZWNlaXZlZCBieSBhIGNvbnRyYWN0IGluIGEgdG9rZW4AAAAQZ2V0X2xpc3RpbmdfdGlwcwAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAfQAAAACVRpcFRvdGFscwAAAA==",
+        "AAAAAAAAAEZHZXQgdGlwIHRvdGFscyByZWNlaXZlZCBieSBhbiBhdXRob3IgYWNyb3NzIHRoZWlyIGNvbnRyYWN0cyBpbiBhIHRva2VuAAAAAAAPZ2V0X2F1dGhvcl90aXBzAAAAAAIAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAB9AAAAAJVGlwVG90YWxzAAAA",
         "AAAAAAAAACRHZXQgbWV0YWRhdGEgZm9yIGEgc3BlY2lmaWMgY29udHJhY3QAAAAMZ2V0X2NvbnRyYWN0AAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPpAAAH0AAAABBDb250cmFjdE1ldGFkYXRhAAAAAw==",
         "AAAAAAAAABtHZXQgYWxsIHB1Ymxpc2hlZCBjb250cmFjdHMAAAAAEWdldF9hbGxfY29udHJhY3RzAAAAAAAAAAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
         "AAAAAAAAABxTZWFyY2ggY29udHJhY3RzIGJ5IGNhdGVnb3J5AAAAEnNlYXJjaF9ieV9jYXRlZ29yeQAAAAAAAQAAAAAAAAAIY2F0ZWdvcnkAAAfQAAAACENhdGVnb3J5AAAAAQAAA+oAAAfQAAAAEENvbnRyYWN0TWV0YWRhdGE=",
+        "AAAAAAAAACJHZXQgdGhlIGRpcmVjdCBmb3JrcyBvZiBhIGNvbnRyYWN0AAAAAAAJZ2V0X2ZvcmtzAAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPqAAAH0AAAABBDb250cmFjdE1ldGFkYXRh",
+        "AAAAAAAAAERHZXQgdGhlIGNoYWluIG9mIHJlbGVhc2VzIGEgY29udHJhY3Qgd2FzIGRlcml2ZWQgZnJvbSwgY2xvc2VzdCBmaXJzdAAAABFnZXRfZm9ya19hbmNlc3RyeQAAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAACkZvcmtTb3VyY2UAAAAAAAM=",
         "AAAAAAAAABdTZWFyY2ggY29udHJhY3RzIGJ5IHRhZwAAAAANc2VhcmNoX2J5X3RhZwAAAAAAAAEAAAAAAAAAA3RhZwAAAAAQAAAAAQAAA+oAAAfQAAAAEENvbnRyYWN0TWV0YWRhdGE=",
-        "AAAAAAAAAEpWZXJpZnkgYSBjb250cmFjdCAoYXVkaXRvcnMgb25seSAtIGZvciBNVlAsIGFueW9uZSBjYW4gdmVyaWZ5IGZvciB0ZXN0aW5nKQAAAAAAD3ZlcmlmeV9jb250cmFjdAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdhdWRpdG9yAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAADhJbmNyZW1lbnQgZGVwbG95bWVudCBjb3VudCAoY2FsbGVkIGJ5IERlcGxveW1lbnRNYW5hZ2VyKQAAABppbmNyZW1lbnRfZGVwbG95bWVudF9jb3VudAAAAAAAAQAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAClBcHByb3ZlIG9yIHJlbW92ZSBhbiBhdWRpdG9yIChhZG1pbiBvbmx5KQAAAAAAAAtzZXRfYXVkaXRvcgAAAAACAAAAAAAAAAdhdWRpdG9yAAAAABMAAAAAAAAAB2FsbG93ZWQAAAAAAQAAAAA=",
+        "AAAAAAAAACZHZXQgdGhlIGF1ZGl0b3JzIGFwcHJvdmVkIGJ5IHRoZSBhZG1pbgAAAAAADGdldF9hdWRpdG9ycwAAAAAAAAABAAAD6gAAABM=",
+        "AAAAAAAAANtWZXJpZnkgYSBjb250cmFjdCdzIGN1cnJlbnQgcmVsZWFzZSAoZm9yIE1WUCwgYW55b25lIGNhbiB2ZXJpZnkgZm9yIHRlc3RpbmcpClRoZSByZWxlYXNlJ3MgaGFzaCBpcyBBdWRpdGVkIHdoZW4gdmVyaWZpZWQgYnkgYW4gYXBwcm92ZWQgYXVkaXRvciBhbmQKQ29tbXVuaXR5IG90aGVyd2lzZTsgcHVibGlzaGluZyBhIG5ldyByZWxlYXNlIGNsZWFycyB0aGUgbGlzdGluZydzIGZsYWcAAAAAD3ZlcmlmeV9jb250cmFjdAAAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdhdWRpdG9yAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAERTZXQgdGhlIERlcGxveW1lbnRNYW5hZ2VyIGFsbG93ZWQgdG8gcmVwb3J0IGRlcGxveW1lbnRzIChhZG1pbiBvbmx5KQAAABZzZXRfZGVwbG95bWVudF9tYW5hZ2VyAAAAAAABAAAAAAAAAAdtYW5hZ2VyAAAAABMAAAAA",
+        "AAAAAAAAACxHZXQgdGhlIGNvbmZpZ3VyZWQgRGVwbG95bWVudE1hbmFnZXIsIGlmIGFueQAAABZnZXRfZGVwbG95bWVudF9tYW5hZ2VyAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAExJbmNyZW1lbnQgZGVwbG95bWVudCBjb3VudCAoY2FsbGVkIGJ5IHRoZSBjb25maWd1cmVkIERlcGxveW1lbnRNYW5hZ2VyIG9ubHkpAAAAGmluY3JlbWVudF9kZXBsb3ltZW50X2NvdW50AAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAAB1HZXQgdG90YWwgbnVtYmVyIG9mIGNvbnRyYWN0cwAAAAAAABJnZXRfY29udHJhY3RfY291bnQAAAAAAAAAAAABAAAABA==",
       ]),
       options,
@@ -349,11 +1346,42 @@ export class Client extends ContractClient {
   public readonly fromJSON = {
     publish_contract: this.txFromJSON<Result<u32>>,
     update_metadata: this.txFromJSON<Result<void>>,
+    publish_release: this.txFromJSON<Result<void>>,
+    yank_release: this.txFromJSON<Result<void>>,
+    set_quarantined: this.txFromJSON<Result<void>>,
+    get_release: this.txFromJSON<Result<Release>>,
+    get_releases: this.txFromJSON<Array<Release>>,
+    find_release_by_hash: this.txFromJSON<Option<string>>,
+    resolve_release: this.txFromJSON<Result<Buffer>>,
+    publish_advisory: this.txFromJSON<Result<u32>>,
+    resolve_advisory: this.txFromJSON<Result<void>>,
+    get_advisories: this.txFromJSON<Array<Advisory>>,
+    get_hash_report: this.txFromJSON<HashReport>,
+    set_deploy_price: this.txFromJSON<Result<void>>,
+    set_revenue_split: this.txFromJSON<Result<void>>,
+    get_deploy_price: this.txFromJSON<Option<DeployPrice>>,
+    get_payout_terms: this.txFromJSON<Result<PayoutTerms>>,
+    set_dependencies: this.txFromJSON<Result<void>>,
+    get_dependencies: this.txFromJSON<Array<Dependency>>,
+    get_dependents: this.txFromJSON<Array<ContractMetadata>>,
+    set_constructor_schema: this.txFromJSON<Result<void>>,
+    get_constructor_schema: this.txFromJSON<Option<Array<ConstructorParam>>>,
+    set_tip_token: this.txFromJSON<void>,
+    get_tip_tokens: this.txFromJSON<Array<string>>,
+    tip_author: this.txFromJSON<Result<void>>,
+    get_listing_tips: this.txFromJSON<TipTotals>,
+    get_author_tips: this.txFromJSON<TipTotals>,
     get_contract: this.txFromJSON<Result<ContractMetadata>>,
     get_all_contracts: this.txFromJSON<Array<ContractMetadata>>,
     search_by_category: this.txFromJSON<Array<ContractMetadata>>,
+    get_forks: this.txFromJSON<Array<ContractMetadata>>,
+    get_fork_ancestry: this.txFromJSON<Result<Array<ForkSource>>>,
     search_by_tag: this.txFromJSON<Array<ContractMetadata>>,
+    set_auditor: this.txFromJSON<void>,
+    get_auditors: this.txFromJSON<Array<string>>,
     verify_contract: this.txFromJSON<Result<void>>,
+    set_deployment_manager: this.txFromJSON<void>,
+    get_deployment_manager: this.txFromJSON<Option<string>>,
     increment_deployment_count: this.txFromJSON<Result<void>>,
     get_contract_count: this.txFromJSON<u32>,
   };
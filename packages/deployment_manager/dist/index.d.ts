@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
-import type { u32, u64 } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,15 +10,344 @@ export declare const networks: {
         readonly contractId: "CDPEFPPNJTF6DHRWQVHB7OB22GAQIKQVJOFOTOLLOEPHXBXEW3ZNPK5N";
     };
 };
+/**
+ * The ContractRegistry errors the DeploymentManager tells apart
+ */
+export declare const RegistryErrors: {
+    2: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    15: {
+        message: string;
+    };
+    16: {
+        message: string;
+    };
+};
 export interface DeploymentRecord {
     contract_id: u32;
     deployed_at: u64;
     deployed_contract_address: string;
     deployer: string;
     deployment_id: u32;
+    group_id: Option<u32>;
+    hidden: boolean;
+    info: DeploymentInfo;
+    init_args: Array<any>;
+    intent_id: Option<u32>;
+    managed: boolean;
+    nonce: Option<u64>;
+    owner: string;
+    release: Option<string>;
+    salt: Buffer;
+    status: DeploymentStatus;
+    upgrades: Array<UpgradeEntry>;
+    wasm_hash: Buffer;
+}
+/**
+ * Lifecycle stage of a deployment record
+ * Decommissioned is final
+ */
+export declare enum DeploymentStatus {
+    Active = 0,
+    Archived = 1,
+    Decommissioned = 2
+}
+/**
+ * Environment a deployment serves
+ */
+export declare enum DeploymentEnvironment {
+    Unspecified = 0,
+    Dev = 1,
+    Staging = 2,
+    Production = 3
+}
+/**
+ * Deployer-maintained description of a deployment
+ */
+export interface DeploymentInfo {
+    environment: DeploymentEnvironment;
+    labels: Array<string>;
+    note: string;
+    project: string;
+}
+/**
+ * One code change of a managed instance
+ * wasm_hash and release are the current code; a rollback restores the entry it undoes
+ */
+export interface UpgradeEntry {
+    from_hash: Buffer;
+    from_release: Option<string>;
+    rollback: boolean;
+    to_hash: Buffer;
+    to_release: Option<string>;
+    upgraded_at: u64;
+}
+/**
+ * One listing release deployed as part of a batch
+ * Salts are derived by the manager as in deploy_next
+ */
+export interface BatchStep {
+    contract_id: u32;
+    init_args: Array<any>;
+    links: Array<StepLink>;
+    release: string;
+}
+/**
+ * Replace constructor argument `arg_index` with the address deployed by an earlier `step`
+ */
+export interface StepLink {
+    arg_index: u32;
+    step: u32;
+}
+/**
+ * Requirements a WASM hash must meet before it is deployed
+ */
+export interface DeployPolicy {
+    max_severity: Severity;
+    min_verification: VerificationLevel;
+    require_published: boolean;
+}
+/**
+ * How strongly a build was verified (mirrors the ContractRegistry type)
+ */
+export declare enum VerificationLevel {
+    Unverified = 0,
+    Community = 1,
+    Audited = 2
+}
+/**
+ * Advisory severity (mirrors the ContractRegistry type)
+ */
+export declare enum Severity {
+    None = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+    Critical = 4
+}
+/**
+ * What the registry knows about a WASM hash (mirrors the ContractRegistry type)
+ */
+export interface HashReport {
+    highest_severity: Severity;
+    listings: Array<u32>;
+    verification: VerificationLevel;
+}
+/**
+ * A published version of a listing's code (mirrors the ContractRegistry type)
+ */
+export interface Release {
+    published_at: u64;
+    version: string;
+    wasm_hash: Buffer;
+    yanked: boolean;
+}
+/**
+ * Fully specified deployment proposed for approval
+ * approvers: Addresses allowed to approve, threshold of which must approve
+ * deadline: Timestamp after which the intent can no longer be approved or executed
+ */
+export interface IntentParams {
+    approvers: Array<string>;
+    contract_id: u32;
+    deadline: u64;
+    init_args: Array<any>;
+    release: string;
+    salt: Buffer;
+    threshold: u32;
+}
+/**
+ * Deployment waiting for, or executed after, multi-party approval
+ */
+export interface DeploymentIntent {
+    approvals: Array<string>;
+    approvers: Array<string>;
+    contract_id: u32;
+    deadline: u64;
+    deployment_id: Option<u32>;
+    init_args: Array<any>;
+    intent_id: u32;
+    proposer: string;
+    release: string;
     salt: Buffer;
+    threshold: u32;
+}
+/**
+ * Who a sponsorship pays for
+ * listings: Listings it covers, or empty for any listing
+ */
+export interface SponsorshipParams {
+    deployers: Array<string>;
+    listings: Array<u32>;
+    token: string;
+}
+/**
+ * Budget a sponsor set aside for other deployers' deploy prices
+ */
+export interface Sponsorship {
+    balance: i128;
+    deployers: Array<string>;
+    deployment_count: u32;
+    listings: Array<u32>;
+    spent: i128;
+    sponsor: string;
+    sponsorship_id: u32;
+    token: string;
+}
+/**
+ * One deployment paid from a sponsorship
+ */
+export interface SponsoredDeployment {
+    amount: i128;
+    contract_id: u32;
+    deployed_at: u64;
+    deployer: string;
+    deployment_id: u32;
+}
+/**
+ * Terms of the keep-alive pool (set by the admin)
+ * Instances are extended to `extend_to` ledgers once fewer than `threshold` remain,
+ * and each extension pays `fee` of `token` from the instance's deposit to the caller
+ */
+export interface KeepAliveConfig {
+    extend_to: u32;
+    fee: i128;
+    threshold: u32;
+    token: string;
+}
+/**
+ * Keep-alive deposit of a deployment
+ */
+export interface KeepAlive {
+    balance: i128;
+    extended_at: u32;
+}
+/**
+ * Code an instance ran, recorded at deployment, on managed upgrades and by syncs
+ */
+export interface ObservedHash {
+    observed_at: u64;
+    release: Option<string>;
     wasm_hash: Buffer;
 }
+/**
+ * Invocation on a freshly deployed instance, e.g. a legacy `initialize`
+ * expected: Empty to accept any return value, or the single value it must return
+ */
+export interface PostDeployCall {
+    args: Array<any>;
+    expected: Array<any>;
+    function: string;
+}
+/**
+ * How deployments from a template get their salt
+ */
+export declare enum SaltStrategy {
+    Derived = 0,
+    Provided = 1
+}
+/**
+ * Template parameters set by its owner
+ */
+export interface TemplateParams {
+    contract_id: u32;
+    init_args: Array<any>;
+    name: string;
+    release: string;
+    salt_strategy: SaltStrategy;
+}
+/**
+ * Saved deployment configuration for a listing release
+ */
+export interface DeploymentTemplate {
+    contract_id: u32;
+    init_args: Array<any>;
+    name: string;
+    owner: string;
+    recommended: boolean;
+    release: string;
+    salt_strategy: SaltStrategy;
+    template_id: u32;
+}
+/**
+ * Share of every deploy price kept by the platform
+ */
+export interface PlatformFee {
+    fee_bps: u32;
+    recipient: string;
+}
+/**
+ * Whether a license covers every deployment by its holder or a single one
+ */
+export declare enum LicenseScope {
+    PerDeployer = 0,
+    PerInstance = 1
+}
+/**
+ * License terms an author sells for a listing
+ */
+export interface LicenseOffer {
+    active: boolean;
+    contract_id: u32;
+    duration: u64;
+    offer_id: u32;
+    price: i128;
+    scope: LicenseScope;
+    token: string;
+}
+/**
+ * A license bought from an offer
+ */
+export interface License {
+    contract_id: u32;
+    deployment_id: Option<u32>;
+    expires_at: Option<u64>;
+    holder: string;
+    license_id: u32;
+    offer_id: u32;
+    purchased_at: u64;
+    scope: LicenseScope;
+}
+export interface DeployPrice {
+    amount: i128;
+    token: string;
+}
+export interface RevenueShare {
+    recipient: string;
+    share_bps: u32;
+}
+export interface PayoutTerms {
+    author: string;
+    shares: Array<RevenueShare>;
+}
+export declare enum ParamType {
+    Any = 0,
+    Bool = 1,
+    U32 = 2,
+    I32 = 3,
+    U64 = 4,
+    I64 = 5,
+    U128 = 6,
+    I128 = 7,
+    U256 = 8,
+    I256 = 9,
+    Address = 10,
+    String = 11,
+    Symbol = 12,
+    Bytes = 13,
+    Vec = 14,
+    Map = 15
+}
+export interface ConstructorParam {
+    default_value: Array<any>;
+    description: string;
+    name: string;
+    param_type: ParamType;
+}
 export declare const Errors: {
     1: {
         message: string;
@@ -35,19 +364,84 @@ export declare const Errors: {
     5: {
         message: string;
     };
+    6: {
+        message: string;
+    };
+    7: {
+        message: string;
+    };
+    8: {
+        message: string;
+    };
+    9: {
+        message: string;
+    };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    14: {
+        message: string;
+    };
+    15: {
+        message: string;
+    };
+    16: {
+        message: string;
+    };
+    17: {
+        message: string;
+    };
+    18: {
+        message: string;
+    };
+    19: {
+        message: string;
+    };
+    20: {
+        message: string;
+    };
+    21: {
+        message: string;
+    };
+    22: {
+        message: string;
+    };
+    23: {
+        message: string;
+    };
+    24: {
+        message: string;
+    };
+    25: {
+        message: string;
+    };
+    26: {
+        message: string;
+    };
+    27: {
+        message: string;
+    };
+    28: {
+        message: string;
+    };
 };
 export interface Client {
     /**
-     * Construct and simulate a deploy_with_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Deploy a contract with an admin address constructor parameter
-     * This is a convenience method for the common pattern of contracts with admin initialization
+     * Construct and simulate a set_platform_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the platform cut taken from every deploy price (admin only)
      */
-    deploy_with_admin: ({ contract_id, deployer, wasm_hash, salt, admin }: {
-        contract_id: u32;
-        deployer: string;
-        wasm_hash: Buffer;
-        salt: Buffer;
-        admin: string;
+    set_platform_fee: ({ recipient, fee_bps }: {
+        recipient: string;
+        fee_bps: u32;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -61,19 +455,32 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Result<u32>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a deploy_from_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Deploy a contract from the registry
-     * This deploys a new contract instance on-chain from the WASM hash
-     * init_args: Constructor arguments for the contract (empty vec if no constructor)
+     * Construct and simulate a get_platform_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the platform fee, if one is configured
      */
-    deploy_from_wasm: ({ contract_id, deployer, wasm_hash, salt, init_args }: {
-        contract_id: u32;
-        deployer: string;
-        wasm_hash: Buffer;
-        salt: Buffer;
-        init_args: Array<any>;
+    get_platform_fee: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<PlatformFee>>>;
+    /**
+     * Construct and simulate a set_keep_alive_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Configure the keep-alive pool (admin only)
+     * The deposit token can't change once set, since deposits are held in it
+     */
+    set_keep_alive_config: ({ config }: {
+        config: KeepAliveConfig;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -87,13 +494,31 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Result<u32>>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get a specific deployment record
+     * Construct and simulate a get_keep_alive_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the keep-alive pool terms, if configured
      */
-    get_deployment: ({ deployment_id }: {
-        deployment_id: u32;
+    get_keep_alive_config: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<KeepAliveConfig>>>;
+    /**
+     * Construct and simulate a set_deploy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set or clear the policy every deployment must meet (admin only)
+     */
+    set_deploy_policy: ({ policy }: {
+        policy: Option<DeployPolicy>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -107,13 +532,32 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+    }) => Promise<AssembledTransaction<void>>;
     /**
-     * Construct and simulate a get_deployment_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get deployment history for a specific deployer
+     * Construct and simulate a get_deploy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the policy every deployment must meet, if set
      */
-    get_deployment_history: ({ deployer }: {
+    get_deploy_policy: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<DeployPolicy>>>;
+    /**
+     * Construct and simulate a set_deployer_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set or clear a deployer's own policy, applied on top of the global one
+     */
+    set_deployer_policy: ({ deployer, policy }: {
         deployer: string;
+        policy: Option<DeployPolicy>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -127,13 +571,56 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    }) => Promise<AssembledTransaction<void>>;
     /**
-     * Construct and simulate a get_contract_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get all deployments of a specific contract
+     * Construct and simulate a get_deployer_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a deployer's own policy, if set
      */
-    get_contract_deployments: ({ contract_id }: {
+    get_deployer_policy: ({ deployer }: {
+        deployer: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<DeployPolicy>>>;
+    /**
+     * Construct and simulate a get_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the ContractRegistry address
+     */
+    get_registry: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a deploy_with_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a contract with an admin address constructor parameter
+     * This is a convenience method for the common pattern of contracts with admin initialization
+     */
+    deploy_with_admin: ({ contract_id, deployer, wasm_hash, salt, admin }: {
         contract_id: u32;
+        deployer: string;
+        wasm_hash: Buffer;
+        salt: Buffer;
+        admin: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -147,12 +634,27 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
     /**
-     * Construct and simulate a get_all_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get all deployment records
+     * Construct and simulate a deploy_from_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a contract from the registry
+     * This deploys a new contract instance on-chain from the WASM hash
+     * init_args: Constructor arguments for the contract (empty vec if no constructor)
+     * If the listing has a constructor schema, init_args are checked against it and
+     * omitted trailing arguments take their defaults; mismatches fail with
+     * InvalidParameters
+     * If the author set a deploy price it is charged to the deployer in the same transaction
+     * Only a hash the listing released is licensed, charged and counted against it;
+     * other hashes are recorded as UNLISTED, and listing ids unknown to the registry
+     * are kept on the record without being counted
      */
-    get_all_deployments: (options?: {
+    deploy_from_wasm: ({ contract_id, deployer, wasm_hash, salt, init_args }: {
+        contract_id: u32;
+        deployer: string;
+        wasm_hash: Buffer;
+        salt: Buffer;
+        init_args: Array<any>;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -165,12 +667,19 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
     /**
-     * Construct and simulate a get_total_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get total number of deployments
+     * Construct and simulate a deploy_from_bytes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Upload WASM and deploy it without publishing it to the registry
+     * The record's contract_id is UNLISTED and keeps the uploaded hash; unlisted
+     * deployments are listed by get_contract_deployments(UNLISTED)
      */
-    get_total_deployments: (options?: {
+    deploy_from_bytes: ({ deployer, wasm, salt, init_args }: {
+        deployer: string;
+        wasm: Buffer;
+        salt: Buffer;
+        init_args: Array<any>;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -183,11 +692,1440 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u32>>;
-}
-export declare class Client extends ContractClient {
-    readonly options: ContractClientOptions;
-    static deploy<T = Client>(
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a deploy_from_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a release of a registry listing
+     * The WASM hash is resolved through the ContractRegistry, so the record is
+     * guaranteed to match the listing's published code
+     * Unknown, yanked and quarantined releases are rejected
+     */
+    deploy_from_registry: ({ contract_id, release, deployer, salt, init_args }: {
+        contract_id: u32;
+        release: string;
+        deployer: string;
+        salt: Buffer;
+        init_args: Array<any>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a deploy_and_call transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a release of a registry listing and invoke functions on the new instance
+     * Calls run in order in the same transaction, e.g. a legacy `initialize` followed
+     * by read-only health checks
+     * If a call fails or returns something other than its expected value the
+     * deployment is aborted
+     */
+    deploy_and_call: ({ contract_id, release, deployer, salt, init_args, calls }: {
+        contract_id: u32;
+        release: string;
+        deployer: string;
+        salt: Buffer;
+        init_args: Array<any>;
+        calls: Array<PostDeployCall>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a deploy_next transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a release of a registry listing with a salt derived by the manager
+     * The salt is the SHA-256 of (deployer, contract_id, nonce), where nonce is the
+     * deployer's next unused nonce, so repeated deployments never collide
+     * The nonce is stored on the record so the salt can be reproduced with derive_salt
+     */
+    deploy_next: ({ contract_id, release, deployer, init_args }: {
+        contract_id: u32;
+        release: string;
+        deployer: string;
+        init_args: Array<any>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a deploy_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy several listing releases in order as one group
+     * Each step may replace constructor arguments with addresses deployed by
+     * earlier steps, e.g. a vault taking the address of the token deployed before it
+     * If any step fails the whole batch is rolled back
+     * Returns the group id linking the resulting deployment records
+     */
+    deploy_batch: ({ deployer, steps }: {
+        deployer: string;
+        steps: Array<BatchStep>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_group_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the deployments of a batch, in step order
+     */
+    get_group_deployments: ({ group_id }: {
+        group_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a create_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Save a deployment template for a listing release
+     * Templates saved by the listing's author are marked as recommended
+     */
+    create_template: ({ owner, params }: {
+        owner: string;
+        params: TemplateParams;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a update_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace a template's parameters (template owner only)
+     */
+    update_template: ({ template_id, params }: {
+        template_id: u32;
+        params: TemplateParams;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a delete_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Delete a template (template owner only)
+     */
+    delete_template: ({ template_id }: {
+        template_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a deploy_from_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy from a saved template
+     * salt: Required for templates with a provided salt strategy, omitted for derived salts
+     */
+    deploy_from_template: ({ template_id, deployer, salt }: {
+        template_id: u32;
+        deployer: string;
+        salt: Option<Buffer>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific template
+     */
+    get_template: ({ template_id }: {
+        template_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<DeploymentTemplate>>>;
+    /**
+     * Construct and simulate a get_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all templates saved by an owner
+     */
+    get_templates: ({ owner }: {
+        owner: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentTemplate>>>;
+    /**
+     * Construct and simulate a get_recommended_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the templates a listing's author recommends
+     */
+    get_recommended_templates: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentTemplate>>>;
+    /**
+     * Construct and simulate a propose_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Propose a deployment that needs sign-off before it runs
+     * Executed intents deploy from the manager's own address, so the instance
+     * address is predict_address(manager address, derive_intent_salt(proposer, salt));
+     * the proposer is recorded as the deployer and owner and can't approve
+     */
+    propose_intent: ({ proposer, params }: {
+        proposer: string;
+        params: IntentParams;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a approve_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Approve a pending intent (designated approvers only)
+     */
+    approve_intent: ({ approver, intent_id }: {
+        approver: string;
+        intent_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a execute_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Execute an intent once enough approvers signed off (anyone can call)
+     * The executor pays the listing's deploy price, if any
+     */
+    execute_intent: ({ executor, intent_id }: {
+        executor: string;
+        intent_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific deployment intent
+     */
+    get_intent: ({ intent_id }: {
+        intent_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<DeploymentIntent>>>;
+    /**
+     * Construct and simulate a get_deployer_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the nonce the deployer's next deploy_next call will start from
+     */
+    get_deployer_nonce: ({ deployer }: {
+        deployer: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a derive_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Compute the salt deploy_next uses for a deployer, listing and nonce
+     */
+    derive_salt: ({ deployer, contract_id, nonce }: {
+        deployer: string;
+        contract_id: u32;
+        nonce: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Buffer>>;
+    /**
+     * Construct and simulate a derive_intent_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Compute the salt an intent by `proposer` with `salt` deploys with
+     */
+    derive_intent_salt: ({ proposer, salt }: {
+        proposer: string;
+        salt: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Buffer>>;
+    /**
+     * Construct and simulate a redeploy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy an identical instance of an earlier deployment (original deployer only)
+     * The recorded WASM hash and constructor arguments are reused with a new salt
+     * Deployments of a registry release are rejected once it is yanked or quarantined
+     */
+    redeploy: ({ deployment_id, new_salt }: {
+        deployment_id: u32;
+        new_salt: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a set_managed_upgrades transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Opt an instance in or out of managed upgrades (record owner only)
+     * The instance must expose `upgrade(new_wasm_hash)` and accept this manager as
+     * its upgrade authority, e.g. by being deployed with the manager as its admin
+     */
+    set_managed_upgrades: ({ deployment_id, managed }: {
+        deployment_id: u32;
+        managed: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a upgrade_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Upgrade a managed instance to a newer release of its listing (record owner only)
+     * The release is resolved through the registry, so yanked and quarantined
+     * releases are rejected, and must meet the global and the owner's deploy policy
+     * Releases published no later than the current one fail with ReleaseNotNewer;
+     * use rollback_deployment to undo an upgrade
+     */
+    upgrade_deployment: ({ deployment_id, release }: {
+        deployment_id: u32;
+        release: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a rollback_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Undo the most recent upgrade of a managed instance (record owner only)
+     * Only upgrades can be rolled back, not earlier rollbacks, and the restored
+     * code must meet the global and the owner's deploy policy
+     * Fails if the instance no longer runs the code that upgrade installed,
+     * e.g. after an upgrade made outside the manager
+     */
+    rollback_deployment: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a sync_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Update a record to the code its instance currently runs
+     * Catches upgrades made outside the manager; anyone can call it
+     * Changes are kept in the observed hash history, with the release of the
+     * record's listing that published the new hash, if any
+     * Returns the current WASM hash
+     */
+    sync_deployment: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Buffer>>>;
+    /**
+     * Construct and simulate a get_observed_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the code a deployment has run, oldest first: the deployed hash,
+     * then every managed upgrade or rollback and change seen by sync_deployment
+     */
+    get_observed_hashes: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<ObservedHash>>>;
+    /**
+     * Construct and simulate a predict_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the address a deployment by `deployer` with `salt` will have
+     * Uses the same derivation as the deploy functions
+     */
+    predict_address: ({ deployer, salt }: {
+        deployer: string;
+        salt: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a is_address_taken transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether a contract already exists at the address for `deployer` and `salt`
+     * Deploying with a taken salt fails with AddressTaken
+     */
+    is_address_taken: ({ deployer, salt }: {
+        deployer: string;
+        salt: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a check_init_args transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check constructor arguments against a listing's schema without deploying
+     * Returns the index of the first invalid argument, or None if they would be accepted
+     * Every deploy entry point applies the same check but only reports InvalidParameters
+     */
+    check_init_args: ({ contract_id, init_args }: {
+        contract_id: u32;
+        init_args: Array<any>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<u32>>>;
+    /**
+     * Construct and simulate a get_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific deployment record
+     */
+    get_deployment: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_deployment_by_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the record of the deployment that created a contract address
+     * Fails with ContractNotFound for addresses not deployed through this manager
+     */
+    get_deployment_by_address: ({ address }: {
+        address: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_deployment_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get deployment history for a specific deployer
+     * Hidden records are left out of this and the other public listings
+     */
+    get_deployment_history: ({ deployer }: {
+        deployer: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a set_deployment_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the labels, environment, note and project of a deployment (record owner only)
+     */
+    set_deployment_info: ({ deployment_id, info }: {
+        deployment_id: u32;
+        info: DeploymentInfo;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_project_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get an owner's deployments that belong to a project, including hidden ones
+     */
+    get_project_deployments: ({ owner, project }: {
+        owner: string;
+        project: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_environment_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get an owner's deployments tagged with an environment, including hidden ones
+     */
+    get_environment_deployments: ({ owner, environment }: {
+        owner: string;
+        environment: DeploymentEnvironment;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a set_deployment_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Move a deployment to another lifecycle stage (record owner only)
+     * Decommissioned records can't change stage again
+     */
+    set_deployment_status: ({ deployment_id, status }: {
+        deployment_id: u32;
+        status: DeploymentStatus;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_deployment_hidden transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hide a deployment from public listings, or show it again (record owner only)
+     */
+    set_deployment_hidden: ({ deployment_id, hidden }: {
+        deployment_id: u32;
+        hidden: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a transfer_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hand a deployment record to a new owner, e.g. a team multisig (record owner only)
+     * The original deployer stays on the record and in its deployment history
+     */
+    transfer_deployment: ({ deployment_id, new_owner }: {
+        deployment_id: u32;
+        new_owner: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a get_owned_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the deployments an address owns, including hidden ones
+     */
+    get_owned_deployments: ({ owner }: {
+        owner: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_contract_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all deployments of a specific contract
+     */
+    get_contract_deployments: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_hash_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a page of the deployments running a WASM hash
+     * Deployments are listed in the order they were indexed, except that when
+     * one is upgraded away the last one takes its slot
+     * Hidden records are included, so every affected instance can be found
+     * start: Index of the first deployment to return
+     * limit: Maximum number of records to return
+     */
+    get_hash_deployments: ({ wasm_hash, start, limit }: {
+        wasm_hash: Buffer;
+        start: u32;
+        limit: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_hash_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the number of deployments running a WASM hash
+     */
+    get_hash_deployment_count: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_release_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a page of the deployments of a listing release, ordered like get_hash_deployments
+     * Deployments by WASM hash are indexed under the release that published the hash
+     */
+    get_release_deployments: ({ contract_id, release, start, limit }: {
+        contract_id: u32;
+        release: string;
+        start: u32;
+        limit: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_release_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the number of deployments of a listing release
+     */
+    get_release_deployment_count: ({ contract_id, release }: {
+        contract_id: u32;
+        release: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_all_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all deployment records
+     */
+    get_all_deployments: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+    /**
+     * Construct and simulate a get_total_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get total number of deployments
+     */
+    get_total_deployments: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a fund_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Add an instance to the keep-alive pool, or top up its deposit (record owner only)
+     * amount: Deposit in the pool token, spent on extension fees
+     * Decommissioned instances can't be funded
+     */
+    fund_keep_alive: ({ deployment_id, amount }: {
+        deployment_id: u32;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a leave_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Take an instance out of the keep-alive pool and refund its remaining deposit
+     * (record owner only)
+     * Returns the refunded amount
+     */
+    leave_keep_alive: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a extend_instances transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Extend the TTL of instances in the keep-alive pool that are due (anyone can call)
+     * Each extension covers the instance and its code and pays the caller the pool fee
+     * from that instance's deposit; instances that can't pay the fee are skipped
+     * start: Index of the first pool entry to check
+     * limit: Maximum number of pool entries to check
+     * Returns the number of instances extended
+     */
+    extend_instances: ({ caller, start, limit }: {
+        caller: string;
+        start: u32;
+        limit: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_keep_alive_pool_size transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the number of instances in the keep-alive pool
+     */
+    get_keep_alive_pool_size: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get an instance's keep-alive deposit, if it is in the pool
+     */
+    get_keep_alive: ({ deployment_id }: {
+        deployment_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<KeepAlive>>>;
+    /**
+     * Construct and simulate a create_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set aside a budget that pays deploy prices for allowlisted deployers
+     * amount: Initial budget in `params.token`, held by the manager
+     */
+    create_sponsorship: ({ sponsor, params, amount }: {
+        sponsor: string;
+        params: SponsorshipParams;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a fund_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Add to a sponsorship's budget (sponsor only)
+     */
+    fund_sponsorship: ({ sponsorship_id, amount }: {
+        sponsorship_id: u32;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a set_sponsorship_allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the deployers and listings a sponsorship covers (sponsor only)
+     */
+    set_sponsorship_allowlist: ({ sponsorship_id, deployers, listings }: {
+        sponsorship_id: u32;
+        deployers: Array<string>;
+        listings: Array<u32>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a withdraw_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Refund a sponsorship's remaining budget to the sponsor (sponsor only)
+     * The sponsorship stays, with an empty budget, and can be funded again
+     * Returns the refunded amount
+     */
+    withdraw_sponsorship: ({ sponsorship_id }: {
+        sponsorship_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+     * Construct and simulate a deploy_sponsored transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy a release of a registry listing with the deploy price paid from a sponsorship
+     * The deployer must be on the sponsorship's allowlist, the listing must be covered
+     * and priced in the sponsorship's token, and the budget must cover the price
+     * Exactly the amount debited from the sponsorship is paid out of the manager
+     */
+    deploy_sponsored: ({ sponsorship_id, contract_id, release, deployer, salt, init_args }: {
+        sponsorship_id: u32;
+        contract_id: u32;
+        release: string;
+        deployer: string;
+        salt: Buffer;
+        init_args: Array<any>;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific sponsorship, including its remaining and spent budget
+     */
+    get_sponsorship: ({ sponsorship_id }: {
+        sponsorship_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Sponsorship>>>;
+    /**
+     * Construct and simulate a get_sponsorships transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all sponsorships created by a sponsor
+     */
+    get_sponsorships: ({ sponsor }: {
+        sponsor: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Sponsorship>>>;
+    /**
+     * Construct and simulate a get_sponsorship_usage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get the deployments a sponsorship paid for, oldest first
+     */
+    get_sponsorship_usage: ({ sponsorship_id }: {
+        sponsorship_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<SponsoredDeployment>>>;
+    /**
+     * Construct and simulate a create_license_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Offer licenses for a listing (listing author only)
+     * Once a listing has an offer, deploying it requires a valid license
+     * duration: Seconds a license stays valid after purchase (0 for perpetual)
+     */
+    create_license_offer: ({ contract_id, token, price, duration, scope }: {
+        contract_id: u32;
+        token: string;
+        price: i128;
+        duration: u64;
+        scope: LicenseScope;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a set_license_offer_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Open or close an offer for new purchases (listing author only)
+     * Closing every offer does not unlicense the listing
+     */
+    set_license_offer_active: ({ offer_id, active }: {
+        offer_id: u32;
+        active: boolean;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+     * Construct and simulate a purchase_license transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Buy a license, paying the offer price to the listing's authors
+     */
+    purchase_license: ({ buyer, offer_id }: {
+        buyer: string;
+        offer_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u32>>>;
+    /**
+     * Construct and simulate a get_license_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get a specific license offer
+     */
+    get_license_offer: ({ offer_id }: {
+        offer_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<LicenseOffer>>>;
+    /**
+     * Construct and simulate a get_license_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all license offers for a listing
+     */
+    get_license_offers: ({ contract_id }: {
+        contract_id: u32;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<LicenseOffer>>>;
+    /**
+     * Construct and simulate a get_licenses transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Get all licenses bought by a holder, including expired and used ones
+     */
+    get_licenses: ({ holder }: {
+        holder: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<License>>>;
+    /**
+     * Construct and simulate a has_valid_license transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Check whether a holder can currently deploy a listing
+     */
+    has_valid_license: ({ contract_id, holder }: {
+        contract_id: u32;
+        holder: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+}
+export declare class Client extends ContractClient {
+    readonly options: ContractClientOptions;
+    static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, registry }: {
+        admin: string;
+        registry: string;
+    }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -199,12 +2137,82 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
+        set_platform_fee: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_platform_fee: (json: string) => AssembledTransaction<PlatformFee | undefined>;
+        set_keep_alive_config: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_keep_alive_config: (json: string) => AssembledTransaction<KeepAliveConfig | undefined>;
+        set_deploy_policy: (json: string) => AssembledTransaction<void>;
+        get_deploy_policy: (json: string) => AssembledTransaction<DeployPolicy | undefined>;
+        set_deployer_policy: (json: string) => AssembledTransaction<void>;
+        get_deployer_policy: (json: string) => AssembledTransaction<DeployPolicy | undefined>;
+        get_registry: (json: string) => AssembledTransaction<string>;
         deploy_with_admin: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         deploy_from_wasm: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_from_bytes: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_from_registry: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_and_call: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_next: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_batch: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_group_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        create_template: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        update_template: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        delete_template: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_from_template: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_template: (json: string) => AssembledTransaction<Result<DeploymentTemplate, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_templates: (json: string) => AssembledTransaction<DeploymentTemplate[]>;
+        get_recommended_templates: (json: string) => AssembledTransaction<DeploymentTemplate[]>;
+        propose_intent: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        approve_intent: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        execute_intent: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_intent: (json: string) => AssembledTransaction<Result<DeploymentIntent, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_deployer_nonce: (json: string) => AssembledTransaction<bigint>;
+        derive_salt: (json: string) => AssembledTransaction<Buffer>;
+        derive_intent_salt: (json: string) => AssembledTransaction<Buffer>;
+        redeploy: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_managed_upgrades: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        upgrade_deployment: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        rollback_deployment: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        sync_deployment: (json: string) => AssembledTransaction<Result<Buffer, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_observed_hashes: (json: string) => AssembledTransaction<ObservedHash[]>;
+        predict_address: (json: string) => AssembledTransaction<string>;
+        is_address_taken: (json: string) => AssembledTransaction<boolean>;
+        check_init_args: (json: string) => AssembledTransaction<number | undefined>;
         get_deployment: (json: string) => AssembledTransaction<Result<DeploymentRecord, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_deployment_by_address: (json: string) => AssembledTransaction<Result<DeploymentRecord, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_deployment_history: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        set_deployment_info: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_project_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        get_environment_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        set_deployment_status: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_deployment_hidden: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        transfer_deployment: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_owned_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
         get_contract_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        get_hash_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        get_hash_deployment_count: (json: string) => AssembledTransaction<number>;
+        get_release_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
+        get_release_deployment_count: (json: string) => AssembledTransaction<number>;
         get_all_deployments: (json: string) => AssembledTransaction<DeploymentRecord[]>;
         get_total_deployments: (json: string) => AssembledTransaction<number>;
+        fund_keep_alive: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        leave_keep_alive: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        extend_instances: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_keep_alive_pool_size: (json: string) => AssembledTransaction<number>;
+        get_keep_alive: (json: string) => AssembledTransaction<KeepAlive | undefined>;
+        create_sponsorship: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        fund_sponsorship: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_sponsorship_allowlist: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw_sponsorship: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        deploy_sponsored: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_sponsorship: (json: string) => AssembledTransaction<Result<Sponsorship, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_sponsorships: (json: string) => AssembledTransaction<Sponsorship[]>;
+        get_sponsorship_usage: (json: string) => AssembledTransaction<SponsoredDeployment[]>;
+        create_license_offer: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_license_offer_active: (json: string) => AssembledTransaction<Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        purchase_license: (json: string) => AssembledTransaction<Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_license_offer: (json: string) => AssembledTransaction<Result<LicenseOffer, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_license_offers: (json: string) => AssembledTransaction<LicenseOffer[]>;
+        get_licenses: (json: string) => AssembledTransaction<License[]>;
+        has_valid_license: (json: string) => AssembledTransaction<boolean>;
     };
 }
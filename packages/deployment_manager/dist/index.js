@@ -13,40 +13,336 @@ export const networks = {
         contractId: "CDPEFPPNJTF6DHRWQVHB7OB22GAQIKQVJOFOTOLLOEPHXBXEW3ZNPK5N",
     }
 };
+/**
+ * The ContractRegistry errors the DeploymentManager tells apart
+ */
+export const RegistryErrors = {
+    2: { message: "ContractNotFound" },
+    13: { message: "ReleaseNotFound" },
+    15: { message: "ReleaseYanked" },
+    16: { message: "ContractQuarantined" }
+};
+/**
+ * Lifecycle stage of a deployment record
+ * Decommissioned is final
+ */
+export var DeploymentStatus;
+(function (DeploymentStatus) {
+    DeploymentStatus[DeploymentStatus["Active"] = 0] = "Active";
+    DeploymentStatus[DeploymentStatus["Archived"] = 1] = "Archived";
+    DeploymentStatus[DeploymentStatus["Decommissioned"] = 2] = "Decommissioned";
+})(DeploymentStatus || (DeploymentStatus = {}));
+/**
+ * Environment a deployment serves
+ */
+export var DeploymentEnvironment;
+(function (DeploymentEnvironment) {
+    DeploymentEnvironment[DeploymentEnvironment["Unspecified"] = 0] = "Unspecified";
+    DeploymentEnvironment[DeploymentEnvironment["Dev"] = 1] = "Dev";
+    DeploymentEnvironment[DeploymentEnvironment["Staging"] = 2] = "Staging";
+    DeploymentEnvironment[DeploymentEnvironment["Production"] = 3] = "Production";
+})(DeploymentEnvironment || (DeploymentEnvironment = {}));
+/**
+ * How strongly a build was verified (mirrors the ContractRegistry type)
+ */
+export var VerificationLevel;
+(function (VerificationLevel) {
+    VerificationLevel[VerificationLevel["Unverified"] = 0] = "Unverified";
+    VerificationLevel[VerificationLevel["Community"] = 1] = "Community";
+    VerificationLevel[VerificationLevel["Audited"] = 2] = "Audited";
+})(VerificationLevel || (VerificationLevel = {}));
+/**
+ * Advisory severity (mirrors the ContractRegistry type)
+ */
+export var Severity;
+(function (Severity) {
+    Severity[Severity["None"] = 0] = "None";
+    Severity[Severity["Low"] = 1] = "Low";
+    Severity[Severity["Medium"] = 2] = "Medium";
+    Severity[Severity["High"] = 3] = "High";
+    Severity[Severity["Critical"] = 4] = "Critical";
+})(Severity || (Severity = {}));
+/**
+ * How deployments from a template get their salt
+ */
+export var SaltStrategy;
+(function (SaltStrategy) {
+    SaltStrategy[SaltStrategy["Derived"] = 0] = "Derived";
+    SaltStrategy[SaltStrategy["Provided"] = 1] = "Provided";
+})(SaltStrategy || (SaltStrategy = {}));
+/**
+ * Whether a license covers every deployment by its holder or a single one
+ */
+export var LicenseScope;
+(function (LicenseScope) {
+    LicenseScope[LicenseScope["PerDeployer"] = 0] = "PerDeployer";
+    LicenseScope[LicenseScope["PerInstance"] = 1] = "PerInstance";
+})(LicenseScope || (LicenseScope = {}));
+export var ParamType;
+(function (ParamType) {
+    ParamType[ParamType["Any"] = 0] = "Any";
+    ParamType[ParamType["Bool"] = 1] = "Bool";
+    ParamType[ParamType["U32"] = 2] = "U32";
+    ParamType[ParamType["I32"] = 3] = "I32";
+    ParamType[ParamType["U64"] = 4] = "U64";
+    ParamType[ParamType["I64"] = 5] = "I64";
+    ParamType[ParamType["U128"] = 6] = "U128";
+    ParamType[ParamType["I128"] = 7] = "I128";
+    ParamType[ParamType["U256"] = 8] = "U256";
+    ParamType[ParamType["I256"] = 9] = "I256";
+    ParamType[ParamType["Address"] = 10] = "Address";
+    ParamType[ParamType["String"] = 11] = "String";
+    ParamType[ParamType["Symbol"] = 12] = "Symbol";
+    ParamType[ParamType["Bytes"] = 13] = "Bytes";
+    ParamType[ParamType["Vec"] = 14] = "Vec";
+    ParamType[ParamType["Map"] = 15] = "Map";
+})(ParamType || (ParamType = {}));
 export const Errors = {
     1: { message: "ContractNotFound" },
     2: { message: "DeploymentFailed" },
     3: { message: "InvalidParameters" },
     4: { message: "InvalidWasmHash" },
-    5: { message: "UnauthorizedAccess" }
+    5: { message: "UnauthorizedAccess" },
+    6: { message: "LicenseRequired" },
+    7: { message: "OfferNotFound" },
+    8: { message: "OfferInactive" },
+    9: { message: "ReleaseNotFound" },
+    10: { message: "ReleaseYanked" },
+    11: { message: "ListingQuarantined" },
+    12: { message: "AddressTaken" },
+    13: { message: "TemplateNotFound" },
+    14: { message: "PostDeployCallFailed" },
+    15: { message: "PostDeployCheckFailed" },
+    16: { message: "UpgradeNotManaged" },
+    17: { message: "UpgradeFailed" },
+    18: { message: "KeepAliveNotConfigured" },
+    19: { message: "HashNotPublished" },
+    20: { message: "HashNotVerified" },
+    21: { message: "HashHasAdvisories" },
+    22: { message: "IntentNotFound" },
+    23: { message: "IntentExpired" },
+    24: { message: "IntentNotApproved" },
+    25: { message: "SponsorshipNotFound" },
+    26: { message: "SponsorshipExhausted" },
+    27: { message: "ReleaseNotNewer" },
+    28: { message: "PaymentMismatch" }
 };
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, registry }, 
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ admin, registry }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAAEERlcGxveW1lbnRSZWNvcmQAAAAHAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXBsb3llZF9hdAAAAAAGAAAAAAAAABlkZXBsb3llZF9jb250cmFjdF9hZGRyZXNzAAAAAAAAEwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
-            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAEAAAAAAAAAEERlcGxveW1lbnRGYWlsZWQAAAACAAAAAAAAABFJbnZhbGlkUGFyYW1ldGVycwAAAAAAAAMAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAEAAAAAAAAABJVbmF1dGhvcml6ZWRBY2Nlc3MAAAAAAAU=",
+        super(new ContractSpec(["AAAABAAAAD1UaGUgQ29udHJhY3RSZWdpc3RyeSBlcnJvcnMgdGhlIERlcGxveW1lbnRNYW5hZ2VyIHRlbGxzIGFwYXJ0AAAAAAAAAAAAAA1SZWdpc3RyeUVycm9yAAAAAAAABAAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAD1JlbGVhc2VOb3RGb3VuZAAAAAANAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAADwAAAAAAAAATQ29udHJhY3RRdWFyYW50aW5lZAAAAAAQ",
+            "AAAAAQAAAAAAAAAAAAAAEERlcGxveW1lbnRSZWNvcmQAAAASAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXBsb3llZF9hdAAAAAAGAAAAAAAAABlkZXBsb3llZF9jb250cmFjdF9hZGRyZXNzAAAAAAAAEwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAIZ3JvdXBfaWQAAAPoAAAABAAAAAAAAAAGaGlkZGVuAAAAAAABAAAAAAAAAARpbmZvAAAH0AAAAA5EZXBsb3ltZW50SW5mbwAAAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAJaW50ZW50X2lkAAAAAAAD6AAAAAQAAAAAAAAAB21hbmFnZWQAAAAAAQAAAAAAAAAFbm9uY2UAAAAAAAPoAAAABgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdyZWxlYXNlAAAAA+gAAAAQAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAABnN0YXR1cwAAAAAH0AAAABBEZXBsb3ltZW50U3RhdHVzAAAAAAAAAAh1cGdyYWRlcwAAA+oAAAfQAAAADFVwZ3JhZGVFbnRyeQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+            "AAAAAwAAAD5MaWZlY3ljbGUgc3RhZ2Ugb2YgYSBkZXBsb3ltZW50IHJlY29yZApEZWNvbW1pc3Npb25lZCBpcyBmaW5hbAAAAAAAAAAAABBEZXBsb3ltZW50U3RhdHVzAAAAAwAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhBcmNoaXZlZAAAAAEAAAAAAAAADkRlY29tbWlzc2lvbmVkAAAAAAAC",
+            "AAAAAwAAAB9FbnZpcm9ubWVudCBhIGRlcGxveW1lbnQgc2VydmVzAAAAAAAAAAAVRGVwbG95bWVudEVudmlyb25tZW50AAAAAAAABAAAAAAAAAALVW5zcGVjaWZpZWQAAAAAAAAAAAAAAAADRGV2AAAAAAEAAAAAAAAAB1N0YWdpbmcAAAAAAgAAAAAAAAAKUHJvZHVjdGlvbgAAAAAAAw==",
+            "AAAAAQAAAC9EZXBsb3llci1tYWludGFpbmVkIGRlc2NyaXB0aW9uIG9mIGEgZGVwbG95bWVudAAAAAAAAAAADkRlcGxveW1lbnRJbmZvAAAAAAAEAAAAAAAAAAtlbnZpcm9ubWVudAAAAAfQAAAAFURlcGxveW1lbnRFbnZpcm9ubWVudAAAAAAAAAAAAAAGbGFiZWxzAAAAAAPqAAAAEAAAAAAAAAAEbm90ZQAAABAAAAAAAAAAB3Byb2plY3QAAAAAEA==",
+            "AAAAAQAAAHlPbmUgY29kZSBjaGFuZ2Ugb2YgYSBtYW5hZ2VkIGluc3RhbmNlCndhc21faGFzaCBhbmQgcmVsZWFzZSBhcmUgdGhlIGN1cnJlbnQgY29kZTsgYSByb2xsYmFjayByZXN0b3JlcyB0aGUgZW50cnkgaXQgdW5kb2VzAAAAAAAAAAAAAAxVcGdyYWRlRW50cnkAAAAGAAAAAAAAAAlmcm9tX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAMZnJvbV9yZWxlYXNlAAAD6AAAABAAAAAAAAAACHJvbGxiYWNrAAAAAQAAAAAAAAAHdG9faGFzaAAAAAPuAAAAIAAAAAAAAAAKdG9fcmVsZWFzZQAAAAAD6AAAABAAAAAAAAAAC3VwZ3JhZGVkX2F0AAAAAAY=",
+            "AAAAAQAAAGJPbmUgbGlzdGluZyByZWxlYXNlIGRlcGxveWVkIGFzIHBhcnQgb2YgYSBiYXRjaApTYWx0cyBhcmUgZGVyaXZlZCBieSB0aGUgbWFuYWdlciBhcyBpbiBkZXBsb3lfbmV4dAAAAAAAAAAAAAlCYXRjaFN0ZXAAAAAAAAAEAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAFbGlua3MAAAAAAAPqAAAH0AAAAAhTdGVwTGluawAAAAAAAAAHcmVsZWFzZQAAAAAQ",
+            "AAAAAQAAAFdSZXBsYWNlIGNvbnN0cnVjdG9yIGFyZ3VtZW50IGBhcmdfaW5kZXhgIHdpdGggdGhlIGFkZHJlc3MgZGVwbG95ZWQgYnkgYW4gZWFybGllciBgc3RlcGAAAAAAAAAAAAhTdGVwTGluawAAAAIAAAAAAAAACWFyZ19pbmRleAAAAAAAAAQAAAAAAAAABHN0ZXAAAAAE",
+            "AAAAAQAAADhSZXF1aXJlbWVudHMgYSBXQVNNIGhhc2ggbXVzdCBtZWV0IGJlZm9yZSBpdCBpcyBkZXBsb3llZAAAAAAAAAAMRGVwbG95UG9saWN5AAAAAwAAAAAAAAAMbWF4X3NldmVyaXR5AAAH0AAAAAhTZXZlcml0eQAAAAAAAAAQbWluX3ZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAAAAAAAAAAAEXJlcXVpcmVfcHVibGlzaGVkAAAAAAAAAQ==",
+            "AAAAAwAAAEVIb3cgc3Ryb25nbHkgYSBidWlsZCB3YXMgdmVyaWZpZWQgKG1pcnJvcnMgdGhlIENvbnRyYWN0UmVnaXN0cnkgdHlwZSkAAAAAAAAAAAAAEVZlcmlmaWNhdGlvbkxldmVsAAAAAAAAAwAAAAAAAAAKVW52ZXJpZmllZAAAAAAAAAAAAAAAAAAJQ29tbXVuaXR5AAAAAAAAAQAAAAAAAAAHQXVkaXRlZAAAAAAC",
+            "AAAAAwAAADVBZHZpc29yeSBzZXZlcml0eSAobWlycm9ycyB0aGUgQ29udHJhY3RSZWdpc3RyeSB0eXBlKQAAAAAAAAAAAAAIU2V2ZXJpdHkAAAAFAAAAAAAAAAROb25lAAAAAAAAAAAAAAADTG93AAAAAAEAAAAAAAAABk1lZGl1bQAAAAAAAgAAAAAAAAAESGlnaAAAAAMAAAAAAAAACENyaXRpY2FsAAAABA==",
+            "AAAAAQAAAE1XaGF0IHRoZSByZWdpc3RyeSBrbm93cyBhYm91dCBhIFdBU00gaGFzaCAobWlycm9ycyB0aGUgQ29udHJhY3RSZWdpc3RyeSB0eXBlKQAAAAAAAAAAAAAKSGFzaFJlcG9ydAAAAAAAAwAAAAAAAAAQaGlnaGVzdF9zZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAADHZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAA=",
+            "AAAAAQAAAEtBIHB1Ymxpc2hlZCB2ZXJzaW9uIG9mIGEgbGlzdGluZydzIGNvZGUgKG1pcnJvcnMgdGhlIENvbnRyYWN0UmVnaXN0cnkgdHlwZSkAAAAAAAAAAAdSZWxlYXNlAAAAAAQAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABnlhbmtlZAAAAAAAAQ==",
+            "AAAAAQAAAMpGdWxseSBzcGVjaWZpZWQgZGVwbG95bWVudCBwcm9wb3NlZCBmb3IgYXBwcm92YWwKYXBwcm92ZXJzOiBBZGRyZXNzZXMgYWxsb3dlZCB0byBhcHByb3ZlLCB0aHJlc2hvbGQgb2Ygd2hpY2ggbXVzdCBhcHByb3ZlCmRlYWRsaW5lOiBUaW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIGludGVudCBjYW4gbm8gbG9uZ2VyIGJlIGFwcHJvdmVkIG9yIGV4ZWN1dGVkAAAAAAAAAAAADEludGVudFBhcmFtcwAAAAcAAAAAAAAACWFwcHJvdmVycwAAAAAAA+oAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJdGhyZXNob2xkAAAAAAAABA==",
+            "AAAAAQAAAD9EZXBsb3ltZW50IHdhaXRpbmcgZm9yLCBvciBleGVjdXRlZCBhZnRlciwgbXVsdGktcGFydHkgYXBwcm92YWwAAAAAAAAAABBEZXBsb3ltZW50SW50ZW50AAAACwAAAAAAAAAJYXBwcm92YWxzAAAAAAAD6gAAABMAAAAAAAAACWFwcHJvdmVycwAAAAAAA+oAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAPoAAAABAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
+            "AAAAAQAAAFFXaG8gYSBzcG9uc29yc2hpcCBwYXlzIGZvcgpsaXN0aW5nczogTGlzdGluZ3MgaXQgY292ZXJzLCBvciBlbXB0eSBmb3IgYW55IGxpc3RpbmcAAAAAAAAAAAAAEVNwb25zb3JzaGlwUGFyYW1zAAAAAAAAAwAAAAAAAAAJZGVwbG95ZXJzAAAAAAAD6gAAABMAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAABXRva2VuAAAAAAAAEw==",
+            "AAAAAQAAAD1CdWRnZXQgYSBzcG9uc29yIHNldCBhc2lkZSBmb3Igb3RoZXIgZGVwbG95ZXJzJyBkZXBsb3kgcHJpY2VzAAAAAAAAAAAAAAtTcG9uc29yc2hpcAAAAAAIAAAAAAAAAAdiYWxhbmNlAAAAAAsAAAAAAAAACWRlcGxveWVycwAAAAAAA+oAAAATAAAAAAAAABBkZXBsb3ltZW50X2NvdW50AAAABAAAAAAAAAAIbGlzdGluZ3MAAAPqAAAABAAAAAAAAAAFc3BlbnQAAAAAAAALAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+            "AAAAAQAAACZPbmUgZGVwbG95bWVudCBwYWlkIGZyb20gYSBzcG9uc29yc2hpcAAAAAAAAAAAABNTcG9uc29yZWREZXBsb3ltZW50AAAAAAUAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAALZGVwbG95ZWRfYXQAAAAABgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABA==",
+            "AAAAAQAAANRUZXJtcyBvZiB0aGUga2VlcC1hbGl2ZSBwb29sIChzZXQgYnkgdGhlIGFkbWluKQpJbnN0YW5jZXMgYXJlIGV4dGVuZGVkIHRvIGBleHRlbmRfdG9gIGxlZGdlcnMgb25jZSBmZXdlciB0aGFuIGB0aHJlc2hvbGRgIHJlbWFpbiwKYW5kIGVhY2ggZXh0ZW5zaW9uIHBheXMgYGZlZWAgb2YgYHRva2VuYCBmcm9tIHRoZSBpbnN0YW5jZSdzIGRlcG9zaXQgdG8gdGhlIGNhbGxlcgAAAAAAAAAPS2VlcEFsaXZlQ29uZmlnAAAAAAQAAAAAAAAACWV4dGVuZF90bwAAAAAAAAQAAAAAAAAAA2ZlZQAAAAALAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+            "AAAAAQAAACJLZWVwLWFsaXZlIGRlcG9zaXQgb2YgYSBkZXBsb3ltZW50AAAAAAAAAAAACUtlZXBBbGl2ZQAAAAAAAAIAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAALZXh0ZW5kZWRfYXQAAAAABA==",
+            "AAAAAQAAAE5Db2RlIGFuIGluc3RhbmNlIHJhbiwgcmVjb3JkZWQgYXQgZGVwbG95bWVudCwgb24gbWFuYWdlZCB1cGdyYWRlcyBhbmQgYnkgc3luY3MAAAAAAAAAAAAMT2JzZXJ2ZWRIYXNoAAAAAwAAAAAAAAALb2JzZXJ2ZWRfYXQAAAAABgAAAAAAAAAHcmVsZWFzZQAAAAPoAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+            "AAAAAQAAAJRJbnZvY2F0aW9uIG9uIGEgZnJlc2hseSBkZXBsb3llZCBpbnN0YW5jZSwgZS5nLiBhIGxlZ2FjeSBgaW5pdGlhbGl6ZWAKZXhwZWN0ZWQ6IEVtcHR5IHRvIGFjY2VwdCBhbnkgcmV0dXJuIHZhbHVlLCBvciB0aGUgc2luZ2xlIHZhbHVlIGl0IG11c3QgcmV0dXJuAAAAAAAAAA5Qb3N0RGVwbG95Q2FsbAAAAAAAAwAAAAAAAAAEYXJncwAAA+oAAAAAAAAAAAAAAAhleHBlY3RlZAAAA+oAAAAAAAAAAAAAAAhmdW5jdGlvbgAAABE=",
+            "AAAAAwAAAC5Ib3cgZGVwbG95bWVudHMgZnJvbSBhIHRlbXBsYXRlIGdldCB0aGVpciBzYWx0AAAAAAAAAAAADFNhbHRTdHJhdGVneQAAAAIAAAAAAAAAB0Rlcml2ZWQAAAAAAAAAAAAAAAAIUHJvdmlkZWQAAAAB",
+            "AAAAAQAAACRUZW1wbGF0ZSBwYXJhbWV0ZXJzIHNldCBieSBpdHMgb3duZXIAAAAAAAAADlRlbXBsYXRlUGFyYW1zAAAAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAAB3JlbGVhc2UAAAAAEAAAAAAAAAANc2FsdF9zdHJhdGVneQAAAAAAB9AAAAAMU2FsdFN0cmF0ZWd5",
+            "AAAAAQAAADRTYXZlZCBkZXBsb3ltZW50IGNvbmZpZ3VyYXRpb24gZm9yIGEgbGlzdGluZyByZWxlYXNlAAAAAAAAABJEZXBsb3ltZW50VGVtcGxhdGUAAAAAAAgAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAtyZWNvbW1lbmRlZAAAAAABAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAADXNhbHRfc3RyYXRlZ3kAAAAAAAfQAAAADFNhbHRTdHJhdGVneQAAAAAAAAALdGVtcGxhdGVfaWQAAAAABA==",
+            "AAAAAQAAADBTaGFyZSBvZiBldmVyeSBkZXBsb3kgcHJpY2Uga2VwdCBieSB0aGUgcGxhdGZvcm0AAAAAAAAAC1BsYXRmb3JtRmVlAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEw==",
+            "AAAAAwAAAEdXaGV0aGVyIGEgbGljZW5zZSBjb3ZlcnMgZXZlcnkgZGVwbG95bWVudCBieSBpdHMgaG9sZGVyIG9yIGEgc2luZ2xlIG9uZQAAAAAAAAAADExpY2Vuc2VTY29wZQAAAAIAAAAAAAAAC1BlckRlcGxveWVyAAAAAAAAAAAAAAAAC1Blckluc3RhbmNlAAAAAAE=",
+            "AAAAAQAAACtMaWNlbnNlIHRlcm1zIGFuIGF1dGhvciBzZWxscyBmb3IgYSBsaXN0aW5nAAAAAAAAAAAMTGljZW5zZU9mZmVyAAAABwAAAAAAAAAGYWN0aXZlAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkdXJhdGlvbgAAAAYAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAVzY29wZQAAAAAAB9AAAAAMTGljZW5zZVNjb3BlAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+            "AAAAAQAAAB5BIGxpY2Vuc2UgYm91Z2h0IGZyb20gYW4gb2ZmZXIAAAAAAAAAAAAHTGljZW5zZQAAAAAIAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAD6AAAAAQAAAAAAAAACmV4cGlyZXNfYXQAAAAAA+gAAAAGAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAACmxpY2Vuc2VfaWQAAAAAAAQAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAMcHVyY2hhc2VkX2F0AAAABgAAAAAAAAAFc2NvcGUAAAAAAAfQAAAADExpY2Vuc2VTY29wZQ==",
+            "AAAAAQAAAAAAAAAAAAAAC0RlcGxveVByaWNlAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+            "AAAAAQAAAAAAAAAAAAAADFJldmVudWVTaGFyZQAAAAIAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQ=",
+            "AAAAAQAAAAAAAAAAAAAAC1BheW91dFRlcm1zAAAAAAIAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAxSZXZlbnVlU2hhcmU=",
+            "AAAAAwAAAAAAAAAAAAAACVBhcmFtVHlwZQAAAAAAABAAAAAAAAAAA0FueQAAAAAAAAAAAAAAAARCb29sAAAAAQAAAAAAAAADVTMyAAAAAAIAAAAAAAAAA0kzMgAAAAADAAAAAAAAAANVNjQAAAAABAAAAAAAAAADSTY0AAAAAAUAAAAAAAAABFUxMjgAAAAGAAAAAAAAAARJMTI4AAAABwAAAAAAAAAEVTI1NgAAAAgAAAAAAAAABEkyNTYAAAAJAAAAAAAAAAdBZGRyZXNzAAAAAAoAAAAAAAAABlN0cmluZwAAAAAACwAAAAAAAAAGU3ltYm9sAAAAAAAMAAAAAAAAAAVCeXRlcwAAAAAAAA0AAAAAAAAAA1ZlYwAAAAAOAAAAAAAAAANNYXAAAAAADw==",
+            "AAAAAQAAAAAAAAAAAAAAEENvbnN0cnVjdG9yUGFyYW0AAAAEAAAAAAAAAA1kZWZhdWx0X3ZhbHVlAAAAAAAD6gAAAAAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAApwYXJhbV90eXBlAAAAAAfQAAAACVBhcmFtVHlwZQAAAA==",
+            "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAHAAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAEAAAAAAAAAEERlcGxveW1lbnRGYWlsZWQAAAACAAAAAAAAABFJbnZhbGlkUGFyYW1ldGVycwAAAAAAAAMAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAEAAAAAAAAABJVbmF1dGhvcml6ZWRBY2Nlc3MAAAAAAAUAAAAAAAAAD0xpY2Vuc2VSZXF1aXJlZAAAAAAGAAAAAAAAAA1PZmZlck5vdEZvdW5kAAAAAAAABwAAAAAAAAANT2ZmZXJJbmFjdGl2ZQAAAAAAAAgAAAAAAAAAD1JlbGVhc2VOb3RGb3VuZAAAAAAJAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAACgAAAAAAAAASTGlzdGluZ1F1YXJhbnRpbmVkAAAAAAALAAAAAAAAAAxBZGRyZXNzVGFrZW4AAAAMAAAAAAAAABBUZW1wbGF0ZU5vdEZvdW5kAAAADQAAAAAAAAAUUG9zdERlcGxveUNhbGxGYWlsZWQAAAAOAAAAAAAAABVQb3N0RGVwbG95Q2hlY2tGYWlsZWQAAAAAAAAPAAAAAAAAABFVcGdyYWRlTm90TWFuYWdlZAAAAAAAABAAAAAAAAAADVVwZ3JhZGVGYWlsZWQAAAAAAAARAAAAAAAAABZLZWVwQWxpdmVOb3RDb25maWd1cmVkAAAAAAASAAAAAAAAABBIYXNoTm90UHVibGlzaGVkAAAAEwAAAAAAAAAPSGFzaE5vdFZlcmlmaWVkAAAAABQAAAAAAAAAEUhhc2hIYXNBZHZpc29yaWVzAAAAAAAAFQAAAAAAAAAOSW50ZW50Tm90Rm91bmQAAAAAABYAAAAAAAAADUludGVudEV4cGlyZWQAAAAAAAAXAAAAAAAAABFJbnRlbnROb3RBcHByb3ZlZAAAAAAAABgAAAAAAAAAE1Nwb25zb3JzaGlwTm90Rm91bmQAAAAAGQAAAAAAAAAUU3BvbnNvcnNoaXBFeGhhdXN0ZWQAAAAaAAAAAAAAAA9SZWxlYXNlTm90TmV3ZXIAAAAAGwAAAAAAAAAPUGF5bWVudE1pc21hdGNoAAAAABw=",
             "AAAABQAAAAAAAAAAAAAADURlcGxveWVkRXZlbnQAAAAAAAABAAAADmRlcGxveWVkX2V2ZW50AAAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAAAAAAEGRlcGxveWVkX2FkZHJlc3MAAAATAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAC1JveWFsdHlQYWlkAAAAAAEAAAAMcm95YWx0eV9wYWlkAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAEEluc3RhbmNlVXBncmFkZWQAAAABAAAAEWluc3RhbmNlX3VwZ3JhZGVkAAAAAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAACHJvbGxiYWNrAAAAAQAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAAEERlcGxveW1lbnRTeW5jZWQAAAABAAAAEWRlcGxveW1lbnRfc3luY2VkAAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAAF0RlcGxveW1lbnRTdGF0dXNDaGFuZ2VkAAAAAAEAAAAZZGVwbG95bWVudF9zdGF0dXNfY2hhbmdlZAAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAEERlcGxveW1lbnRTdGF0dXMAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAG0RlcGxveW1lbnRWaXNpYmlsaXR5Q2hhbmdlZAAAAAABAAAAHWRlcGxveW1lbnRfdmlzaWJpbGl0eV9jaGFuZ2VkAAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAZoaWRkZW4AAAAAAAEAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAFURlcGxveW1lbnRUcmFuc2ZlcnJlZAAAAAAAAAEAAAAWZGVwbG95bWVudF90cmFuc2ZlcnJlZAAAAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAADkludGVudFByb3Bvc2VkAAAAAAABAAAAD2ludGVudF9wcm9wb3NlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAADkludGVudEFwcHJvdmVkAAAAAAABAAAAD2ludGVudF9hcHByb3ZlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAIYXBwcm92ZXIAAAATAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAADkludGVudEV4ZWN1dGVkAAAAAAABAAAAD2ludGVudF9leGVjdXRlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAEVNwb25zb3JzaGlwRnVuZGVkAAAAAAAAAQAAABJzcG9uc29yc2hpcF9mdW5kZWQAAAAAAAIAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAD1Nwb25zb3JlZERlcGxveQAAAAABAAAAEHNwb25zb3JlZF9kZXBsb3kAAAADAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAAAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAE0xpY2Vuc2VPZmZlckNyZWF0ZWQAAAAAAQAAABVsaWNlbnNlX29mZmVyX2NyZWF0ZWQAAAAAAAACAAAAAAAAAAhvZmZlcl9pZAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAEExpY2Vuc2VQdXJjaGFzZWQAAAABAAAAEWxpY2Vuc2VfcHVyY2hhc2VkAAAAAAAAAwAAAAAAAAAKbGljZW5zZV9pZAAAAAAABAAAAAAAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAAAAAABmhvbGRlcgAAAAAAEwAAAAAAAAAC",
+            "AAAAAAAAAOZDb25maWd1cmUgdGhlIGFkbWluIGFuZCB0aGUgQ29udHJhY3RSZWdpc3RyeSB0aGlzIG1hbmFnZXIgZGVwbG95cyBmcm9tClRoZSByZWdpc3RyeSdzIGFkbWluIG11c3QgdGhlbiBuYW1lIHRoaXMgbWFuYWdlciB3aXRoIHNldF9kZXBsb3ltZW50X21hbmFnZXI7CnVudGlsIHRoZW4gZGVwbG95bWVudHMgb2YgYSBsaXN0aW5nJ3MgcHVibGlzaGVkIGNvZGUgZmFpbCB3aXRoIFVuYXV0aG9yaXplZEFjY2VzcwAAAAAADV9fY29uc3RydWN0b3IAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACHJlZ2lzdHJ5AAAAEwAAAAA=",
+            "AAAAAAAAAD9TZXQgdGhlIHBsYXRmb3JtIGN1dCB0YWtlbiBmcm9tIGV2ZXJ5IGRlcGxveSBwcmljZSAoYWRtaW4gb25seSkAAAAAEHNldF9wbGF0Zm9ybV9mZWUAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAACpHZXQgdGhlIHBsYXRmb3JtIGZlZSwgaWYgb25lIGlzIGNvbmZpZ3VyZWQAAAAAABBnZXRfcGxhdGZvcm1fZmVlAAAAAAAAAAEAAAPoAAAH0AAAAAtQbGF0Zm9ybUZlZQA=",
+            "AAAAAAAAAHFDb25maWd1cmUgdGhlIGtlZXAtYWxpdmUgcG9vbCAoYWRtaW4gb25seSkKVGhlIGRlcG9zaXQgdG9rZW4gY2FuJ3QgY2hhbmdlIG9uY2Ugc2V0LCBzaW5jZSBkZXBvc2l0cyBhcmUgaGVsZCBpbiBpdAAAAAAAABVzZXRfa2VlcF9hbGl2ZV9jb25maWcAAAAAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAAPS2VlcEFsaXZlQ29uZmlnAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAACxHZXQgdGhlIGtlZXAtYWxpdmUgcG9vbCB0ZXJtcywgaWYgY29uZmlndXJlZAAAABVnZXRfa2VlcF9hbGl2ZV9jb25maWcAAAAAAAAAAAAAAQAAA+gAAAfQAAAAD0tlZXBBbGl2ZUNvbmZpZwA=",
+            "AAAAAAAAAD9TZXQgb3IgY2xlYXIgdGhlIHBvbGljeSBldmVyeSBkZXBsb3ltZW50IG11c3QgbWVldCAoYWRtaW4gb25seSkAAAAAEXNldF9kZXBsb3lfcG9saWN5AAAAAAAAAQAAAAAAAAAGcG9saWN5AAAAAAPoAAAH0AAAAAxEZXBsb3lQb2xpY3kAAAAA",
+            "AAAAAAAAADFHZXQgdGhlIHBvbGljeSBldmVyeSBkZXBsb3ltZW50IG11c3QgbWVldCwgaWYgc2V0AAAAAAAAEWdldF9kZXBsb3lfcG9saWN5AAAAAAAAAAAAAAEAAAPoAAAH0AAAAAxEZXBsb3lQb2xpY3k=",
+            "AAAAAAAAAEZTZXQgb3IgY2xlYXIgYSBkZXBsb3llcidzIG93biBwb2xpY3ksIGFwcGxpZWQgb24gdG9wIG9mIHRoZSBnbG9iYWwgb25lAAAAAAATc2V0X2RlcGxveWVyX3BvbGljeQAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAABnBvbGljeQAAAAAD6AAAB9AAAAAMRGVwbG95UG9saWN5AAAAAA==",
+            "AAAAAAAAACNHZXQgYSBkZXBsb3llcidzIG93biBwb2xpY3ksIGlmIHNldAAAAAATZ2V0X2RlcGxveWVyX3BvbGljeQAAAAABAAAAAAAAAAhkZXBsb3llcgAAABMAAAABAAAD6AAAB9AAAAAMRGVwbG95UG9saWN5",
+            "AAAAAAAAACBHZXQgdGhlIENvbnRyYWN0UmVnaXN0cnkgYWRkcmVzcwAAAAxnZXRfcmVnaXN0cnkAAAAAAAAAAQAAABM=",
             "AAAAAAAAAJhEZXBsb3kgYSBjb250cmFjdCB3aXRoIGFuIGFkbWluIGFkZHJlc3MgY29uc3RydWN0b3IgcGFyYW1ldGVyClRoaXMgaXMgYSBjb252ZW5pZW5jZSBtZXRob2QgZm9yIHRoZSBjb21tb24gcGF0dGVybiBvZiBjb250cmFjdHMgd2l0aCBhZG1pbiBpbml0aWFsaXphdGlvbgAAABFkZXBsb3lfd2l0aF9hZG1pbgAAAAAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAAEAAAAAw==",
-            "AAAAAAAAALREZXBsb3kgYSBjb250cmFjdCBmcm9tIHRoZSByZWdpc3RyeQpUaGlzIGRlcGxveXMgYSBuZXcgY29udHJhY3QgaW5zdGFuY2Ugb24tY2hhaW4gZnJvbSB0aGUgV0FTTSBoYXNoCmluaXRfYXJnczogQ29uc3RydWN0b3IgYXJndW1lbnRzIGZvciB0aGUgY29udHJhY3QgKGVtcHR5IHZlYyBpZiBubyBjb25zdHJ1Y3RvcikAAAAQZGVwbG95X2Zyb21fd2FzbQAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAnpEZXBsb3kgYSBjb250cmFjdCBmcm9tIHRoZSByZWdpc3RyeQpUaGlzIGRlcGxveXMgYSBuZXcgY29udHJhY3QgaW5zdGFuY2Ugb24tY2hhaW4gZnJvbSB0aGUgV0FTTSBoYXNoCmluaXRfYXJnczogQ29uc3RydWN0b3IgYXJndW1lbnRzIGZvciB0aGUgY29udHJhY3QgKGVtcHR5IHZlYyBpZiBubyBjb25zdHJ1Y3RvcikKSWYgdGhlIGxpc3RpbmcgaGFzIGEgY29uc3RydWN0b3Igc2NoZW1hLCBpbml0X2FyZ3MgYXJlIGNoZWNrZWQgYWdhaW5zdCBpdCBhbmQKb21pdHRlZCB0cmFpbGluZyBhcmd1bWVudHMgdGFrZSB0aGVpciBkZWZhdWx0czsgbWlzbWF0Y2hlcyBmYWlsIHdpdGgKSW52YWxpZFBhcmFtZXRlcnMKSWYgdGhlIGF1dGhvciBzZXQgYSBkZXBsb3kgcHJpY2UgaXQgaXMgY2hhcmdlZCB0byB0aGUgZGVwbG95ZXIgaW4gdGhlIHNhbWUgdHJhbnNhY3Rpb24KT25seSBhIGhhc2ggdGhlIGxpc3RpbmcgcmVsZWFzZWQgaXMgbGljZW5zZWQsIGNoYXJnZWQgYW5kIGNvdW50ZWQgYWdhaW5zdCBpdDsKb3RoZXIgaGFzaGVzIGFyZSByZWNvcmRlZCBhcyBVTkxJU1RFRCwgYW5kIGxpc3RpbmcgaWRzIHVua25vd24gdG8gdGhlIHJlZ2lzdHJ5CmFyZSBrZXB0IG9uIHRoZSByZWNvcmQgd2l0aG91dCBiZWluZyBjb3VudGVkAAAAAAAQZGVwbG95X2Zyb21fd2FzbQAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAMdVcGxvYWQgV0FTTSBhbmQgZGVwbG95IGl0IHdpdGhvdXQgcHVibGlzaGluZyBpdCB0byB0aGUgcmVnaXN0cnkKVGhlIHJlY29yZCdzIGNvbnRyYWN0X2lkIGlzIFVOTElTVEVEIGFuZCBrZWVwcyB0aGUgdXBsb2FkZWQgaGFzaDsgdW5saXN0ZWQKZGVwbG95bWVudHMgYXJlIGxpc3RlZCBieSBnZXRfY29udHJhY3RfZGVwbG95bWVudHMoVU5MSVNURUQpAAAAABFkZXBsb3lfZnJvbV9ieXRlcwAAAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEd2FzbQAAAA4AAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAANZEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZwpUaGUgV0FTTSBoYXNoIGlzIHJlc29sdmVkIHRocm91Z2ggdGhlIENvbnRyYWN0UmVnaXN0cnksIHNvIHRoZSByZWNvcmQgaXMKZ3VhcmFudGVlZCB0byBtYXRjaCB0aGUgbGlzdGluZydzIHB1Ymxpc2hlZCBjb2RlClVua25vd24sIHlhbmtlZCBhbmQgcXVhcmFudGluZWQgcmVsZWFzZXMgYXJlIHJlamVjdGVkAAAAAAAUZGVwbG95X2Zyb21fcmVnaXN0cnkAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAARdEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyBhbmQgaW52b2tlIGZ1bmN0aW9ucyBvbiB0aGUgbmV3IGluc3RhbmNlCkNhbGxzIHJ1biBpbiBvcmRlciBpbiB0aGUgc2FtZSB0cmFuc2FjdGlvbiwgZS5nLiBhIGxlZ2FjeSBgaW5pdGlhbGl6ZWAgZm9sbG93ZWQKYnkgcmVhZC1vbmx5IGhlYWx0aCBjaGVja3MKSWYgYSBjYWxsIGZhaWxzIG9yIHJldHVybnMgc29tZXRoaW5nIG90aGVyIHRoYW4gaXRzIGV4cGVjdGVkIHZhbHVlIHRoZQpkZXBsb3ltZW50IGlzIGFib3J0ZWQAAAAAD2RlcGxveV9hbmRfY2FsbAAAAAAGAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAFY2FsbHMAAAAAAAPqAAAH0AAAAA5Qb3N0RGVwbG95Q2FsbAAAAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAASxEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyB3aXRoIGEgc2FsdCBkZXJpdmVkIGJ5IHRoZSBtYW5hZ2VyClRoZSBzYWx0IGlzIHRoZSBTSEEtMjU2IG9mIChkZXBsb3llciwgY29udHJhY3RfaWQsIG5vbmNlKSwgd2hlcmUgbm9uY2UgaXMgdGhlCmRlcGxveWVyJ3MgbmV4dCB1bnVzZWQgbm9uY2UsIHNvIHJlcGVhdGVkIGRlcGxveW1lbnRzIG5ldmVyIGNvbGxpZGUKVGhlIG5vbmNlIGlzIHN0b3JlZCBvbiB0aGUgcmVjb3JkIHNvIHRoZSBzYWx0IGNhbiBiZSByZXByb2R1Y2VkIHdpdGggZGVyaXZlX3NhbHQAAAALZGVwbG95X25leHQAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAATpEZXBsb3kgc2V2ZXJhbCBsaXN0aW5nIHJlbGVhc2VzIGluIG9yZGVyIGFzIG9uZSBncm91cApFYWNoIHN0ZXAgbWF5IHJlcGxhY2UgY29uc3RydWN0b3IgYXJndW1lbnRzIHdpdGggYWRkcmVzc2VzIGRlcGxveWVkIGJ5CmVhcmxpZXIgc3RlcHMsIGUuZy4gYSB2YXVsdCB0YWtpbmcgdGhlIGFkZHJlc3Mgb2YgdGhlIHRva2VuIGRlcGxveWVkIGJlZm9yZSBpdApJZiBhbnkgc3RlcCBmYWlscyB0aGUgd2hvbGUgYmF0Y2ggaXMgcm9sbGVkIGJhY2sKUmV0dXJucyB0aGUgZ3JvdXAgaWQgbGlua2luZyB0aGUgcmVzdWx0aW5nIGRlcGxveW1lbnQgcmVjb3JkcwAAAAAADGRlcGxveV9iYXRjaAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAFc3RlcHMAAAAAAAPqAAAH0AAAAAlCYXRjaFN0ZXAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAC1HZXQgdGhlIGRlcGxveW1lbnRzIG9mIGEgYmF0Y2gsIGluIHN0ZXAgb3JkZXIAAAAAAAAVZ2V0X2dyb3VwX2RlcGxveW1lbnRzAAAAAAAAAQAAAAAAAAAIZ3JvdXBfaWQAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAAHJTYXZlIGEgZGVwbG95bWVudCB0ZW1wbGF0ZSBmb3IgYSBsaXN0aW5nIHJlbGVhc2UKVGVtcGxhdGVzIHNhdmVkIGJ5IHRoZSBsaXN0aW5nJ3MgYXV0aG9yIGFyZSBtYXJrZWQgYXMgcmVjb21tZW5kZWQAAAAAAA9jcmVhdGVfdGVtcGxhdGUAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAZwYXJhbXMAAAAAB9AAAAAOVGVtcGxhdGVQYXJhbXMAAAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAADVSZXBsYWNlIGEgdGVtcGxhdGUncyBwYXJhbWV0ZXJzICh0ZW1wbGF0ZSBvd25lciBvbmx5KQAAAAAAAA91cGRhdGVfdGVtcGxhdGUAAAAAAgAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAGcGFyYW1zAAAAAAfQAAAADlRlbXBsYXRlUGFyYW1zAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAACdEZWxldGUgYSB0ZW1wbGF0ZSAodGVtcGxhdGUgb3duZXIgb25seSkAAAAAD2RlbGV0ZV90ZW1wbGF0ZQAAAAABAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAHJEZXBsb3kgZnJvbSBhIHNhdmVkIHRlbXBsYXRlCnNhbHQ6IFJlcXVpcmVkIGZvciB0ZW1wbGF0ZXMgd2l0aCBhIHByb3ZpZGVkIHNhbHQgc3RyYXRlZ3ksIG9taXR0ZWQgZm9yIGRlcml2ZWQgc2FsdHMAAAAAABRkZXBsb3lfZnJvbV90ZW1wbGF0ZQAAAAMAAAAAAAAAC3RlbXBsYXRlX2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+gAAAPuAAAAIAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAABdHZXQgYSBzcGVjaWZpYyB0ZW1wbGF0ZQAAAAAMZ2V0X3RlbXBsYXRlAAAAAQAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAEAAAPpAAAH0AAAABJEZXBsb3ltZW50VGVtcGxhdGUAAAAAAAM=",
+            "AAAAAAAAACNHZXQgYWxsIHRlbXBsYXRlcyBzYXZlZCBieSBhbiBvd25lcgAAAAANZ2V0X3RlbXBsYXRlcwAAAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAPqAAAH0AAAABJEZXBsb3ltZW50VGVtcGxhdGUAAA==",
+            "AAAAAAAAAC9HZXQgdGhlIHRlbXBsYXRlcyBhIGxpc3RpbmcncyBhdXRob3IgcmVjb21tZW5kcwAAAAAZZ2V0X3JlY29tbWVuZGVkX3RlbXBsYXRlcwAAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6gAAB9AAAAASRGVwbG95bWVudFRlbXBsYXRlAAA=",
+            "AAAAAAAAARVQcm9wb3NlIGEgZGVwbG95bWVudCB0aGF0IG5lZWRzIHNpZ24tb2ZmIGJlZm9yZSBpdCBydW5zCkV4ZWN1dGVkIGludGVudHMgZGVwbG95IGZyb20gdGhlIG1hbmFnZXIncyBvd24gYWRkcmVzcywgc28gdGhlIGluc3RhbmNlCmFkZHJlc3MgaXMgcHJlZGljdF9hZGRyZXNzKG1hbmFnZXIgYWRkcmVzcywgZGVyaXZlX2ludGVudF9zYWx0KHByb3Bvc2VyLCBzYWx0KSk7CnRoZSBwcm9wb3NlciBpcyByZWNvcmRlZCBhcyB0aGUgZGVwbG95ZXIgYW5kIG93bmVyIGFuZCBjYW4ndCBhcHByb3ZlAAAAAAAADnByb3Bvc2VfaW50ZW50AAAAAAACAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAAAxJbnRlbnRQYXJhbXMAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAADRBcHByb3ZlIGEgcGVuZGluZyBpbnRlbnQgKGRlc2lnbmF0ZWQgYXBwcm92ZXJzIG9ubHkpAAAADmFwcHJvdmVfaW50ZW50AAAAAAACAAAAAAAAAAhhcHByb3ZlcgAAABMAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAHlFeGVjdXRlIGFuIGludGVudCBvbmNlIGVub3VnaCBhcHByb3ZlcnMgc2lnbmVkIG9mZiAoYW55b25lIGNhbiBjYWxsKQpUaGUgZXhlY3V0b3IgcGF5cyB0aGUgbGlzdGluZydzIGRlcGxveSBwcmljZSwgaWYgYW55AAAAAAAADmV4ZWN1dGVfaW50ZW50AAAAAAACAAAAAAAAAAhleGVjdXRvcgAAABMAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAACBHZXQgYSBzcGVjaWZpYyBkZXBsb3ltZW50IGludGVudAAAAApnZXRfaW50ZW50AAAAAAABAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAQAAA+kAAAfQAAAAEERlcGxveW1lbnRJbnRlbnQAAAAD",
+            "AAAAAAAAAEJHZXQgdGhlIG5vbmNlIHRoZSBkZXBsb3llcidzIG5leHQgZGVwbG95X25leHQgY2FsbCB3aWxsIHN0YXJ0IGZyb20AAAAAABJnZXRfZGVwbG95ZXJfbm9uY2UAAAAAAAEAAAAAAAAACGRlcGxveWVyAAAAEwAAAAEAAAAG",
+            "AAAAAAAAAENDb21wdXRlIHRoZSBzYWx0IGRlcGxveV9uZXh0IHVzZXMgZm9yIGEgZGVwbG95ZXIsIGxpc3RpbmcgYW5kIG5vbmNlAAAAAAtkZXJpdmVfc2FsdAAAAAADAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABW5vbmNlAAAAAAAABgAAAAEAAAPuAAAAIA==",
+            "AAAAAAAAAEFDb21wdXRlIHRoZSBzYWx0IGFuIGludGVudCBieSBgcHJvcG9zZXJgIHdpdGggYHNhbHRgIGRlcGxveXMgd2l0aAAAAAAAABJkZXJpdmVfaW50ZW50X3NhbHQAAAAAAAIAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+4AAAAg",
+            "AAAAAAAAAOpEZXBsb3kgYW4gaWRlbnRpY2FsIGluc3RhbmNlIG9mIGFuIGVhcmxpZXIgZGVwbG95bWVudCAob3JpZ2luYWwgZGVwbG95ZXIgb25seSkKVGhlIHJlY29yZGVkIFdBU00gaGFzaCBhbmQgY29uc3RydWN0b3IgYXJndW1lbnRzIGFyZSByZXVzZWQgd2l0aCBhIG5ldyBzYWx0CkRlcGxveW1lbnRzIG9mIGEgcmVnaXN0cnkgcmVsZWFzZSBhcmUgcmVqZWN0ZWQgb25jZSBpdCBpcyB5YW5rZWQgb3IgcXVhcmFudGluZWQAAAAAAAhyZWRlcGxveQAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAhuZXdfc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAANpPcHQgYW4gaW5zdGFuY2UgaW4gb3Igb3V0IG9mIG1hbmFnZWQgdXBncmFkZXMgKHJlY29yZCBvd25lciBvbmx5KQpUaGUgaW5zdGFuY2UgbXVzdCBleHBvc2UgYHVwZ3JhZGUobmV3X3dhc21faGFzaClgIGFuZCBhY2NlcHQgdGhpcyBtYW5hZ2VyIGFzCml0cyB1cGdyYWRlIGF1dGhvcml0eSwgZS5nLiBieSBiZWluZyBkZXBsb3llZCB3aXRoIHRoZSBtYW5hZ2VyIGFzIGl0cyBhZG1pbgAAAAAAFHNldF9tYW5hZ2VkX3VwZ3JhZGVzAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAB21hbmFnZWQAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAV1VcGdyYWRlIGEgbWFuYWdlZCBpbnN0YW5jZSB0byBhIG5ld2VyIHJlbGVhc2Ugb2YgaXRzIGxpc3RpbmcgKHJlY29yZCBvd25lciBvbmx5KQpUaGUgcmVsZWFzZSBpcyByZXNvbHZlZCB0aHJvdWdoIHRoZSByZWdpc3RyeSwgc28geWFua2VkIGFuZCBxdWFyYW50aW5lZApyZWxlYXNlcyBhcmUgcmVqZWN0ZWQsIGFuZCBtdXN0IG1lZXQgdGhlIGdsb2JhbCBhbmQgdGhlIG93bmVyJ3MgZGVwbG95IHBvbGljeQpSZWxlYXNlcyBwdWJsaXNoZWQgbm8gbGF0ZXIgdGhhbiB0aGUgY3VycmVudCBvbmUgZmFpbCB3aXRoIFJlbGVhc2VOb3ROZXdlcjsKdXNlIHJvbGxiYWNrX2RlcGxveW1lbnQgdG8gdW5kbyBhbiB1cGdyYWRlAAAAAAAAEnVwZ3JhZGVfZGVwbG95bWVudAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAB3JlbGVhc2UAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAT1VbmRvIHRoZSBtb3N0IHJlY2VudCB1cGdyYWRlIG9mIGEgbWFuYWdlZCBpbnN0YW5jZSAocmVjb3JkIG93bmVyIG9ubHkpCk9ubHkgdXBncmFkZXMgY2FuIGJlIHJvbGxlZCBiYWNrLCBub3QgZWFybGllciByb2xsYmFja3MsIGFuZCB0aGUgcmVzdG9yZWQKY29kZSBtdXN0IG1lZXQgdGhlIGdsb2JhbCBhbmQgdGhlIG93bmVyJ3MgZGVwbG95IHBvbGljeQpGYWlscyBpZiB0aGUgaW5zdGFuY2Ugbm8gbG9uZ2VyIHJ1bnMgdGhlIGNvZGUgdGhhdCB1cGdyYWRlIGluc3RhbGxlZCwKZS5nLiBhZnRlciBhbiB1cGdyYWRlIG1hZGUgb3V0c2lkZSB0aGUgbWFuYWdlcgAAAAAAABNyb2xsYmFja19kZXBsb3ltZW50AAAAAAEAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAQ9VcGRhdGUgYSByZWNvcmQgdG8gdGhlIGNvZGUgaXRzIGluc3RhbmNlIGN1cnJlbnRseSBydW5zCkNhdGNoZXMgdXBncmFkZXMgbWFkZSBvdXRzaWRlIHRoZSBtYW5hZ2VyOyBhbnlvbmUgY2FuIGNhbGwgaXQKQ2hhbmdlcyBhcmUga2VwdCBpbiB0aGUgb2JzZXJ2ZWQgaGFzaCBoaXN0b3J5LCB3aXRoIHRoZSByZWxlYXNlIG9mIHRoZQpyZWNvcmQncyBsaXN0aW5nIHRoYXQgcHVibGlzaGVkIHRoZSBuZXcgaGFzaCwgaWYgYW55ClJldHVybnMgdGhlIGN1cnJlbnQgV0FTTSBoYXNoAAAAAA9zeW5jX2RlcGxveW1lbnQAAAAAAQAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAABAAAD6QAAA+4AAAAgAAAAAw==",
+            "AAAAAAAAAI1HZXQgdGhlIGNvZGUgYSBkZXBsb3ltZW50IGhhcyBydW4sIG9sZGVzdCBmaXJzdDogdGhlIGRlcGxveWVkIGhhc2gsCnRoZW4gZXZlcnkgbWFuYWdlZCB1cGdyYWRlIG9yIHJvbGxiYWNrIGFuZCBjaGFuZ2Ugc2VlbiBieSBzeW5jX2RlcGxveW1lbnQAAAAAAAATZ2V0X29ic2VydmVkX2hhc2hlcwAAAAABAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAEAAAPqAAAH0AAAAAxPYnNlcnZlZEhhc2g=",
+            "AAAAAAAAAHFHZXQgdGhlIGFkZHJlc3MgYSBkZXBsb3ltZW50IGJ5IGBkZXBsb3llcmAgd2l0aCBgc2FsdGAgd2lsbCBoYXZlClVzZXMgdGhlIHNhbWUgZGVyaXZhdGlvbiBhcyB0aGUgZGVwbG95IGZ1bmN0aW9ucwAAAAAAAA9wcmVkaWN0X2FkZHJlc3MAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAAEw==",
+            "AAAAAAAAAIRDaGVjayB3aGV0aGVyIGEgY29udHJhY3QgYWxyZWFkeSBleGlzdHMgYXQgdGhlIGFkZHJlc3MgZm9yIGBkZXBsb3llcmAgYW5kIGBzYWx0YApEZXBsb3lpbmcgd2l0aCBhIHRha2VuIHNhbHQgZmFpbHMgd2l0aCBBZGRyZXNzVGFrZW4AAAAQaXNfYWRkcmVzc190YWtlbgAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAAAE=",
+            "AAAAAAAAAO5DaGVjayBjb25zdHJ1Y3RvciBhcmd1bWVudHMgYWdhaW5zdCBhIGxpc3RpbmcncyBzY2hlbWEgd2l0aG91dCBkZXBsb3lpbmcKUmV0dXJucyB0aGUgaW5kZXggb2YgdGhlIGZpcnN0IGludmFsaWQgYXJndW1lbnQsIG9yIE5vbmUgaWYgdGhleSB3b3VsZCBiZSBhY2NlcHRlZApFdmVyeSBkZXBsb3kgZW50cnkgcG9pbnQgYXBwbGllcyB0aGUgc2FtZSBjaGVjayBidXQgb25seSByZXBvcnRzIEludmFsaWRQYXJhbWV0ZXJzAAAAAAAPY2hlY2tfaW5pdF9hcmdzAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAQAAA+gAAAAE",
             "AAAAAAAAACBHZXQgYSBzcGVjaWZpYyBkZXBsb3ltZW50IHJlY29yZAAAAA5nZXRfZGVwbG95bWVudAAAAAAAAQAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAABAAAD6QAAB9AAAAAQRGVwbG95bWVudFJlY29yZAAAAAM=",
-            "AAAAAAAAAC5HZXQgZGVwbG95bWVudCBoaXN0b3J5IGZvciBhIHNwZWNpZmljIGRlcGxveWVyAAAAAAAWZ2V0X2RlcGxveW1lbnRfaGlzdG9yeQAAAAAAAQAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAAIxHZXQgdGhlIHJlY29yZCBvZiB0aGUgZGVwbG95bWVudCB0aGF0IGNyZWF0ZWQgYSBjb250cmFjdCBhZGRyZXNzCkZhaWxzIHdpdGggQ29udHJhY3ROb3RGb3VuZCBmb3IgYWRkcmVzc2VzIG5vdCBkZXBsb3llZCB0aHJvdWdoIHRoaXMgbWFuYWdlcgAAABlnZXRfZGVwbG95bWVudF9ieV9hZGRyZXNzAAAAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAQAAA+kAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQAAAAD",
+            "AAAAAAAAAHBHZXQgZGVwbG95bWVudCBoaXN0b3J5IGZvciBhIHNwZWNpZmljIGRlcGxveWVyCkhpZGRlbiByZWNvcmRzIGFyZSBsZWZ0IG91dCBvZiB0aGlzIGFuZCB0aGUgb3RoZXIgcHVibGljIGxpc3RpbmdzAAAAFmdldF9kZXBsb3ltZW50X2hpc3RvcnkAAAAAAAEAAAAAAAAACGRlcGxveWVyAAAAEwAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
+            "AAAAAAAAAFFTZXQgdGhlIGxhYmVscywgZW52aXJvbm1lbnQsIG5vdGUgYW5kIHByb2plY3Qgb2YgYSBkZXBsb3ltZW50IChyZWNvcmQgb3duZXIgb25seSkAAAAAAAATc2V0X2RlcGxveW1lbnRfaW5mbwAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAEaW5mbwAAB9AAAAAORGVwbG95bWVudEluZm8AAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAEpHZXQgYW4gb3duZXIncyBkZXBsb3ltZW50cyB0aGF0IGJlbG9uZyB0byBhIHByb2plY3QsIGluY2x1ZGluZyBoaWRkZW4gb25lcwAAAAAAF2dldF9wcm9qZWN0X2RlcGxveW1lbnRzAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAHcHJvamVjdAAAAAAQAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAAExHZXQgYW4gb3duZXIncyBkZXBsb3ltZW50cyB0YWdnZWQgd2l0aCBhbiBlbnZpcm9ubWVudCwgaW5jbHVkaW5nIGhpZGRlbiBvbmVzAAAAG2dldF9lbnZpcm9ubWVudF9kZXBsb3ltZW50cwAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC2Vudmlyb25tZW50AAAAB9AAAAAVRGVwbG95bWVudEVudmlyb25tZW50AAAAAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAAHBNb3ZlIGEgZGVwbG95bWVudCB0byBhbm90aGVyIGxpZmVjeWNsZSBzdGFnZSAocmVjb3JkIG93bmVyIG9ubHkpCkRlY29tbWlzc2lvbmVkIHJlY29yZHMgY2FuJ3QgY2hhbmdlIHN0YWdlIGFnYWluAAAAFXNldF9kZXBsb3ltZW50X3N0YXR1cwAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAQRGVwbG95bWVudFN0YXR1cwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAExIaWRlIGEgZGVwbG95bWVudCBmcm9tIHB1YmxpYyBsaXN0aW5ncywgb3Igc2hvdyBpdCBhZ2FpbiAocmVjb3JkIG93bmVyIG9ubHkpAAAAFXNldF9kZXBsb3ltZW50X2hpZGRlbgAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZoaWRkZW4AAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+            "AAAAAAAAAJlIYW5kIGEgZGVwbG95bWVudCByZWNvcmQgdG8gYSBuZXcgb3duZXIsIGUuZy4gYSB0ZWFtIG11bHRpc2lnIChyZWNvcmQgb3duZXIgb25seSkKVGhlIG9yaWdpbmFsIGRlcGxveWVyIHN0YXlzIG9uIHRoZSByZWNvcmQgYW5kIGluIGl0cyBkZXBsb3ltZW50IGhpc3RvcnkAAAAAAAATdHJhbnNmZXJfZGVwbG95bWVudAAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAJbmV3X293bmVyAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAADpHZXQgdGhlIGRlcGxveW1lbnRzIGFuIGFkZHJlc3Mgb3ducywgaW5jbHVkaW5nIGhpZGRlbiBvbmVzAAAAAAAVZ2V0X293bmVkX2RlcGxveW1lbnRzAAAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
             "AAAAAAAAACpHZXQgYWxsIGRlcGxveW1lbnRzIG9mIGEgc3BlY2lmaWMgY29udHJhY3QAAAAAABhnZXRfY29udHJhY3RfZGVwbG95bWVudHMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAAUlHZXQgYSBwYWdlIG9mIHRoZSBkZXBsb3ltZW50cyBydW5uaW5nIGEgV0FTTSBoYXNoCkRlcGxveW1lbnRzIGFyZSBsaXN0ZWQgaW4gdGhlIG9yZGVyIHRoZXkgd2VyZSBpbmRleGVkLCBleGNlcHQgdGhhdCB3aGVuCm9uZSBpcyB1cGdyYWRlZCBhd2F5IHRoZSBsYXN0IG9uZSB0YWtlcyBpdHMgc2xvdApIaWRkZW4gcmVjb3JkcyBhcmUgaW5jbHVkZWQsIHNvIGV2ZXJ5IGFmZmVjdGVkIGluc3RhbmNlIGNhbiBiZSBmb3VuZApzdGFydDogSW5kZXggb2YgdGhlIGZpcnN0IGRlcGxveW1lbnQgdG8gcmV0dXJuCmxpbWl0OiBNYXhpbXVtIG51bWJlciBvZiByZWNvcmRzIHRvIHJldHVybgAAAAAAABRnZXRfaGFzaF9kZXBsb3ltZW50cwAAAAMAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
+            "AAAAAAAAADFHZXQgdGhlIG51bWJlciBvZiBkZXBsb3ltZW50cyBydW5uaW5nIGEgV0FTTSBoYXNoAAAAAAAAGWdldF9oYXNoX2RlcGxveW1lbnRfY291bnQAAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAE",
+            "AAAAAAAAAKRHZXQgYSBwYWdlIG9mIHRoZSBkZXBsb3ltZW50cyBvZiBhIGxpc3RpbmcgcmVsZWFzZSwgb3JkZXJlZCBsaWtlIGdldF9oYXNoX2RlcGxveW1lbnRzCkRlcGxveW1lbnRzIGJ5IFdBU00gaGFzaCBhcmUgaW5kZXhlZCB1bmRlciB0aGUgcmVsZWFzZSB0aGF0IHB1Ymxpc2hlZCB0aGUgaGFzaAAAABdnZXRfcmVsZWFzZV9kZXBsb3ltZW50cwAAAAAEAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+            "AAAAAAAAADJHZXQgdGhlIG51bWJlciBvZiBkZXBsb3ltZW50cyBvZiBhIGxpc3RpbmcgcmVsZWFzZQAAAAAAHGdldF9yZWxlYXNlX2RlcGxveW1lbnRfY291bnQAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAABAAAABA==",
             "AAAAAAAAABpHZXQgYWxsIGRlcGxveW1lbnQgcmVjb3JkcwAAAAAAE2dldF9hbGxfZGVwbG95bWVudHMAAAAAAAAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
-            "AAAAAAAAAB9HZXQgdG90YWwgbnVtYmVyIG9mIGRlcGxveW1lbnRzAAAAABVnZXRfdG90YWxfZGVwbG95bWVudHMAAAAAAAAAAAAAAQAAAAQ="]), options);
+            "AAAAAAAAAB9HZXQgdG90YWwgbnVtYmVyIG9mIGRlcGxveW1lbnRzAAAAABVnZXRfdG90YWxfZGVwbG95bWVudHMAAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAALVBZGQgYW4gaW5zdGFuY2UgdG8gdGhlIGtlZXAtYWxpdmUgcG9vbCwgb3IgdG9wIHVwIGl0cyBkZXBvc2l0IChyZWNvcmQgb3duZXIgb25seSkKYW1vdW50OiBEZXBvc2l0IGluIHRoZSBwb29sIHRva2VuLCBzcGVudCBvbiBleHRlbnNpb24gZmVlcwpEZWNvbW1pc3Npb25lZCBpbnN0YW5jZXMgY2FuJ3QgYmUgZnVuZGVkAAAAAAAAD2Z1bmRfa2VlcF9hbGl2ZQAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+            "AAAAAAAAAHxUYWtlIGFuIGluc3RhbmNlIG91dCBvZiB0aGUga2VlcC1hbGl2ZSBwb29sIGFuZCByZWZ1bmQgaXRzIHJlbWFpbmluZyBkZXBvc2l0CihyZWNvcmQgb3duZXIgb25seSkKUmV0dXJucyB0aGUgcmVmdW5kZWQgYW1vdW50AAAAEGxlYXZlX2tlZXBfYWxpdmUAAAABAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAAXNFeHRlbmQgdGhlIFRUTCBvZiBpbnN0YW5jZXMgaW4gdGhlIGtlZXAtYWxpdmUgcG9vbCB0aGF0IGFyZSBkdWUgKGFueW9uZSBjYW4gY2FsbCkKRWFjaCBleHRlbnNpb24gY292ZXJzIHRoZSBpbnN0YW5jZSBhbmQgaXRzIGNvZGUgYW5kIHBheXMgdGhlIGNhbGxlciB0aGUgcG9vbCBmZWUKZnJvbSB0aGF0IGluc3RhbmNlJ3MgZGVwb3NpdDsgaW5zdGFuY2VzIHRoYXQgY2FuJ3QgcGF5IHRoZSBmZWUgYXJlIHNraXBwZWQKc3RhcnQ6IEluZGV4IG9mIHRoZSBmaXJzdCBwb29sIGVudHJ5IHRvIGNoZWNrCmxpbWl0OiBNYXhpbXVtIG51bWJlciBvZiBwb29sIGVudHJpZXMgdG8gY2hlY2sKUmV0dXJucyB0aGUgbnVtYmVyIG9mIGluc3RhbmNlcyBleHRlbmRlZAAAAAAQZXh0ZW5kX2luc3RhbmNlcwAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAADJHZXQgdGhlIG51bWJlciBvZiBpbnN0YW5jZXMgaW4gdGhlIGtlZXAtYWxpdmUgcG9vbAAAAAAAGGdldF9rZWVwX2FsaXZlX3Bvb2xfc2l6ZQAAAAAAAAABAAAABA==",
+            "AAAAAAAAADpHZXQgYW4gaW5zdGFuY2UncyBrZWVwLWFsaXZlIGRlcG9zaXQsIGlmIGl0IGlzIGluIHRoZSBwb29sAAAAAAAOZ2V0X2tlZXBfYWxpdmUAAAAAAAEAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAQAAA+gAAAfQAAAACUtlZXBBbGl2ZQAAAA==",
+            "AAAAAAAAAIJTZXQgYXNpZGUgYSBidWRnZXQgdGhhdCBwYXlzIGRlcGxveSBwcmljZXMgZm9yIGFsbG93bGlzdGVkIGRlcGxveWVycwphbW91bnQ6IEluaXRpYWwgYnVkZ2V0IGluIGBwYXJhbXMudG9rZW5gLCBoZWxkIGJ5IHRoZSBtYW5hZ2VyAAAAAAASY3JlYXRlX3Nwb25zb3JzaGlwAAAAAAADAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAABFTcG9uc29yc2hpcFBhcmFtcwAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAACxBZGQgdG8gYSBzcG9uc29yc2hpcCdzIGJ1ZGdldCAoc3BvbnNvciBvbmx5KQAAABBmdW5kX3Nwb25zb3JzaGlwAAAAAgAAAAAAAAAOc3BvbnNvcnNoaXBfaWQAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAEZSZXBsYWNlIHRoZSBkZXBsb3llcnMgYW5kIGxpc3RpbmdzIGEgc3BvbnNvcnNoaXAgY292ZXJzIChzcG9uc29yIG9ubHkpAAAAAAAZc2V0X3Nwb25zb3JzaGlwX2FsbG93bGlzdAAAAAAAAAMAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAlkZXBsb3llcnMAAAAAAAPqAAAAEwAAAAAAAAAIbGlzdGluZ3MAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAKZSZWZ1bmQgYSBzcG9uc29yc2hpcCdzIHJlbWFpbmluZyBidWRnZXQgdG8gdGhlIHNwb25zb3IgKHNwb25zb3Igb25seSkKVGhlIHNwb25zb3JzaGlwIHN0YXlzLCB3aXRoIGFuIGVtcHR5IGJ1ZGdldCwgYW5kIGNhbiBiZSBmdW5kZWQgYWdhaW4KUmV0dXJucyB0aGUgcmVmdW5kZWQgYW1vdW50AAAAAAAUd2l0aGRyYXdfc3BvbnNvcnNoaXAAAAABAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+            "AAAAAAAAATtEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyB3aXRoIHRoZSBkZXBsb3kgcHJpY2UgcGFpZCBmcm9tIGEgc3BvbnNvcnNoaXAKVGhlIGRlcGxveWVyIG11c3QgYmUgb24gdGhlIHNwb25zb3JzaGlwJ3MgYWxsb3dsaXN0LCB0aGUgbGlzdGluZyBtdXN0IGJlIGNvdmVyZWQKYW5kIHByaWNlZCBpbiB0aGUgc3BvbnNvcnNoaXAncyB0b2tlbiwgYW5kIHRoZSBidWRnZXQgbXVzdCBjb3ZlciB0aGUgcHJpY2UKRXhhY3RseSB0aGUgYW1vdW50IGRlYml0ZWQgZnJvbSB0aGUgc3BvbnNvcnNoaXAgaXMgcGFpZCBvdXQgb2YgdGhlIG1hbmFnZXIAAAAAEGRlcGxveV9zcG9uc29yZWQAAAAGAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+            "AAAAAAAAAERHZXQgYSBzcGVjaWZpYyBzcG9uc29yc2hpcCwgaW5jbHVkaW5nIGl0cyByZW1haW5pbmcgYW5kIHNwZW50IGJ1ZGdldAAAAA9nZXRfc3BvbnNvcnNoaXAAAAAAAQAAAAAAAAAOc3BvbnNvcnNoaXBfaWQAAAAAAAQAAAABAAAD6QAAB9AAAAALU3BvbnNvcnNoaXAAAAAAAw==",
+            "AAAAAAAAAClHZXQgYWxsIHNwb25zb3JzaGlwcyBjcmVhdGVkIGJ5IGEgc3BvbnNvcgAAAAAAABBnZXRfc3BvbnNvcnNoaXBzAAAAAQAAAAAAAAAHc3BvbnNvcgAAAAATAAAAAQAAA+oAAAfQAAAAC1Nwb25zb3JzaGlwAA==",
+            "AAAAAAAAADhHZXQgdGhlIGRlcGxveW1lbnRzIGEgc3BvbnNvcnNoaXAgcGFpZCBmb3IsIG9sZGVzdCBmaXJzdAAAABVnZXRfc3BvbnNvcnNoaXBfdXNhZ2UAAAAAAAABAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAEAAAPqAAAH0AAAABNTcG9uc29yZWREZXBsb3ltZW50AA==",
+            "AAAAAAAAAL5PZmZlciBsaWNlbnNlcyBmb3IgYSBsaXN0aW5nIChsaXN0aW5nIGF1dGhvciBvbmx5KQpPbmNlIGEgbGlzdGluZyBoYXMgYW4gb2ZmZXIsIGRlcGxveWluZyBpdCByZXF1aXJlcyBhIHZhbGlkIGxpY2Vuc2UKZHVyYXRpb246IFNlY29uZHMgYSBsaWNlbnNlIHN0YXlzIHZhbGlkIGFmdGVyIHB1cmNoYXNlICgwIGZvciBwZXJwZXR1YWwpAAAAAAAUY3JlYXRlX2xpY2Vuc2Vfb2ZmZXIAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAAAAAAVzY29wZQAAAAAAB9AAAAAMTGljZW5zZVNjb3BlAAAAAQAAA+kAAAAEAAAAAw==",
+            "AAAAAAAAAHFPcGVuIG9yIGNsb3NlIGFuIG9mZmVyIGZvciBuZXcgcHVyY2hhc2VzIChsaXN0aW5nIGF1dGhvciBvbmx5KQpDbG9zaW5nIGV2ZXJ5IG9mZmVyIGRvZXMgbm90IHVubGljZW5zZSB0aGUgbGlzdGluZwAAAAAAABhzZXRfbGljZW5zZV9vZmZlcl9hY3RpdmUAAAACAAAAAAAAAAhvZmZlcl9pZAAAAAQAAAAAAAAABmFjdGl2ZQAAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+            "AAAAAAAAAD5CdXkgYSBsaWNlbnNlLCBwYXlpbmcgdGhlIG9mZmVyIHByaWNlIHRvIHRoZSBsaXN0aW5nJ3MgYXV0aG9ycwAAAAAAEHB1cmNoYXNlX2xpY2Vuc2UAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAPpAAAABAAAAAM=",
+            "AAAAAAAAABxHZXQgYSBzcGVjaWZpYyBsaWNlbnNlIG9mZmVyAAAAEWdldF9saWNlbnNlX29mZmVyAAAAAAAAAQAAAAAAAAAIb2ZmZXJfaWQAAAAEAAAAAQAAA+kAAAfQAAAADExpY2Vuc2VPZmZlcgAAAAM=",
+            "AAAAAAAAACRHZXQgYWxsIGxpY2Vuc2Ugb2ZmZXJzIGZvciBhIGxpc3RpbmcAAAASZ2V0X2xpY2Vuc2Vfb2ZmZXJzAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAADExpY2Vuc2VPZmZlcg==",
+            "AAAAAAAAAERHZXQgYWxsIGxpY2Vuc2VzIGJvdWdodCBieSBhIGhvbGRlciwgaW5jbHVkaW5nIGV4cGlyZWQgYW5kIHVzZWQgb25lcwAAAAxnZXRfbGljZW5zZXMAAAABAAAAAAAAAAZob2xkZXIAAAAAABMAAAABAAAD6gAAB9AAAAAHTGljZW5zZQA=",
+            "AAAAAAAAADVDaGVjayB3aGV0aGVyIGEgaG9sZGVyIGNhbiBjdXJyZW50bHkgZGVwbG95IGEgbGlzdGluZwAAAAAAABFoYXNfdmFsaWRfbGljZW5zZQAAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABmhvbGRlcgAAAAAAEwAAAAEAAAAB"]), options);
         this.options = options;
     }
     fromJSON = {
+        set_platform_fee: (this.txFromJSON),
+        get_platform_fee: (this.txFromJSON),
+        set_keep_alive_config: (this.txFromJSON),
+        get_keep_alive_config: (this.txFromJSON),
+        set_deploy_policy: (this.txFromJSON),
+        get_deploy_policy: (this.txFromJSON),
+        set_deployer_policy: (this.txFromJSON),
+        get_deployer_policy: (this.txFromJSON),
+        get_registry: (this.txFromJSON),
         deploy_with_admin: (this.txFromJSON),
         deploy_from_wasm: (this.txFromJSON),
+        deploy_from_bytes: (this.txFromJSON),
+        deploy_from_registry: (this.txFromJSON),
+        deploy_and_call: (this.txFromJSON),
+        deploy_next: (this.txFromJSON),
+        deploy_batch: (this.txFromJSON),
+        get_group_deployments: (this.txFromJSON),
+        create_template: (this.txFromJSON),
+        update_template: (this.txFromJSON),
+        delete_template: (this.txFromJSON),
+        deploy_from_template: (this.txFromJSON),
+        get_template: (this.txFromJSON),
+        get_templates: (this.txFromJSON),
+        get_recommended_templates: (this.txFromJSON),
+        propose_intent: (this.txFromJSON),
+        approve_intent: (this.txFromJSON),
+        execute_intent: (this.txFromJSON),
+        get_intent: (this.txFromJSON),
+        get_deployer_nonce: (this.txFromJSON),
+        derive_salt: (this.txFromJSON),
+        derive_intent_salt: (this.txFromJSON),
+        redeploy: (this.txFromJSON),
+        set_managed_upgrades: (this.txFromJSON),
+        upgrade_deployment: (this.txFromJSON),
+        rollback_deployment: (this.txFromJSON),
+        sync_deployment: (this.txFromJSON),
+        get_observed_hashes: (this.txFromJSON),
+        predict_address: (this.txFromJSON),
+        is_address_taken: (this.txFromJSON),
+        check_init_args: (this.txFromJSON),
         get_deployment: (this.txFromJSON),
+        get_deployment_by_address: (this.txFromJSON),
         get_deployment_history: (this.txFromJSON),
+        set_deployment_info: (this.txFromJSON),
+        get_project_deployments: (this.txFromJSON),
+        get_environment_deployments: (this.txFromJSON),
+        set_deployment_status: (this.txFromJSON),
+        set_deployment_hidden: (this.txFromJSON),
+        transfer_deployment: (this.txFromJSON),
+        get_owned_deployments: (this.txFromJSON),
         get_contract_deployments: (this.txFromJSON),
+        get_hash_deployments: (this.txFromJSON),
+        get_hash_deployment_count: (this.txFromJSON),
+        get_release_deployments: (this.txFromJSON),
+        get_release_deployment_count: (this.txFromJSON),
         get_all_deployments: (this.txFromJSON),
-        get_total_deployments: (this.txFromJSON)
+        get_total_deployments: (this.txFromJSON),
+        fund_keep_alive: (this.txFromJSON),
+        leave_keep_alive: (this.txFromJSON),
+        extend_instances: (this.txFromJSON),
+        get_keep_alive_pool_size: (this.txFromJSON),
+        get_keep_alive: (this.txFromJSON),
+        create_sponsorship: (this.txFromJSON),
+        fund_sponsorship: (this.txFromJSON),
+        set_sponsorship_allowlist: (this.txFromJSON),
+        withdraw_sponsorship: (this.txFromJSON),
+        deploy_sponsored: (this.txFromJSON),
+        get_sponsorship: (this.txFromJSON),
+        get_sponsorships: (this.txFromJSON),
+        get_sponsorship_usage: (this.txFromJSON),
+        create_license_offer: (this.txFromJSON),
+        set_license_offer_active: (this.txFromJSON),
+        purchase_license: (this.txFromJSON),
+        get_license_offer: (this.txFromJSON),
+        get_license_offers: (this.txFromJSON),
+        get_licenses: (this.txFromJSON),
+        has_valid_license: (this.txFromJSON)
     };
 }
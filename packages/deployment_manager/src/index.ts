@@ -37,44 +37,408 @@ export const networks = {
   },
 } as const;
 
+/**
+ * The ContractRegistry errors the DeploymentManager tells apart
+ */
+export const RegistryErrors = {
+  2: { message: "ContractNotFound" },
+  13: { message: "ReleaseNotFound" },
+  15: { message: "ReleaseYanked" },
+  16: { message: "ContractQuarantined" },
+};
+
 export interface DeploymentRecord {
   contract_id: u32;
   deployed_at: u64;
   deployed_contract_address: string;
   deployer: string;
   deployment_id: u32;
+  group_id: Option<u32>;
+  hidden: boolean;
+  info: DeploymentInfo;
+  init_args: Array<any>;
+  intent_id: Option<u32>;
+  managed: boolean;
+  nonce: Option<u64>;
+  owner: string;
+  release: Option<string>;
+  salt: Buffer;
+  status: DeploymentStatus;
+  upgrades: Array<UpgradeEntry>;
+  wasm_hash: Buffer;
+}
+
+/**
+ * Lifecycle stage of a deployment record
+ * Decommissioned is final
+ */
+export enum DeploymentStatus {
+  Active = 0,
+  Archived = 1,
+  Decommissioned = 2,
+}
+
+/**
+ * Environment a deployment serves
+ */
+export enum DeploymentEnvironment {
+  Unspecified = 0,
+  Dev = 1,
+  Staging = 2,
+  Production = 3,
+}
+
+/**
+ * Deployer-maintained description of a deployment
+ */
+export interface DeploymentInfo {
+  environment: DeploymentEnvironment;
+  labels: Array<string>;
+  note: string;
+  project: string;
+}
+
+/**
+ * One code change of a managed instance
+ * wasm_hash and release are the current code; a rollback restores the entry it undoes
+ */
+export interface UpgradeEntry {
+  from_hash: Buffer;
+  from_release: Option<string>;
+  rollback: boolean;
+  to_hash: Buffer;
+  to_release: Option<string>;
+  upgraded_at: u64;
+}
+
+/**
+ * One listing release deployed as part of a batch
+ * Salts are derived by the manager as in deploy_next
+ */
+export interface BatchStep {
+  contract_id: u32;
+  init_args: Array<any>;
+  links: Array<StepLink>;
+  release: string;
+}
+
+/**
+ * Replace constructor argument `arg_index` with the address deployed by an earlier `step`
+ */
+export interface StepLink {
+  arg_index: u32;
+  step: u32;
+}
+
+/**
+ * Requirements a WASM hash must meet before it is deployed
+ */
+export interface DeployPolicy {
+  max_severity: Severity;
+  min_verification: VerificationLevel;
+  require_published: boolean;
+}
+
+/**
+ * How strongly a build was verified (mirrors the ContractRegistry type)
+ */
+export enum VerificationLevel {
+  Unverified = 0,
+  Community = 1,
+  Audited = 2,
+}
+
+/**
+ * Advisory severity (mirrors the ContractRegistry type)
+ */
+export enum Severity {
+  None = 0,
+  Low = 1,
+  Medium = 2,
+  High = 3,
+  Critical = 4,
+}
+
+/**
+ * What the registry knows about a WASM hash (mirrors the ContractRegistry type)
+ */
+export interface HashReport {
+  highest_severity: Severity;
+  listings: Array<u32>;
+  verification: VerificationLevel;
+}
+
+/**
+ * A published version of a listing's code (mirrors the ContractRegistry type)
+ */
+export interface Release {
+  published_at: u64;
+  version: string;
+  wasm_hash: Buffer;
+  yanked: boolean;
+}
+
+/**
+ * Fully specified deployment proposed for approval
+ * approvers: Addresses allowed to approve, threshold of which must approve
+ * deadline: Timestamp after which the intent can no longer be approved or executed
+ */
+export interface IntentParams {
+  approvers: Array<string>;
+  contract_id: u32;
+  deadline: u64;
+  init_args: Array<any>;
+  release: string;
+  salt: Buffer;
+  threshold: u32;
+}
+
+/**
+ * Deployment waiting for, or executed after, multi-party approval
+ */
+export interface DeploymentIntent {
+  approvals: Array<string>;
+  approvers: Array<string>;
+  contract_id: u32;
+  deadline: u64;
+  deployment_id: Option<u32>;
+  init_args: Array<any>;
+  intent_id: u32;
+  proposer: string;
+  release: string;
   salt: Buffer;
+  threshold: u32;
+}
+
+/**
+ * Who a sponsorship pays for
+ * listings: Listings it covers, or empty for any listing
+ */
+export interface SponsorshipParams {
+  deployers: Array<string>;
+  listings: Array<u32>;
+  token: string;
+}
+
+/**
+ * Budget a sponsor set aside for other deployers' deploy prices
+ */
+export interface Sponsorship {
+  balance: i128;
+  deployers: Array<string>;
+  deployment_count: u32;
+  listings: Array<u32>;
+  spent: i128;
+  sponsor: string;
+  sponsorship_id: u32;
+  token: string;
+}
+
+/**
+ * One deployment paid from a sponsorship
+ */
+export interface SponsoredDeployment {
+  amount: i128;
+  contract_id: u32;
+  deployed_at: u64;
+  deployer: string;
+  deployment_id: u32;
+}
+
+/**
+ * Terms of the keep-alive pool (set by the admin)
+ * Instances are extended to `extend_to` ledgers once fewer than `threshold` remain,
+ * and each extension pays `fee` of `token` from the instance's deposit to the caller
+ */
+export interface KeepAliveConfig {
+  extend_to: u32;
+  fee: i128;
+  threshold: u32;
+  token: string;
+}
+
+/**
+ * Keep-alive deposit of a deployment
+ */
+export interface KeepAlive {
+  balance: i128;
+  extended_at: u32;
+}
+
+/**
+ * Code an instance ran, recorded at deployment, on managed upgrades and by syncs
+ */
+export interface ObservedHash {
+  observed_at: u64;
+  release: Option<string>;
   wasm_hash: Buffer;
 }
 
+/**
+ * Invocation on a freshly deployed instance, e.g. a legacy `initialize`
+ * expected: Empty to accept any return value, or the single value it must return
+ */
+export interface PostDeployCall {
+  args: Array<any>;
+  expected: Array<any>;
+  function: string;
+}
+
+/**
+ * How deployments from a template get their salt
+ */
+export enum SaltStrategy {
+  Derived = 0,
+  Provided = 1,
+}
+
+/**
+ * Template parameters set by its owner
+ */
+export interface TemplateParams {
+  contract_id: u32;
+  init_args: Array<any>;
+  name: string;
+  release: string;
+  salt_strategy: SaltStrategy;
+}
+
+/**
+ * Saved deployment configuration for a listing release
+ */
+export interface DeploymentTemplate {
+  contract_id: u32;
+  init_args: Array<any>;
+  name: string;
+  owner: string;
+  recommended: boolean;
+  release: string;
+  salt_strategy: SaltStrategy;
+  template_id: u32;
+}
+
+/**
+ * Share of every deploy price kept by the platform
+ */
+export interface PlatformFee {
+  fee_bps: u32;
+  recipient: string;
+}
+
+/**
+ * Whether a license covers every deployment by its holder or a single one
+ */
+export enum LicenseScope {
+  PerDeployer = 0,
+  PerInstance = 1,
+}
+
+/**
+ * License terms an author sells for a listing
+ */
+export interface LicenseOffer {
+  active: boolean;
+  contract_id: u32;
+  duration: u64;
+  offer_id: u32;
+  price: i128;
+  scope: LicenseScope;
+  token: string;
+}
+
+/**
+ * A license bought from an offer
+ */
+export interface License {
+  contract_id: u32;
+  deployment_id: Option<u32>;
+  expires_at: Option<u64>;
+  holder: string;
+  license_id: u32;
+  offer_id: u32;
+  purchased_at: u64;
+  scope: LicenseScope;
+}
+
+export interface DeployPrice {
+  amount: i128;
+  token: string;
+}
+
+export interface RevenueShare {
+  recipient: string;
+  share_bps: u32;
+}
+
+export interface PayoutTerms {
+  author: string;
+  shares: Array<RevenueShare>;
+}
+
+export enum ParamType {
+  Any = 0,
+  Bool = 1,
+  U32 = 2,
+  I32 = 3,
+  U64 = 4,
+  I64 = 5,
+  U128 = 6,
+  I128 = 7,
+  U256 = 8,
+  I256 = 9,
+  Address = 10,
+  String = 11,
+  Symbol = 12,
+  Bytes = 13,
+  Vec = 14,
+  Map = 15,
+}
+
+export interface ConstructorParam {
+  default_value: Array<any>;
+  description: string;
+  name: string;
+  param_type: ParamType;
+}
+
 export const Errors = {
   1: { message: "ContractNotFound" },
   2: { message: "DeploymentFailed" },
   3: { message: "InvalidParameters" },
   4: { message: "InvalidWasmHash" },
   5: { message: "UnauthorizedAccess" },
+  6: { message: "LicenseRequired" },
+  7: { message: "OfferNotFound" },
+  8: { message: "OfferInactive" },
+  9: { message: "ReleaseNotFound" },
+  10: { message: "ReleaseYanked" },
+  11: { message: "ListingQuarantined" },
+  12: { message: "AddressTaken" },
+  13: { message: "TemplateNotFound" },
+  14: { message: "PostDeployCallFailed" },
+  15: { message: "PostDeployCheckFailed" },
+  16: { message: "UpgradeNotManaged" },
+  17: { message: "UpgradeFailed" },
+  18: { message: "KeepAliveNotConfigured" },
+  19: { message: "HashNotPublished" },
+  20: { message: "HashNotVerified" },
+  21: { message: "HashHasAdvisories" },
+  22: { message: "IntentNotFound" },
+  23: { message: "IntentExpired" },
+  24: { message: "IntentNotApproved" },
+  25: { message: "SponsorshipNotFound" },
+  26: { message: "SponsorshipExhausted" },
+  27: { message: "ReleaseNotNewer" },
+  28: { message: "PaymentMismatch" },
 };
 
 export interface Client {
   /**
-   * Construct and simulate a deploy_with_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Deploy a contract with an admin address constructor parameter
-   * This is a convenience method for the common pattern of contracts with admin initialization
+   * Construct and simulate a set_platform_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the platform cut taken from every deploy price (admin only)
    */
-  deploy_with_admin: (
-    {
-      contract_id,
-      deployer,
-      wasm_hash,
-      salt,
-      admin,
-    }: {
-      contract_id: u32;
-      deployer: string;
-      wasm_hash: Buffer;
-      salt: Buffer;
-      admin: string;
-    },
+  set_platform_fee: (
+    { recipient, fee_bps }: { recipient: string; fee_bps: u32 },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -91,28 +455,36 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<u32>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a deploy_from_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Deploy a contract from the registry
-   * This deploys a new contract instance on-chain from the WASM hash
-   * init_args: Constructor arguments for the contract (empty vec if no constructor)
+   * Construct and simulate a get_platform_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the platform fee, if one is configured
    */
-  deploy_from_wasm: (
-    {
-      contract_id,
-      deployer,
-      wasm_hash,
-      salt,
-      init_args,
-    }: {
-      contract_id: u32;
-      deployer: string;
-      wasm_hash: Buffer;
-      salt: Buffer;
-      init_args: Array<any>;
-    },
+  get_platform_fee: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PlatformFee>>>;
+
+  /**
+   * Construct and simulate a set_keep_alive_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configure the keep-alive pool (admin only)
+   * The deposit token can't change once set, since deposits are held in it
+   */
+  set_keep_alive_config: (
+    { config }: { config: KeepAliveConfig },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -129,14 +501,35 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<u32>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
 
   /**
-   * Construct and simulate a get_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get a specific deployment record
+   * Construct and simulate a get_keep_alive_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the keep-alive pool terms, if configured
    */
-  get_deployment: (
-    { deployment_id }: { deployment_id: u32 },
+  get_keep_alive_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<KeepAliveConfig>>>;
+
+  /**
+   * Construct and simulate a set_deploy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set or clear the policy every deployment must meet (admin only)
+   */
+  set_deploy_policy: (
+    { policy }: { policy: Option<DeployPolicy> },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -153,14 +546,35 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+  ) => Promise<AssembledTransaction<void>>;
 
   /**
-   * Construct and simulate a get_deployment_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get deployment history for a specific deployer
+   * Construct and simulate a get_deploy_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the policy every deployment must meet, if set
    */
-  get_deployment_history: (
-    { deployer }: { deployer: string },
+  get_deploy_policy: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<DeployPolicy>>>;
+
+  /**
+   * Construct and simulate a set_deployer_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set or clear a deployer's own policy, applied on top of the global one
+   */
+  set_deployer_policy: (
+    { deployer, policy }: { deployer: string; policy: Option<DeployPolicy> },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -177,14 +591,14 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+  ) => Promise<AssembledTransaction<void>>;
 
   /**
-   * Construct and simulate a get_contract_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all deployments of a specific contract
+   * Construct and simulate a get_deployer_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a deployer's own policy, if set
    */
-  get_contract_deployments: (
-    { contract_id }: { contract_id: u32 },
+  get_deployer_policy: (
+    { deployer }: { deployer: string },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -201,13 +615,13 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+  ) => Promise<AssembledTransaction<Option<DeployPolicy>>>;
 
   /**
-   * Construct and simulate a get_all_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all deployment records
+   * Construct and simulate a get_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ContractRegistry address
    */
-  get_all_deployments: (options?: {
+  get_registry: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -222,31 +636,1831 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+  }) => Promise<AssembledTransaction<string>>;
 
   /**
-   * Construct and simulate a get_total_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get total number of deployments
+   * Construct and simulate a deploy_with_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a contract with an admin address constructor parameter
+   * This is a convenience method for the common pattern of contracts with admin initialization
    */
-  get_total_deployments: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  deploy_with_admin: (
+    {
+      contract_id,
+      deployer,
+      wasm_hash,
+      salt,
+      admin,
+    }: {
+      contract_id: u32;
+      deployer: string;
+      wasm_hash: Buffer;
+      salt: Buffer;
+      admin: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_from_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a contract from the registry
+   * This deploys a new contract instance on-chain from the WASM hash
+   * init_args: Constructor arguments for the contract (empty vec if no constructor)
+   * If the listing has a constructor schema, init_args are checked against it and
+   * omitted trailing arguments take their defaults; mismatches fail with
+   * InvalidParameters
+   * If the author set a deploy price it is charged to the deployer in the same transaction
+   * Only a hash the listing released is licensed, charged and counted against it;
+   * other hashes are recorded as UNLISTED, and listing ids unknown to the registry
+   * are kept on the record without being counted
+   */
+  deploy_from_wasm: (
+    {
+      contract_id,
+      deployer,
+      wasm_hash,
+      salt,
+      init_args,
+    }: {
+      contract_id: u32;
+      deployer: string;
+      wasm_hash: Buffer;
+      salt: Buffer;
+      init_args: Array<any>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_from_bytes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upload WASM and deploy it without publishing it to the registry
+   * The record's contract_id is UNLISTED and keeps the uploaded hash; unlisted
+   * deployments are listed by get_contract_deployments(UNLISTED)
+   */
+  deploy_from_bytes: (
+    {
+      deployer,
+      wasm,
+      salt,
+      init_args,
+    }: { deployer: string; wasm: Buffer; salt: Buffer; init_args: Array<any> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_from_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a release of a registry listing
+   * The WASM hash is resolved through the ContractRegistry, so the record is
+   * guaranteed to match the listing's published code
+   * Unknown, yanked and quarantined releases are rejected
+   */
+  deploy_from_registry: (
+    {
+      contract_id,
+      release,
+      deployer,
+      salt,
+      init_args,
+    }: {
+      contract_id: u32;
+      release: string;
+      deployer: string;
+      salt: Buffer;
+      init_args: Array<any>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_and_call transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a release of a registry listing and invoke functions on the new instance
+   * Calls run in order in the same transaction, e.g. a legacy `initialize` followed
+   * by read-only health checks
+   * If a call fails or returns something other than its expected value the
+   * deployment is aborted
+   */
+  deploy_and_call: (
+    {
+      contract_id,
+      release,
+      deployer,
+      salt,
+      init_args,
+      calls,
+    }: {
+      contract_id: u32;
+      release: string;
+      deployer: string;
+      salt: Buffer;
+      init_args: Array<any>;
+      calls: Array<PostDeployCall>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_next transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a release of a registry listing with a salt derived by the manager
+   * The salt is the SHA-256 of (deployer, contract_id, nonce), where nonce is the
+   * deployer's next unused nonce, so repeated deployments never collide
+   * The nonce is stored on the record so the salt can be reproduced with derive_salt
+   */
+  deploy_next: (
+    {
+      contract_id,
+      release,
+      deployer,
+      init_args,
+    }: {
+      contract_id: u32;
+      release: string;
+      deployer: string;
+      init_args: Array<any>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a deploy_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy several listing releases in order as one group
+   * Each step may replace constructor arguments with addresses deployed by
+   * earlier steps, e.g. a vault taking the address of the token deployed before it
+   * If any step fails the whole batch is rolled back
+   * Returns the group id linking the resulting deployment records
+   */
+  deploy_batch: (
+    { deployer, steps }: { deployer: string; steps: Array<BatchStep> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_group_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the deployments of a batch, in step order
+   */
+  get_group_deployments: (
+    { group_id }: { group_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a create_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Save a deployment template for a listing release
+   * Templates saved by the listing's author are marked as recommended
+   */
+  create_template: (
+    { owner, params }: { owner: string; params: TemplateParams },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a update_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace a template's parameters (template owner only)
+   */
+  update_template: (
+    { template_id, params }: { template_id: u32; params: TemplateParams },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a delete_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delete a template (template owner only)
+   */
+  delete_template: (
+    { template_id }: { template_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a deploy_from_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy from a saved template
+   * salt: Required for templates with a provided salt strategy, omitted for derived salts
+   */
+  deploy_from_template: (
+    {
+      template_id,
+      deployer,
+      salt,
+    }: { template_id: u32; deployer: string; salt: Option<Buffer> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_template transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific template
+   */
+  get_template: (
+    { template_id }: { template_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<DeploymentTemplate>>>;
+
+  /**
+   * Construct and simulate a get_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all templates saved by an owner
+   */
+  get_templates: (
+    { owner }: { owner: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentTemplate>>>;
+
+  /**
+   * Construct and simulate a get_recommended_templates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the templates a listing's author recommends
+   */
+  get_recommended_templates: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentTemplate>>>;
+
+  /**
+   * Construct and simulate a propose_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a deployment that needs sign-off before it runs
+   * Executed intents deploy from the manager's own address, so the instance
+   * address is predict_address(manager address, derive_intent_salt(proposer, salt));
+   * the proposer is recorded as the deployer and owner and can't approve
+   */
+  propose_intent: (
+    { proposer, params }: { proposer: string; params: IntentParams },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a approve_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve a pending intent (designated approvers only)
+   */
+  approve_intent: (
+    { approver, intent_id }: { approver: string; intent_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a execute_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Execute an intent once enough approvers signed off (anyone can call)
+   * The executor pays the listing's deploy price, if any
+   */
+  execute_intent: (
+    { executor, intent_id }: { executor: string; intent_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_intent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific deployment intent
+   */
+  get_intent: (
+    { intent_id }: { intent_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<DeploymentIntent>>>;
+
+  /**
+   * Construct and simulate a get_deployer_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the nonce the deployer's next deploy_next call will start from
+   */
+  get_deployer_nonce: (
+    { deployer }: { deployer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u64>>;
+
+  /**
+   * Construct and simulate a derive_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compute the salt deploy_next uses for a deployer, listing and nonce
+   */
+  derive_salt: (
+    {
+      deployer,
+      contract_id,
+      nonce,
+    }: { deployer: string; contract_id: u32; nonce: u64 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a derive_intent_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compute the salt an intent by `proposer` with `salt` deploys with
+   */
+  derive_intent_salt: (
+    { proposer, salt }: { proposer: string; salt: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a redeploy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy an identical instance of an earlier deployment (original deployer only)
+   * The recorded WASM hash and constructor arguments are reused with a new salt
+   * Deployments of a registry release are rejected once it is yanked or quarantined
+   */
+  redeploy: (
+    { deployment_id, new_salt }: { deployment_id: u32; new_salt: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a set_managed_upgrades transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opt an instance in or out of managed upgrades (record owner only)
+   * The instance must expose `upgrade(new_wasm_hash)` and accept this manager as
+   * its upgrade authority, e.g. by being deployed with the manager as its admin
+   */
+  set_managed_upgrades: (
+    { deployment_id, managed }: { deployment_id: u32; managed: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a upgrade_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upgrade a managed instance to a newer release of its listing (record owner only)
+   * The release is resolved through the registry, so yanked and quarantined
+   * releases are rejected, and must meet the global and the owner's deploy policy
+   * Releases published no later than the current one fail with ReleaseNotNewer;
+   * use rollback_deployment to undo an upgrade
+   */
+  upgrade_deployment: (
+    { deployment_id, release }: { deployment_id: u32; release: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a rollback_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Undo the most recent upgrade of a managed instance (record owner only)
+   * Only upgrades can be rolled back, not earlier rollbacks, and the restored
+   * code must meet the global and the owner's deploy policy
+   * Fails if the instance no longer runs the code that upgrade installed,
+   * e.g. after an upgrade made outside the manager
+   */
+  rollback_deployment: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a sync_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update a record to the code its instance currently runs
+   * Catches upgrades made outside the manager; anyone can call it
+   * Changes are kept in the observed hash history, with the release of the
+   * record's listing that published the new hash, if any
+   * Returns the current WASM hash
+   */
+  sync_deployment: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Buffer>>>;
+
+  /**
+   * Construct and simulate a get_observed_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the code a deployment has run, oldest first: the deployed hash,
+   * then every managed upgrade or rollback and change seen by sync_deployment
+   */
+  get_observed_hashes: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<ObservedHash>>>;
+
+  /**
+   * Construct and simulate a predict_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address a deployment by `deployer` with `salt` will have
+   * Uses the same derivation as the deploy functions
+   */
+  predict_address: (
+    { deployer, salt }: { deployer: string; salt: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a is_address_taken transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a contract already exists at the address for `deployer` and `salt`
+   * Deploying with a taken salt fails with AddressTaken
+   */
+  is_address_taken: (
+    { deployer, salt }: { deployer: string; salt: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a check_init_args transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check constructor arguments against a listing's schema without deploying
+   * Returns the index of the first invalid argument, or None if they would be accepted
+   * Every deploy entry point applies the same check but only reports InvalidParameters
+   */
+  check_init_args: (
+    { contract_id, init_args }: { contract_id: u32; init_args: Array<any> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<u32>>>;
+
+  /**
+   * Construct and simulate a get_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific deployment record
+   */
+  get_deployment: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_deployment_by_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the record of the deployment that created a contract address
+   * Fails with ContractNotFound for addresses not deployed through this manager
+   */
+  get_deployment_by_address: (
+    { address }: { address: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_deployment_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get deployment history for a specific deployer
+   * Hidden records are left out of this and the other public listings
+   */
+  get_deployment_history: (
+    { deployer }: { deployer: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a set_deployment_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the labels, environment, note and project of a deployment (record owner only)
+   */
+  set_deployment_info: (
+    { deployment_id, info }: { deployment_id: u32; info: DeploymentInfo },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_project_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an owner's deployments that belong to a project, including hidden ones
+   */
+  get_project_deployments: (
+    { owner, project }: { owner: string; project: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_environment_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an owner's deployments tagged with an environment, including hidden ones
+   */
+  get_environment_deployments: (
+    {
+      owner,
+      environment,
+    }: { owner: string; environment: DeploymentEnvironment },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a set_deployment_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Move a deployment to another lifecycle stage (record owner only)
+   * Decommissioned records can't change stage again
+   */
+  set_deployment_status: (
+    { deployment_id, status }: { deployment_id: u32; status: DeploymentStatus },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_deployment_hidden transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hide a deployment from public listings, or show it again (record owner only)
+   */
+  set_deployment_hidden: (
+    { deployment_id, hidden }: { deployment_id: u32; hidden: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a transfer_deployment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand a deployment record to a new owner, e.g. a team multisig (record owner only)
+   * The original deployer stays on the record and in its deployment history
+   */
+  transfer_deployment: (
+    { deployment_id, new_owner }: { deployment_id: u32; new_owner: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a get_owned_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the deployments an address owns, including hidden ones
+   */
+  get_owned_deployments: (
+    { owner }: { owner: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_contract_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all deployments of a specific contract
+   */
+  get_contract_deployments: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_hash_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the deployments running a WASM hash
+   * Deployments are listed in the order they were indexed, except that when
+   * one is upgraded away the last one takes its slot
+   * Hidden records are included, so every affected instance can be found
+   * start: Index of the first deployment to return
+   * limit: Maximum number of records to return
+   */
+  get_hash_deployments: (
+    { wasm_hash, start, limit }: { wasm_hash: Buffer; start: u32; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_hash_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of deployments running a WASM hash
+   */
+  get_hash_deployment_count: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_release_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the deployments of a listing release, ordered like get_hash_deployments
+   * Deployments by WASM hash are indexed under the release that published the hash
+   */
+  get_release_deployments: (
+    {
+      contract_id,
+      release,
+      start,
+      limit,
+    }: { contract_id: u32; release: string; start: u32; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_release_deployment_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of deployments of a listing release
+   */
+  get_release_deployment_count: (
+    { contract_id, release }: { contract_id: u32; release: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_all_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all deployment records
+   */
+  get_all_deployments: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<DeploymentRecord>>>;
+
+  /**
+   * Construct and simulate a get_total_deployments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total number of deployments
+   */
+  get_total_deployments: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a fund_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add an instance to the keep-alive pool, or top up its deposit (record owner only)
+   * amount: Deposit in the pool token, spent on extension fees
+   * Decommissioned instances can't be funded
+   */
+  fund_keep_alive: (
+    { deployment_id, amount }: { deployment_id: u32; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a leave_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take an instance out of the keep-alive pool and refund its remaining deposit
+   * (record owner only)
+   * Returns the refunded amount
+   */
+  leave_keep_alive: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a extend_instances transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of instances in the keep-alive pool that are due (anyone can call)
+   * Each extension covers the instance and its code and pays the caller the pool fee
+   * from that instance's deposit; instances that can't pay the fee are skipped
+   * start: Index of the first pool entry to check
+   * limit: Maximum number of pool entries to check
+   * Returns the number of instances extended
+   */
+  extend_instances: (
+    { caller, start, limit }: { caller: string; start: u32; limit: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_keep_alive_pool_size transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of instances in the keep-alive pool
+   */
+  get_keep_alive_pool_size: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_keep_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an instance's keep-alive deposit, if it is in the pool
+   */
+  get_keep_alive: (
+    { deployment_id }: { deployment_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Option<KeepAlive>>>;
+
+  /**
+   * Construct and simulate a create_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set aside a budget that pays deploy prices for allowlisted deployers
+   * amount: Initial budget in `params.token`, held by the manager
+   */
+  create_sponsorship: (
+    {
+      sponsor,
+      params,
+      amount,
+    }: { sponsor: string; params: SponsorshipParams; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a fund_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add to a sponsorship's budget (sponsor only)
+   */
+  fund_sponsorship: (
+    { sponsorship_id, amount }: { sponsorship_id: u32; amount: i128 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a set_sponsorship_allowlist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the deployers and listings a sponsorship covers (sponsor only)
+   */
+  set_sponsorship_allowlist: (
+    {
+      sponsorship_id,
+      deployers,
+      listings,
+    }: { sponsorship_id: u32; deployers: Array<string>; listings: Array<u32> },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a withdraw_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refund a sponsorship's remaining budget to the sponsor (sponsor only)
+   * The sponsorship stays, with an empty budget, and can be funded again
+   * Returns the refunded amount
+   */
+  withdraw_sponsorship: (
+    { sponsorship_id }: { sponsorship_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+
+  /**
+   * Construct and simulate a deploy_sponsored transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy a release of a registry listing with the deploy price paid from a sponsorship
+   * The deployer must be on the sponsorship's allowlist, the listing must be covered
+   * and priced in the sponsorship's token, and the budget must cover the price
+   * Exactly the amount debited from the sponsorship is paid out of the manager
+   */
+  deploy_sponsored: (
+    {
+      sponsorship_id,
+      contract_id,
+      release,
+      deployer,
+      salt,
+      init_args,
+    }: {
+      sponsorship_id: u32;
+      contract_id: u32;
+      release: string;
+      deployer: string;
+      salt: Buffer;
+      init_args: Array<any>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific sponsorship, including its remaining and spent budget
+   */
+  get_sponsorship: (
+    { sponsorship_id }: { sponsorship_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Sponsorship>>>;
+
+  /**
+   * Construct and simulate a get_sponsorships transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all sponsorships created by a sponsor
+   */
+  get_sponsorships: (
+    { sponsor }: { sponsor: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<Sponsorship>>>;
+
+  /**
+   * Construct and simulate a get_sponsorship_usage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the deployments a sponsorship paid for, oldest first
+   */
+  get_sponsorship_usage: (
+    { sponsorship_id }: { sponsorship_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<SponsoredDeployment>>>;
+
+  /**
+   * Construct and simulate a create_license_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer licenses for a listing (listing author only)
+   * Once a listing has an offer, deploying it requires a valid license
+   * duration: Seconds a license stays valid after purchase (0 for perpetual)
+   */
+  create_license_offer: (
+    {
+      contract_id,
+      token,
+      price,
+      duration,
+      scope,
+    }: {
+      contract_id: u32;
+      token: string;
+      price: i128;
+      duration: u64;
+      scope: LicenseScope;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a set_license_offer_active transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open or close an offer for new purchases (listing author only)
+   * Closing every offer does not unlicense the listing
+   */
+  set_license_offer_active: (
+    { offer_id, active }: { offer_id: u32; active: boolean },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+
+  /**
+   * Construct and simulate a purchase_license transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buy a license, paying the offer price to the listing's authors
+   */
+  purchase_license: (
+    { buyer, offer_id }: { buyer: string; offer_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+
+  /**
+   * Construct and simulate a get_license_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a specific license offer
+   */
+  get_license_offer: (
+    { offer_id }: { offer_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<LicenseOffer>>>;
+
+  /**
+   * Construct and simulate a get_license_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all license offers for a listing
+   */
+  get_license_offers: (
+    { contract_id }: { contract_id: u32 },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<LicenseOffer>>>;
+
+  /**
+   * Construct and simulate a get_licenses transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all licenses bought by a holder, including expired and used ones
+   */
+  get_licenses: (
+    { holder }: { holder: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<License>>>;
+
+  /**
+   * Construct and simulate a has_valid_license transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a holder can currently deploy a listing
+   */
+  has_valid_license: (
+    { contract_id, holder }: { contract_id: u32; holder: string },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<boolean>>;
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, registry }: { admin: string; registry: string },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -258,32 +2472,219 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       },
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options);
+    return ContractClient.deploy({ admin, registry }, options);
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAQAAAAAAAAAAAAAAEERlcGxveW1lbnRSZWNvcmQAAAAHAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXBsb3llZF9hdAAAAAAGAAAAAAAAABlkZXBsb3llZF9jb250cmFjdF9hZGRyZXNzAAAAAAAAEwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAEAAAAAAAAAEERlcGxveW1lbnRGYWlsZWQAAAACAAAAAAAAABFJbnZhbGlkUGFyYW1ldGVycwAAAAAAAAMAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAEAAAAAAAAABJVbmF1dGhvcml6ZWRBY2Nlc3MAAAAAAAU=",
+        "AAAABAAAAD1UaGUgQ29udHJhY3RSZWdpc3RyeSBlcnJvcnMgdGhlIERlcGxveW1lbnRNYW5hZ2VyIHRlbGxzIGFwYXJ0AAAAAAAAAAAAAA1SZWdpc3RyeUVycm9yAAAAAAAABAAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAIAAAAAAAAAD1JlbGVhc2VOb3RGb3VuZAAAAAANAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAADwAAAAAAAAATQ29udHJhY3RRdWFyYW50aW5lZAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAAEERlcGxveW1lbnRSZWNvcmQAAAASAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAtkZXBsb3llZF9hdAAAAAAGAAAAAAAAABlkZXBsb3llZF9jb250cmFjdF9hZGRyZXNzAAAAAAAAEwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAIZ3JvdXBfaWQAAAPoAAAABAAAAAAAAAAGaGlkZGVuAAAAAAABAAAAAAAAAARpbmZvAAAH0AAAAA5EZXBsb3ltZW50SW5mbwAAAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAJaW50ZW50X2lkAAAAAAAD6AAAAAQAAAAAAAAAB21hbmFnZWQAAAAAAQAAAAAAAAAFbm9uY2UAAAAAAAPoAAAABgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdyZWxlYXNlAAAAA+gAAAAQAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAABnN0YXR1cwAAAAAH0AAAABBEZXBsb3ltZW50U3RhdHVzAAAAAAAAAAh1cGdyYWRlcwAAA+oAAAfQAAAADFVwZ3JhZGVFbnRyeQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+        "AAAAAwAAAD5MaWZlY3ljbGUgc3RhZ2Ugb2YgYSBkZXBsb3ltZW50IHJlY29yZApEZWNvbW1pc3Npb25lZCBpcyBmaW5hbAAAAAAAAAAAABBEZXBsb3ltZW50U3RhdHVzAAAAAwAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhBcmNoaXZlZAAAAAEAAAAAAAAADkRlY29tbWlzc2lvbmVkAAAAAAAC",
+        "AAAAAwAAAB9FbnZpcm9ubWVudCBhIGRlcGxveW1lbnQgc2VydmVzAAAAAAAAAAAVRGVwbG95bWVudEVudmlyb25tZW50AAAAAAAABAAAAAAAAAALVW5zcGVjaWZpZWQAAAAAAAAAAAAAAAADRGV2AAAAAAEAAAAAAAAAB1N0YWdpbmcAAAAAAgAAAAAAAAAKUHJvZHVjdGlvbgAAAAAAAw==",
+        "AAAAAQAAAC9EZXBsb3llci1tYWludGFpbmVkIGRlc2NyaXB0aW9uIG9mIGEgZGVwbG95bWVudAAAAAAAAAAADkRlcGxveW1lbnRJbmZvAAAAAAAEAAAAAAAAAAtlbnZpcm9ubWVudAAAAAfQAAAAFURlcGxveW1lbnRFbnZpcm9ubWVudAAAAAAAAAAAAAAGbGFiZWxzAAAAAAPqAAAAEAAAAAAAAAAEbm90ZQAAABAAAAAAAAAAB3Byb2plY3QAAAAAEA==",
+        "AAAAAQAAAHlPbmUgY29kZSBjaGFuZ2Ugb2YgYSBtYW5hZ2VkIGluc3RhbmNlCndhc21faGFzaCBhbmQgcmVsZWFzZSBhcmUgdGhlIGN1cnJlbnQgY29kZTsgYSByb2xsYmFjayByZXN0b3JlcyB0aGUgZW50cnkgaXQgdW5kb2VzAAAAAAAAAAAAAAxVcGdyYWRlRW50cnkAAAAGAAAAAAAAAAlmcm9tX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAMZnJvbV9yZWxlYXNlAAAD6AAAABAAAAAAAAAACHJvbGxiYWNrAAAAAQAAAAAAAAAHdG9faGFzaAAAAAPuAAAAIAAAAAAAAAAKdG9fcmVsZWFzZQAAAAAD6AAAABAAAAAAAAAAC3VwZ3JhZGVkX2F0AAAAAAY=",
+        "AAAAAQAAAGJPbmUgbGlzdGluZyByZWxlYXNlIGRlcGxveWVkIGFzIHBhcnQgb2YgYSBiYXRjaApTYWx0cyBhcmUgZGVyaXZlZCBieSB0aGUgbWFuYWdlciBhcyBpbiBkZXBsb3lfbmV4dAAAAAAAAAAAAAlCYXRjaFN0ZXAAAAAAAAAEAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAFbGlua3MAAAAAAAPqAAAH0AAAAAhTdGVwTGluawAAAAAAAAAHcmVsZWFzZQAAAAAQ",
+        "AAAAAQAAAFdSZXBsYWNlIGNvbnN0cnVjdG9yIGFyZ3VtZW50IGBhcmdfaW5kZXhgIHdpdGggdGhlIGFkZHJlc3MgZGVwbG95ZWQgYnkgYW4gZWFybGllciBgc3RlcGAAAAAAAAAAAAhTdGVwTGluawAAAAIAAAAAAAAACWFyZ19pbmRleAAAAAAAAAQAAAAAAAAABHN0ZXAAAAAE",
+        "AAAAAQAAADhSZXF1aXJlbWVudHMgYSBXQVNNIGhhc2ggbXVzdCBtZWV0IGJlZm9yZSBpdCBpcyBkZXBsb3llZAAAAAAAAAAMRGVwbG95UG9saWN5AAAAAwAAAAAAAAAMbWF4X3NldmVyaXR5AAAH0AAAAAhTZXZlcml0eQAAAAAAAAAQbWluX3ZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAAAAAAAAAAAEXJlcXVpcmVfcHVibGlzaGVkAAAAAAAAAQ==",
+        "AAAAAwAAAEVIb3cgc3Ryb25nbHkgYSBidWlsZCB3YXMgdmVyaWZpZWQgKG1pcnJvcnMgdGhlIENvbnRyYWN0UmVnaXN0cnkgdHlwZSkAAAAAAAAAAAAAEVZlcmlmaWNhdGlvbkxldmVsAAAAAAAAAwAAAAAAAAAKVW52ZXJpZmllZAAAAAAAAAAAAAAAAAAJQ29tbXVuaXR5AAAAAAAAAQAAAAAAAAAHQXVkaXRlZAAAAAAC",
+        "AAAAAwAAADVBZHZpc29yeSBzZXZlcml0eSAobWlycm9ycyB0aGUgQ29udHJhY3RSZWdpc3RyeSB0eXBlKQAAAAAAAAAAAAAIU2V2ZXJpdHkAAAAFAAAAAAAAAAROb25lAAAAAAAAAAAAAAADTG93AAAAAAEAAAAAAAAABk1lZGl1bQAAAAAAAgAAAAAAAAAESGlnaAAAAAMAAAAAAAAACENyaXRpY2FsAAAABA==",
+        "AAAAAQAAAE1XaGF0IHRoZSByZWdpc3RyeSBrbm93cyBhYm91dCBhIFdBU00gaGFzaCAobWlycm9ycyB0aGUgQ29udHJhY3RSZWdpc3RyeSB0eXBlKQAAAAAAAAAAAAAKSGFzaFJlcG9ydAAAAAAAAwAAAAAAAAAQaGlnaGVzdF9zZXZlcml0eQAAB9AAAAAIU2V2ZXJpdHkAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAADHZlcmlmaWNhdGlvbgAAB9AAAAARVmVyaWZpY2F0aW9uTGV2ZWwAAAA=",
+        "AAAAAQAAAEtBIHB1Ymxpc2hlZCB2ZXJzaW9uIG9mIGEgbGlzdGluZydzIGNvZGUgKG1pcnJvcnMgdGhlIENvbnRyYWN0UmVnaXN0cnkgdHlwZSkAAAAAAAAAAAdSZWxlYXNlAAAAAAQAAAAAAAAADHB1Ymxpc2hlZF9hdAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABnlhbmtlZAAAAAAAAQ==",
+        "AAAAAQAAAMpGdWxseSBzcGVjaWZpZWQgZGVwbG95bWVudCBwcm9wb3NlZCBmb3IgYXBwcm92YWwKYXBwcm92ZXJzOiBBZGRyZXNzZXMgYWxsb3dlZCB0byBhcHByb3ZlLCB0aHJlc2hvbGQgb2Ygd2hpY2ggbXVzdCBhcHByb3ZlCmRlYWRsaW5lOiBUaW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIGludGVudCBjYW4gbm8gbG9uZ2VyIGJlIGFwcHJvdmVkIG9yIGV4ZWN1dGVkAAAAAAAAAAAADEludGVudFBhcmFtcwAAAAcAAAAAAAAACWFwcHJvdmVycwAAAAAAA+oAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJdGhyZXNob2xkAAAAAAAABA==",
+        "AAAAAQAAAD9EZXBsb3ltZW50IHdhaXRpbmcgZm9yLCBvciBleGVjdXRlZCBhZnRlciwgbXVsdGktcGFydHkgYXBwcm92YWwAAAAAAAAAABBEZXBsb3ltZW50SW50ZW50AAAACwAAAAAAAAAJYXBwcm92YWxzAAAAAAAD6gAAABMAAAAAAAAACWFwcHJvdmVycwAAAAAAA+oAAAATAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAPoAAAABAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
+        "AAAAAQAAAFFXaG8gYSBzcG9uc29yc2hpcCBwYXlzIGZvcgpsaXN0aW5nczogTGlzdGluZ3MgaXQgY292ZXJzLCBvciBlbXB0eSBmb3IgYW55IGxpc3RpbmcAAAAAAAAAAAAAEVNwb25zb3JzaGlwUGFyYW1zAAAAAAAAAwAAAAAAAAAJZGVwbG95ZXJzAAAAAAAD6gAAABMAAAAAAAAACGxpc3RpbmdzAAAD6gAAAAQAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAAD1CdWRnZXQgYSBzcG9uc29yIHNldCBhc2lkZSBmb3Igb3RoZXIgZGVwbG95ZXJzJyBkZXBsb3kgcHJpY2VzAAAAAAAAAAAAAAtTcG9uc29yc2hpcAAAAAAIAAAAAAAAAAdiYWxhbmNlAAAAAAsAAAAAAAAACWRlcGxveWVycwAAAAAAA+oAAAATAAAAAAAAABBkZXBsb3ltZW50X2NvdW50AAAABAAAAAAAAAAIbGlzdGluZ3MAAAPqAAAABAAAAAAAAAAFc3BlbnQAAAAAAAALAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAACZPbmUgZGVwbG95bWVudCBwYWlkIGZyb20gYSBzcG9uc29yc2hpcAAAAAAAAAAAABNTcG9uc29yZWREZXBsb3ltZW50AAAAAAUAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAALZGVwbG95ZWRfYXQAAAAABgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABA==",
+        "AAAAAQAAANRUZXJtcyBvZiB0aGUga2VlcC1hbGl2ZSBwb29sIChzZXQgYnkgdGhlIGFkbWluKQpJbnN0YW5jZXMgYXJlIGV4dGVuZGVkIHRvIGBleHRlbmRfdG9gIGxlZGdlcnMgb25jZSBmZXdlciB0aGFuIGB0aHJlc2hvbGRgIHJlbWFpbiwKYW5kIGVhY2ggZXh0ZW5zaW9uIHBheXMgYGZlZWAgb2YgYHRva2VuYCBmcm9tIHRoZSBpbnN0YW5jZSdzIGRlcG9zaXQgdG8gdGhlIGNhbGxlcgAAAAAAAAAPS2VlcEFsaXZlQ29uZmlnAAAAAAQAAAAAAAAACWV4dGVuZF90bwAAAAAAAAQAAAAAAAAAA2ZlZQAAAAALAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAACJLZWVwLWFsaXZlIGRlcG9zaXQgb2YgYSBkZXBsb3ltZW50AAAAAAAAAAAACUtlZXBBbGl2ZQAAAAAAAAIAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAALZXh0ZW5kZWRfYXQAAAAABA==",
+        "AAAAAQAAAE5Db2RlIGFuIGluc3RhbmNlIHJhbiwgcmVjb3JkZWQgYXQgZGVwbG95bWVudCwgb24gbWFuYWdlZCB1cGdyYWRlcyBhbmQgYnkgc3luY3MAAAAAAAAAAAAMT2JzZXJ2ZWRIYXNoAAAAAwAAAAAAAAALb2JzZXJ2ZWRfYXQAAAAABgAAAAAAAAAHcmVsZWFzZQAAAAPoAAAAEAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
+        "AAAAAQAAAJRJbnZvY2F0aW9uIG9uIGEgZnJlc2hseSBkZXBsb3llZCBpbnN0YW5jZSwgZS5nLiBhIGxlZ2FjeSBgaW5pdGlhbGl6ZWAKZXhwZWN0ZWQ6IEVtcHR5IHRvIGFjY2VwdCBhbnkgcmV0dXJuIHZhbHVlLCBvciB0aGUgc2luZ2xlIHZhbHVlIGl0IG11c3QgcmV0dXJuAAAAAAAAAA5Qb3N0RGVwbG95Q2FsbAAAAAAAAwAAAAAAAAAEYXJncwAAA+oAAAAAAAAAAAAAAAhleHBlY3RlZAAAA+oAAAAAAAAAAAAAAAhmdW5jdGlvbgAAABE=",
+        "AAAAAwAAAC5Ib3cgZGVwbG95bWVudHMgZnJvbSBhIHRlbXBsYXRlIGdldCB0aGVpciBzYWx0AAAAAAAAAAAADFNhbHRTdHJhdGVneQAAAAIAAAAAAAAAB0Rlcml2ZWQAAAAAAAAAAAAAAAAIUHJvdmlkZWQAAAAB",
+        "AAAAAQAAACRUZW1wbGF0ZSBwYXJhbWV0ZXJzIHNldCBieSBpdHMgb3duZXIAAAAAAAAADlRlbXBsYXRlUGFyYW1zAAAAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAAB3JlbGVhc2UAAAAAEAAAAAAAAAANc2FsdF9zdHJhdGVneQAAAAAAB9AAAAAMU2FsdFN0cmF0ZWd5",
+        "AAAAAQAAADRTYXZlZCBkZXBsb3ltZW50IGNvbmZpZ3VyYXRpb24gZm9yIGEgbGlzdGluZyByZWxlYXNlAAAAAAAAABJEZXBsb3ltZW50VGVtcGxhdGUAAAAAAAgAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAtyZWNvbW1lbmRlZAAAAAABAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAADXNhbHRfc3RyYXRlZ3kAAAAAAAfQAAAADFNhbHRTdHJhdGVneQAAAAAAAAALdGVtcGxhdGVfaWQAAAAABA==",
+        "AAAAAQAAADBTaGFyZSBvZiBldmVyeSBkZXBsb3kgcHJpY2Uga2VwdCBieSB0aGUgcGxhdGZvcm0AAAAAAAAAC1BsYXRmb3JtRmVlAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEw==",
+        "AAAAAwAAAEdXaGV0aGVyIGEgbGljZW5zZSBjb3ZlcnMgZXZlcnkgZGVwbG95bWVudCBieSBpdHMgaG9sZGVyIG9yIGEgc2luZ2xlIG9uZQAAAAAAAAAADExpY2Vuc2VTY29wZQAAAAIAAAAAAAAAC1BlckRlcGxveWVyAAAAAAAAAAAAAAAAC1Blckluc3RhbmNlAAAAAAE=",
+        "AAAAAQAAACtMaWNlbnNlIHRlcm1zIGFuIGF1dGhvciBzZWxscyBmb3IgYSBsaXN0aW5nAAAAAAAAAAAMTGljZW5zZU9mZmVyAAAABwAAAAAAAAAGYWN0aXZlAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAhkdXJhdGlvbgAAAAYAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAVzY29wZQAAAAAAB9AAAAAMTGljZW5zZVNjb3BlAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAAB5BIGxpY2Vuc2UgYm91Z2h0IGZyb20gYW4gb2ZmZXIAAAAAAAAAAAAHTGljZW5zZQAAAAAIAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAD6AAAAAQAAAAAAAAACmV4cGlyZXNfYXQAAAAAA+gAAAAGAAAAAAAAAAZob2xkZXIAAAAAABMAAAAAAAAACmxpY2Vuc2VfaWQAAAAAAAQAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAMcHVyY2hhc2VkX2F0AAAABgAAAAAAAAAFc2NvcGUAAAAAAAfQAAAADExpY2Vuc2VTY29wZQ==",
+        "AAAAAQAAAAAAAAAAAAAAC0RlcGxveVByaWNlAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAAAAAAAAAAAAADFJldmVudWVTaGFyZQAAAAIAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAAC1BheW91dFRlcm1zAAAAAAIAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAGc2hhcmVzAAAAAAPqAAAH0AAAAAxSZXZlbnVlU2hhcmU=",
+        "AAAAAwAAAAAAAAAAAAAACVBhcmFtVHlwZQAAAAAAABAAAAAAAAAAA0FueQAAAAAAAAAAAAAAAARCb29sAAAAAQAAAAAAAAADVTMyAAAAAAIAAAAAAAAAA0kzMgAAAAADAAAAAAAAAANVNjQAAAAABAAAAAAAAAADSTY0AAAAAAUAAAAAAAAABFUxMjgAAAAGAAAAAAAAAARJMTI4AAAABwAAAAAAAAAEVTI1NgAAAAgAAAAAAAAABEkyNTYAAAAJAAAAAAAAAAdBZGRyZXNzAAAAAAoAAAAAAAAABlN0cmluZwAAAAAACwAAAAAAAAAGU3ltYm9sAAAAAAAMAAAAAAAAAAVCeXRlcwAAAAAAAA0AAAAAAAAAA1ZlYwAAAAAOAAAAAAAAAANNYXAAAAAADw==",
+        "AAAAAQAAAAAAAAAAAAAAEENvbnN0cnVjdG9yUGFyYW0AAAAEAAAAAAAAAA1kZWZhdWx0X3ZhbHVlAAAAAAAD6gAAAAAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAApwYXJhbV90eXBlAAAAAAfQAAAACVBhcmFtVHlwZQAAAA==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAHAAAAAAAAAAQQ29udHJhY3ROb3RGb3VuZAAAAAEAAAAAAAAAEERlcGxveW1lbnRGYWlsZWQAAAACAAAAAAAAABFJbnZhbGlkUGFyYW1ldGVycwAAAAAAAAMAAAAAAAAAD0ludmFsaWRXYXNtSGFzaAAAAAAEAAAAAAAAABJVbmF1dGhvcml6ZWRBY2Nlc3MAAAAAAAUAAAAAAAAAD0xpY2Vuc2VSZXF1aXJlZAAAAAAGAAAAAAAAAA1PZmZlck5vdEZvdW5kAAAAAAAABwAAAAAAAAANT2ZmZXJJbmFjdGl2ZQAAAAAAAAgAAAAAAAAAD1JlbGVhc2VOb3RGb3VuZAAAAAAJAAAAAAAAAA1SZWxlYXNlWWFua2VkAAAAAAAACgAAAAAAAAASTGlzdGluZ1F1YXJhbnRpbmVkAAAAAAALAAAAAAAAAAxBZGRyZXNzVGFrZW4AAAAMAAAAAAAAABBUZW1wbGF0ZU5vdEZvdW5kAAAADQAAAAAAAAAUUG9zdERlcGxveUNhbGxGYWlsZWQAAAAOAAAAAAAAABVQb3N0RGVwbG95Q2hlY2tGYWlsZWQAAAAAAAAPAAAAAAAAABFVcGdyYWRlTm90TWFuYWdlZAAAAAAAABAAAAAAAAAADVVwZ3JhZGVGYWlsZWQAAAAAAAARAAAAAAAAABZLZWVwQWxpdmVOb3RDb25maWd1cmVkAAAAAAASAAAAAAAAABBIYXNoTm90UHVibGlzaGVkAAAAEwAAAAAAAAAPSGFzaE5vdFZlcmlmaWVkAAAAABQAAAAAAAAAEUhhc2hIYXNBZHZpc29yaWVzAAAAAAAAFQAAAAAAAAAOSW50ZW50Tm90Rm91bmQAAAAAABYAAAAAAAAADUludGVudEV4cGlyZWQAAAAAAAAXAAAAAAAAABFJbnRlbnROb3RBcHByb3ZlZAAAAAAAABgAAAAAAAAAE1Nwb25zb3JzaGlwTm90Rm91bmQAAAAAGQAAAAAAAAAUU3BvbnNvcnNoaXBFeGhhdXN0ZWQAAAAaAAAAAAAAAA9SZWxlYXNlTm90TmV3ZXIAAAAAGwAAAAAAAAAPUGF5bWVudE1pc21hdGNoAAAAABw=",
         "AAAABQAAAAAAAAAAAAAADURlcGxveWVkRXZlbnQAAAAAAAABAAAADmRlcGxveWVkX2V2ZW50AAAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAAAAAAEGRlcGxveWVkX2FkZHJlc3MAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAC1JveWFsdHlQYWlkAAAAAAEAAAAMcm95YWx0eV9wYWlkAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEEluc3RhbmNlVXBncmFkZWQAAAABAAAAEWluc3RhbmNlX3VwZ3JhZGVkAAAAAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAACHJvbGxiYWNrAAAAAQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEERlcGxveW1lbnRTeW5jZWQAAAABAAAAEWRlcGxveW1lbnRfc3luY2VkAAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAF0RlcGxveW1lbnRTdGF0dXNDaGFuZ2VkAAAAAAEAAAAZZGVwbG95bWVudF9zdGF0dXNfY2hhbmdlZAAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAEERlcGxveW1lbnRTdGF0dXMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAG0RlcGxveW1lbnRWaXNpYmlsaXR5Q2hhbmdlZAAAAAABAAAAHWRlcGxveW1lbnRfdmlzaWJpbGl0eV9jaGFuZ2VkAAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAAZoaWRkZW4AAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFURlcGxveW1lbnRUcmFuc2ZlcnJlZAAAAAAAAAEAAAAWZGVwbG95bWVudF90cmFuc2ZlcnJlZAAAAAAAAwAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkludGVudFByb3Bvc2VkAAAAAAABAAAAD2ludGVudF9wcm9wb3NlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkludGVudEFwcHJvdmVkAAAAAAABAAAAD2ludGVudF9hcHByb3ZlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAIYXBwcm92ZXIAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkludGVudEV4ZWN1dGVkAAAAAAABAAAAD2ludGVudF9leGVjdXRlZAAAAAACAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEVNwb25zb3JzaGlwRnVuZGVkAAAAAAAAAQAAABJzcG9uc29yc2hpcF9mdW5kZWQAAAAAAAIAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD1Nwb25zb3JlZERlcGxveQAAAAABAAAAEHNwb25zb3JlZF9kZXBsb3kAAAADAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAAAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE0xpY2Vuc2VPZmZlckNyZWF0ZWQAAAAAAQAAABVsaWNlbnNlX29mZmVyX2NyZWF0ZWQAAAAAAAACAAAAAAAAAAhvZmZlcl9pZAAAAAQAAAAAAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEExpY2Vuc2VQdXJjaGFzZWQAAAABAAAAEWxpY2Vuc2VfcHVyY2hhc2VkAAAAAAAAAwAAAAAAAAAKbGljZW5zZV9pZAAAAAAABAAAAAAAAAAAAAAACG9mZmVyX2lkAAAABAAAAAAAAAAAAAAABmhvbGRlcgAAAAAAEwAAAAAAAAAC",
+        "AAAAAAAAAOZDb25maWd1cmUgdGhlIGFkbWluIGFuZCB0aGUgQ29udHJhY3RSZWdpc3RyeSB0aGlzIG1hbmFnZXIgZGVwbG95cyBmcm9tClRoZSByZWdpc3RyeSdzIGFkbWluIG11c3QgdGhlbiBuYW1lIHRoaXMgbWFuYWdlciB3aXRoIHNldF9kZXBsb3ltZW50X21hbmFnZXI7CnVudGlsIHRoZW4gZGVwbG95bWVudHMgb2YgYSBsaXN0aW5nJ3MgcHVibGlzaGVkIGNvZGUgZmFpbCB3aXRoIFVuYXV0aG9yaXplZEFjY2VzcwAAAAAADV9fY29uc3RydWN0b3IAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACHJlZ2lzdHJ5AAAAEwAAAAA=",
+        "AAAAAAAAAD9TZXQgdGhlIHBsYXRmb3JtIGN1dCB0YWtlbiBmcm9tIGV2ZXJ5IGRlcGxveSBwcmljZSAoYWRtaW4gb25seSkAAAAAEHNldF9wbGF0Zm9ybV9mZWUAAAACAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAACpHZXQgdGhlIHBsYXRmb3JtIGZlZSwgaWYgb25lIGlzIGNvbmZpZ3VyZWQAAAAAABBnZXRfcGxhdGZvcm1fZmVlAAAAAAAAAAEAAAPoAAAH0AAAAAtQbGF0Zm9ybUZlZQA=",
+        "AAAAAAAAAHFDb25maWd1cmUgdGhlIGtlZXAtYWxpdmUgcG9vbCAoYWRtaW4gb25seSkKVGhlIGRlcG9zaXQgdG9rZW4gY2FuJ3QgY2hhbmdlIG9uY2Ugc2V0LCBzaW5jZSBkZXBvc2l0cyBhcmUgaGVsZCBpbiBpdAAAAAAAABVzZXRfa2VlcF9hbGl2ZV9jb25maWcAAAAAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAAPS2VlcEFsaXZlQ29uZmlnAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAACxHZXQgdGhlIGtlZXAtYWxpdmUgcG9vbCB0ZXJtcywgaWYgY29uZmlndXJlZAAAABVnZXRfa2VlcF9hbGl2ZV9jb25maWcAAAAAAAAAAAAAAQAAA+gAAAfQAAAAD0tlZXBBbGl2ZUNvbmZpZwA=",
+        "AAAAAAAAAD9TZXQgb3IgY2xlYXIgdGhlIHBvbGljeSBldmVyeSBkZXBsb3ltZW50IG11c3QgbWVldCAoYWRtaW4gb25seSkAAAAAEXNldF9kZXBsb3lfcG9saWN5AAAAAAAAAQAAAAAAAAAGcG9saWN5AAAAAAPoAAAH0AAAAAxEZXBsb3lQb2xpY3kAAAAA",
+        "AAAAAAAAADFHZXQgdGhlIHBvbGljeSBldmVyeSBkZXBsb3ltZW50IG11c3QgbWVldCwgaWYgc2V0AAAAAAAAEWdldF9kZXBsb3lfcG9saWN5AAAAAAAAAAAAAAEAAAPoAAAH0AAAAAxEZXBsb3lQb2xpY3k=",
+        "AAAAAAAAAEZTZXQgb3IgY2xlYXIgYSBkZXBsb3llcidzIG93biBwb2xpY3ksIGFwcGxpZWQgb24gdG9wIG9mIHRoZSBnbG9iYWwgb25lAAAAAAATc2V0X2RlcGxveWVyX3BvbGljeQAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAABnBvbGljeQAAAAAD6AAAB9AAAAAMRGVwbG95UG9saWN5AAAAAA==",
+        "AAAAAAAAACNHZXQgYSBkZXBsb3llcidzIG93biBwb2xpY3ksIGlmIHNldAAAAAATZ2V0X2RlcGxveWVyX3BvbGljeQAAAAABAAAAAAAAAAhkZXBsb3llcgAAABMAAAABAAAD6AAAB9AAAAAMRGVwbG95UG9saWN5",
+        "AAAAAAAAACBHZXQgdGhlIENvbnRyYWN0UmVnaXN0cnkgYWRkcmVzcwAAAAxnZXRfcmVnaXN0cnkAAAAAAAAAAQAAABM=",
         "AAAAAAAAAJhEZXBsb3kgYSBjb250cmFjdCB3aXRoIGFuIGFkbWluIGFkZHJlc3MgY29uc3RydWN0b3IgcGFyYW1ldGVyClRoaXMgaXMgYSBjb252ZW5pZW5jZSBtZXRob2QgZm9yIHRoZSBjb21tb24gcGF0dGVybiBvZiBjb250cmFjdHMgd2l0aCBhZG1pbiBpbml0aWFsaXphdGlvbgAAABFkZXBsb3lfd2l0aF9hZG1pbgAAAAAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAAEAAAAAw==",
-        "AAAAAAAAALREZXBsb3kgYSBjb250cmFjdCBmcm9tIHRoZSByZWdpc3RyeQpUaGlzIGRlcGxveXMgYSBuZXcgY29udHJhY3QgaW5zdGFuY2Ugb24tY2hhaW4gZnJvbSB0aGUgV0FTTSBoYXNoCmluaXRfYXJnczogQ29uc3RydWN0b3IgYXJndW1lbnRzIGZvciB0aGUgY29udHJhY3QgKGVtcHR5IHZlYyBpZiBubyBjb25zdHJ1Y3RvcikAAAAQZGVwbG95X2Zyb21fd2FzbQAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAnpEZXBsb3kgYSBjb250cmFjdCBmcm9tIHRoZSByZWdpc3RyeQpUaGlzIGRlcGxveXMgYSBuZXcgY29udHJhY3QgaW5zdGFuY2Ugb24tY2hhaW4gZnJvbSB0aGUgV0FTTSBoYXNoCmluaXRfYXJnczogQ29uc3RydWN0b3IgYXJndW1lbnRzIGZvciB0aGUgY29udHJhY3QgKGVtcHR5IHZlYyBpZiBubyBjb25zdHJ1Y3RvcikKSWYgdGhlIGxpc3RpbmcgaGFzIGEgY29uc3RydWN0b3Igc2NoZW1hLCBpbml0X2FyZ3MgYXJlIGNoZWNrZWQgYWdhaW5zdCBpdCBhbmQKb21pdHRlZCB0cmFpbGluZyBhcmd1bWVudHMgdGFrZSB0aGVpciBkZWZhdWx0czsgbWlzbWF0Y2hlcyBmYWlsIHdpdGgKSW52YWxpZFBhcmFtZXRlcnMKSWYgdGhlIGF1dGhvciBzZXQgYSBkZXBsb3kgcHJpY2UgaXQgaXMgY2hhcmdlZCB0byB0aGUgZGVwbG95ZXIgaW4gdGhlIHNhbWUgdHJhbnNhY3Rpb24KT25seSBhIGhhc2ggdGhlIGxpc3RpbmcgcmVsZWFzZWQgaXMgbGljZW5zZWQsIGNoYXJnZWQgYW5kIGNvdW50ZWQgYWdhaW5zdCBpdDsKb3RoZXIgaGFzaGVzIGFyZSByZWNvcmRlZCBhcyBVTkxJU1RFRCwgYW5kIGxpc3RpbmcgaWRzIHVua25vd24gdG8gdGhlIHJlZ2lzdHJ5CmFyZSBrZXB0IG9uIHRoZSByZWNvcmQgd2l0aG91dCBiZWluZyBjb3VudGVkAAAAAAAQZGVwbG95X2Zyb21fd2FzbQAAAAUAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAMdVcGxvYWQgV0FTTSBhbmQgZGVwbG95IGl0IHdpdGhvdXQgcHVibGlzaGluZyBpdCB0byB0aGUgcmVnaXN0cnkKVGhlIHJlY29yZCdzIGNvbnRyYWN0X2lkIGlzIFVOTElTVEVEIGFuZCBrZWVwcyB0aGUgdXBsb2FkZWQgaGFzaDsgdW5saXN0ZWQKZGVwbG95bWVudHMgYXJlIGxpc3RlZCBieSBnZXRfY29udHJhY3RfZGVwbG95bWVudHMoVU5MSVNURUQpAAAAABFkZXBsb3lfZnJvbV9ieXRlcwAAAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEd2FzbQAAAA4AAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAANZEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZwpUaGUgV0FTTSBoYXNoIGlzIHJlc29sdmVkIHRocm91Z2ggdGhlIENvbnRyYWN0UmVnaXN0cnksIHNvIHRoZSByZWNvcmQgaXMKZ3VhcmFudGVlZCB0byBtYXRjaCB0aGUgbGlzdGluZydzIHB1Ymxpc2hlZCBjb2RlClVua25vd24sIHlhbmtlZCBhbmQgcXVhcmFudGluZWQgcmVsZWFzZXMgYXJlIHJlamVjdGVkAAAAAAAUZGVwbG95X2Zyb21fcmVnaXN0cnkAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAARdEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyBhbmQgaW52b2tlIGZ1bmN0aW9ucyBvbiB0aGUgbmV3IGluc3RhbmNlCkNhbGxzIHJ1biBpbiBvcmRlciBpbiB0aGUgc2FtZSB0cmFuc2FjdGlvbiwgZS5nLiBhIGxlZ2FjeSBgaW5pdGlhbGl6ZWAgZm9sbG93ZWQKYnkgcmVhZC1vbmx5IGhlYWx0aCBjaGVja3MKSWYgYSBjYWxsIGZhaWxzIG9yIHJldHVybnMgc29tZXRoaW5nIG90aGVyIHRoYW4gaXRzIGV4cGVjdGVkIHZhbHVlIHRoZQpkZXBsb3ltZW50IGlzIGFib3J0ZWQAAAAAD2RlcGxveV9hbmRfY2FsbAAAAAAGAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAlpbml0X2FyZ3MAAAAAAAPqAAAAAAAAAAAAAAAFY2FsbHMAAAAAAAPqAAAH0AAAAA5Qb3N0RGVwbG95Q2FsbAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAASxEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyB3aXRoIGEgc2FsdCBkZXJpdmVkIGJ5IHRoZSBtYW5hZ2VyClRoZSBzYWx0IGlzIHRoZSBTSEEtMjU2IG9mIChkZXBsb3llciwgY29udHJhY3RfaWQsIG5vbmNlKSwgd2hlcmUgbm9uY2UgaXMgdGhlCmRlcGxveWVyJ3MgbmV4dCB1bnVzZWQgbm9uY2UsIHNvIHJlcGVhdGVkIGRlcGxveW1lbnRzIG5ldmVyIGNvbGxpZGUKVGhlIG5vbmNlIGlzIHN0b3JlZCBvbiB0aGUgcmVjb3JkIHNvIHRoZSBzYWx0IGNhbiBiZSByZXByb2R1Y2VkIHdpdGggZGVyaXZlX3NhbHQAAAALZGVwbG95X25leHQAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAATpEZXBsb3kgc2V2ZXJhbCBsaXN0aW5nIHJlbGVhc2VzIGluIG9yZGVyIGFzIG9uZSBncm91cApFYWNoIHN0ZXAgbWF5IHJlcGxhY2UgY29uc3RydWN0b3IgYXJndW1lbnRzIHdpdGggYWRkcmVzc2VzIGRlcGxveWVkIGJ5CmVhcmxpZXIgc3RlcHMsIGUuZy4gYSB2YXVsdCB0YWtpbmcgdGhlIGFkZHJlc3Mgb2YgdGhlIHRva2VuIGRlcGxveWVkIGJlZm9yZSBpdApJZiBhbnkgc3RlcCBmYWlscyB0aGUgd2hvbGUgYmF0Y2ggaXMgcm9sbGVkIGJhY2sKUmV0dXJucyB0aGUgZ3JvdXAgaWQgbGlua2luZyB0aGUgcmVzdWx0aW5nIGRlcGxveW1lbnQgcmVjb3JkcwAAAAAADGRlcGxveV9iYXRjaAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAFc3RlcHMAAAAAAAPqAAAH0AAAAAlCYXRjaFN0ZXAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAC1HZXQgdGhlIGRlcGxveW1lbnRzIG9mIGEgYmF0Y2gsIGluIHN0ZXAgb3JkZXIAAAAAAAAVZ2V0X2dyb3VwX2RlcGxveW1lbnRzAAAAAAAAAQAAAAAAAAAIZ3JvdXBfaWQAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAAHJTYXZlIGEgZGVwbG95bWVudCB0ZW1wbGF0ZSBmb3IgYSBsaXN0aW5nIHJlbGVhc2UKVGVtcGxhdGVzIHNhdmVkIGJ5IHRoZSBsaXN0aW5nJ3MgYXV0aG9yIGFyZSBtYXJrZWQgYXMgcmVjb21tZW5kZWQAAAAAAA9jcmVhdGVfdGVtcGxhdGUAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAZwYXJhbXMAAAAAB9AAAAAOVGVtcGxhdGVQYXJhbXMAAAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAADVSZXBsYWNlIGEgdGVtcGxhdGUncyBwYXJhbWV0ZXJzICh0ZW1wbGF0ZSBvd25lciBvbmx5KQAAAAAAAA91cGRhdGVfdGVtcGxhdGUAAAAAAgAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAAAAAAGcGFyYW1zAAAAAAfQAAAADlRlbXBsYXRlUGFyYW1zAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAACdEZWxldGUgYSB0ZW1wbGF0ZSAodGVtcGxhdGUgb3duZXIgb25seSkAAAAAD2RlbGV0ZV90ZW1wbGF0ZQAAAAABAAAAAAAAAAt0ZW1wbGF0ZV9pZAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAHJEZXBsb3kgZnJvbSBhIHNhdmVkIHRlbXBsYXRlCnNhbHQ6IFJlcXVpcmVkIGZvciB0ZW1wbGF0ZXMgd2l0aCBhIHByb3ZpZGVkIHNhbHQgc3RyYXRlZ3ksIG9taXR0ZWQgZm9yIGRlcml2ZWQgc2FsdHMAAAAAABRkZXBsb3lfZnJvbV90ZW1wbGF0ZQAAAAMAAAAAAAAAC3RlbXBsYXRlX2lkAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+gAAAPuAAAAIAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAABdHZXQgYSBzcGVjaWZpYyB0ZW1wbGF0ZQAAAAAMZ2V0X3RlbXBsYXRlAAAAAQAAAAAAAAALdGVtcGxhdGVfaWQAAAAABAAAAAEAAAPpAAAH0AAAABJEZXBsb3ltZW50VGVtcGxhdGUAAAAAAAM=",
+        "AAAAAAAAACNHZXQgYWxsIHRlbXBsYXRlcyBzYXZlZCBieSBhbiBvd25lcgAAAAANZ2V0X3RlbXBsYXRlcwAAAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAPqAAAH0AAAABJEZXBsb3ltZW50VGVtcGxhdGUAAA==",
+        "AAAAAAAAAC9HZXQgdGhlIHRlbXBsYXRlcyBhIGxpc3RpbmcncyBhdXRob3IgcmVjb21tZW5kcwAAAAAZZ2V0X3JlY29tbWVuZGVkX3RlbXBsYXRlcwAAAAAAAAEAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAABAAAD6gAAB9AAAAASRGVwbG95bWVudFRlbXBsYXRlAAA=",
+        "AAAAAAAAARVQcm9wb3NlIGEgZGVwbG95bWVudCB0aGF0IG5lZWRzIHNpZ24tb2ZmIGJlZm9yZSBpdCBydW5zCkV4ZWN1dGVkIGludGVudHMgZGVwbG95IGZyb20gdGhlIG1hbmFnZXIncyBvd24gYWRkcmVzcywgc28gdGhlIGluc3RhbmNlCmFkZHJlc3MgaXMgcHJlZGljdF9hZGRyZXNzKG1hbmFnZXIgYWRkcmVzcywgZGVyaXZlX2ludGVudF9zYWx0KHByb3Bvc2VyLCBzYWx0KSk7CnRoZSBwcm9wb3NlciBpcyByZWNvcmRlZCBhcyB0aGUgZGVwbG95ZXIgYW5kIG93bmVyIGFuZCBjYW4ndCBhcHByb3ZlAAAAAAAADnByb3Bvc2VfaW50ZW50AAAAAAACAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAAAxJbnRlbnRQYXJhbXMAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADRBcHByb3ZlIGEgcGVuZGluZyBpbnRlbnQgKGRlc2lnbmF0ZWQgYXBwcm92ZXJzIG9ubHkpAAAADmFwcHJvdmVfaW50ZW50AAAAAAACAAAAAAAAAAhhcHByb3ZlcgAAABMAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHlFeGVjdXRlIGFuIGludGVudCBvbmNlIGVub3VnaCBhcHByb3ZlcnMgc2lnbmVkIG9mZiAoYW55b25lIGNhbiBjYWxsKQpUaGUgZXhlY3V0b3IgcGF5cyB0aGUgbGlzdGluZydzIGRlcGxveSBwcmljZSwgaWYgYW55AAAAAAAADmV4ZWN1dGVfaW50ZW50AAAAAAACAAAAAAAAAAhleGVjdXRvcgAAABMAAAAAAAAACWludGVudF9pZAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAACBHZXQgYSBzcGVjaWZpYyBkZXBsb3ltZW50IGludGVudAAAAApnZXRfaW50ZW50AAAAAAABAAAAAAAAAAlpbnRlbnRfaWQAAAAAAAAEAAAAAQAAA+kAAAfQAAAAEERlcGxveW1lbnRJbnRlbnQAAAAD",
+        "AAAAAAAAAEJHZXQgdGhlIG5vbmNlIHRoZSBkZXBsb3llcidzIG5leHQgZGVwbG95X25leHQgY2FsbCB3aWxsIHN0YXJ0IGZyb20AAAAAABJnZXRfZGVwbG95ZXJfbm9uY2UAAAAAAAEAAAAAAAAACGRlcGxveWVyAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAENDb21wdXRlIHRoZSBzYWx0IGRlcGxveV9uZXh0IHVzZXMgZm9yIGEgZGVwbG95ZXIsIGxpc3RpbmcgYW5kIG5vbmNlAAAAAAtkZXJpdmVfc2FsdAAAAAADAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABW5vbmNlAAAAAAAABgAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAEFDb21wdXRlIHRoZSBzYWx0IGFuIGludGVudCBieSBgcHJvcG9zZXJgIHdpdGggYHNhbHRgIGRlcGxveXMgd2l0aAAAAAAAABJkZXJpdmVfaW50ZW50X3NhbHQAAAAAAAIAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAOpEZXBsb3kgYW4gaWRlbnRpY2FsIGluc3RhbmNlIG9mIGFuIGVhcmxpZXIgZGVwbG95bWVudCAob3JpZ2luYWwgZGVwbG95ZXIgb25seSkKVGhlIHJlY29yZGVkIFdBU00gaGFzaCBhbmQgY29uc3RydWN0b3IgYXJndW1lbnRzIGFyZSByZXVzZWQgd2l0aCBhIG5ldyBzYWx0CkRlcGxveW1lbnRzIG9mIGEgcmVnaXN0cnkgcmVsZWFzZSBhcmUgcmVqZWN0ZWQgb25jZSBpdCBpcyB5YW5rZWQgb3IgcXVhcmFudGluZWQAAAAAAAhyZWRlcGxveQAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAhuZXdfc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAANpPcHQgYW4gaW5zdGFuY2UgaW4gb3Igb3V0IG9mIG1hbmFnZWQgdXBncmFkZXMgKHJlY29yZCBvd25lciBvbmx5KQpUaGUgaW5zdGFuY2UgbXVzdCBleHBvc2UgYHVwZ3JhZGUobmV3X3dhc21faGFzaClgIGFuZCBhY2NlcHQgdGhpcyBtYW5hZ2VyIGFzCml0cyB1cGdyYWRlIGF1dGhvcml0eSwgZS5nLiBieSBiZWluZyBkZXBsb3llZCB3aXRoIHRoZSBtYW5hZ2VyIGFzIGl0cyBhZG1pbgAAAAAAFHNldF9tYW5hZ2VkX3VwZ3JhZGVzAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAB21hbmFnZWQAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAV1VcGdyYWRlIGEgbWFuYWdlZCBpbnN0YW5jZSB0byBhIG5ld2VyIHJlbGVhc2Ugb2YgaXRzIGxpc3RpbmcgKHJlY29yZCBvd25lciBvbmx5KQpUaGUgcmVsZWFzZSBpcyByZXNvbHZlZCB0aHJvdWdoIHRoZSByZWdpc3RyeSwgc28geWFua2VkIGFuZCBxdWFyYW50aW5lZApyZWxlYXNlcyBhcmUgcmVqZWN0ZWQsIGFuZCBtdXN0IG1lZXQgdGhlIGdsb2JhbCBhbmQgdGhlIG93bmVyJ3MgZGVwbG95IHBvbGljeQpSZWxlYXNlcyBwdWJsaXNoZWQgbm8gbGF0ZXIgdGhhbiB0aGUgY3VycmVudCBvbmUgZmFpbCB3aXRoIFJlbGVhc2VOb3ROZXdlcjsKdXNlIHJvbGxiYWNrX2RlcGxveW1lbnQgdG8gdW5kbyBhbiB1cGdyYWRlAAAAAAAAEnVwZ3JhZGVfZGVwbG95bWVudAAAAAAAAgAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAAAAAAAB3JlbGVhc2UAAAAAEAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAT1VbmRvIHRoZSBtb3N0IHJlY2VudCB1cGdyYWRlIG9mIGEgbWFuYWdlZCBpbnN0YW5jZSAocmVjb3JkIG93bmVyIG9ubHkpCk9ubHkgdXBncmFkZXMgY2FuIGJlIHJvbGxlZCBiYWNrLCBub3QgZWFybGllciByb2xsYmFja3MsIGFuZCB0aGUgcmVzdG9yZWQKY29kZSBtdXN0IG1lZXQgdGhlIGdsb2JhbCBhbmQgdGhlIG93bmVyJ3MgZGVwbG95IHBvbGljeQpGYWlscyBpZiB0aGUgaW5zdGFuY2Ugbm8gbG9uZ2VyIHJ1bnMgdGhlIGNvZGUgdGhhdCB1cGdyYWRlIGluc3RhbGxlZCwKZS5nLiBhZnRlciBhbiB1cGdyYWRlIG1hZGUgb3V0c2lkZSB0aGUgbWFuYWdlcgAAAAAAABNyb2xsYmFja19kZXBsb3ltZW50AAAAAAEAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAQ9VcGRhdGUgYSByZWNvcmQgdG8gdGhlIGNvZGUgaXRzIGluc3RhbmNlIGN1cnJlbnRseSBydW5zCkNhdGNoZXMgdXBncmFkZXMgbWFkZSBvdXRzaWRlIHRoZSBtYW5hZ2VyOyBhbnlvbmUgY2FuIGNhbGwgaXQKQ2hhbmdlcyBhcmUga2VwdCBpbiB0aGUgb2JzZXJ2ZWQgaGFzaCBoaXN0b3J5LCB3aXRoIHRoZSByZWxlYXNlIG9mIHRoZQpyZWNvcmQncyBsaXN0aW5nIHRoYXQgcHVibGlzaGVkIHRoZSBuZXcgaGFzaCwgaWYgYW55ClJldHVybnMgdGhlIGN1cnJlbnQgV0FTTSBoYXNoAAAAAA9zeW5jX2RlcGxveW1lbnQAAAAAAQAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAABAAAD6QAAA+4AAAAgAAAAAw==",
+        "AAAAAAAAAI1HZXQgdGhlIGNvZGUgYSBkZXBsb3ltZW50IGhhcyBydW4sIG9sZGVzdCBmaXJzdDogdGhlIGRlcGxveWVkIGhhc2gsCnRoZW4gZXZlcnkgbWFuYWdlZCB1cGdyYWRlIG9yIHJvbGxiYWNrIGFuZCBjaGFuZ2Ugc2VlbiBieSBzeW5jX2RlcGxveW1lbnQAAAAAAAATZ2V0X29ic2VydmVkX2hhc2hlcwAAAAABAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAEAAAPqAAAH0AAAAAxPYnNlcnZlZEhhc2g=",
+        "AAAAAAAAAHFHZXQgdGhlIGFkZHJlc3MgYSBkZXBsb3ltZW50IGJ5IGBkZXBsb3llcmAgd2l0aCBgc2FsdGAgd2lsbCBoYXZlClVzZXMgdGhlIHNhbWUgZGVyaXZhdGlvbiBhcyB0aGUgZGVwbG95IGZ1bmN0aW9ucwAAAAAAAA9wcmVkaWN0X2FkZHJlc3MAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAAEw==",
+        "AAAAAAAAAIRDaGVjayB3aGV0aGVyIGEgY29udHJhY3QgYWxyZWFkeSBleGlzdHMgYXQgdGhlIGFkZHJlc3MgZm9yIGBkZXBsb3llcmAgYW5kIGBzYWx0YApEZXBsb3lpbmcgd2l0aCBhIHRha2VuIHNhbHQgZmFpbHMgd2l0aCBBZGRyZXNzVGFrZW4AAAAQaXNfYWRkcmVzc190YWtlbgAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAAAE=",
+        "AAAAAAAAAO5DaGVjayBjb25zdHJ1Y3RvciBhcmd1bWVudHMgYWdhaW5zdCBhIGxpc3RpbmcncyBzY2hlbWEgd2l0aG91dCBkZXBsb3lpbmcKUmV0dXJucyB0aGUgaW5kZXggb2YgdGhlIGZpcnN0IGludmFsaWQgYXJndW1lbnQsIG9yIE5vbmUgaWYgdGhleSB3b3VsZCBiZSBhY2NlcHRlZApFdmVyeSBkZXBsb3kgZW50cnkgcG9pbnQgYXBwbGllcyB0aGUgc2FtZSBjaGVjayBidXQgb25seSByZXBvcnRzIEludmFsaWRQYXJhbWV0ZXJzAAAAAAAPY2hlY2tfaW5pdF9hcmdzAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAACWluaXRfYXJncwAAAAAAA+oAAAAAAAAAAQAAA+gAAAAE",
         "AAAAAAAAACBHZXQgYSBzcGVjaWZpYyBkZXBsb3ltZW50IHJlY29yZAAAAA5nZXRfZGVwbG95bWVudAAAAAAAAQAAAAAAAAANZGVwbG95bWVudF9pZAAAAAAAAAQAAAABAAAD6QAAB9AAAAAQRGVwbG95bWVudFJlY29yZAAAAAM=",
-        "AAAAAAAAAC5HZXQgZGVwbG95bWVudCBoaXN0b3J5IGZvciBhIHNwZWNpZmljIGRlcGxveWVyAAAAAAAWZ2V0X2RlcGxveW1lbnRfaGlzdG9yeQAAAAAAAQAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAAIxHZXQgdGhlIHJlY29yZCBvZiB0aGUgZGVwbG95bWVudCB0aGF0IGNyZWF0ZWQgYSBjb250cmFjdCBhZGRyZXNzCkZhaWxzIHdpdGggQ29udHJhY3ROb3RGb3VuZCBmb3IgYWRkcmVzc2VzIG5vdCBkZXBsb3llZCB0aHJvdWdoIHRoaXMgbWFuYWdlcgAAABlnZXRfZGVwbG95bWVudF9ieV9hZGRyZXNzAAAAAAAAAQAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAQAAA+kAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQAAAAD",
+        "AAAAAAAAAHBHZXQgZGVwbG95bWVudCBoaXN0b3J5IGZvciBhIHNwZWNpZmljIGRlcGxveWVyCkhpZGRlbiByZWNvcmRzIGFyZSBsZWZ0IG91dCBvZiB0aGlzIGFuZCB0aGUgb3RoZXIgcHVibGljIGxpc3RpbmdzAAAAFmdldF9kZXBsb3ltZW50X2hpc3RvcnkAAAAAAAEAAAAAAAAACGRlcGxveWVyAAAAEwAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
+        "AAAAAAAAAFFTZXQgdGhlIGxhYmVscywgZW52aXJvbm1lbnQsIG5vdGUgYW5kIHByb2plY3Qgb2YgYSBkZXBsb3ltZW50IChyZWNvcmQgb3duZXIgb25seSkAAAAAAAATc2V0X2RlcGxveW1lbnRfaW5mbwAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAEaW5mbwAAB9AAAAAORGVwbG95bWVudEluZm8AAAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEpHZXQgYW4gb3duZXIncyBkZXBsb3ltZW50cyB0aGF0IGJlbG9uZyB0byBhIHByb2plY3QsIGluY2x1ZGluZyBoaWRkZW4gb25lcwAAAAAAF2dldF9wcm9qZWN0X2RlcGxveW1lbnRzAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAHcHJvamVjdAAAAAAQAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAAExHZXQgYW4gb3duZXIncyBkZXBsb3ltZW50cyB0YWdnZWQgd2l0aCBhbiBlbnZpcm9ubWVudCwgaW5jbHVkaW5nIGhpZGRlbiBvbmVzAAAAG2dldF9lbnZpcm9ubWVudF9kZXBsb3ltZW50cwAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAC2Vudmlyb25tZW50AAAAB9AAAAAVRGVwbG95bWVudEVudmlyb25tZW50AAAAAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAAHBNb3ZlIGEgZGVwbG95bWVudCB0byBhbm90aGVyIGxpZmVjeWNsZSBzdGFnZSAocmVjb3JkIG93bmVyIG9ubHkpCkRlY29tbWlzc2lvbmVkIHJlY29yZHMgY2FuJ3QgY2hhbmdlIHN0YWdlIGFnYWluAAAAFXNldF9kZXBsb3ltZW50X3N0YXR1cwAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAQRGVwbG95bWVudFN0YXR1cwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAExIaWRlIGEgZGVwbG95bWVudCBmcm9tIHB1YmxpYyBsaXN0aW5ncywgb3Igc2hvdyBpdCBhZ2FpbiAocmVjb3JkIG93bmVyIG9ubHkpAAAAFXNldF9kZXBsb3ltZW50X2hpZGRlbgAAAAAAAAIAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZoaWRkZW4AAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAJlIYW5kIGEgZGVwbG95bWVudCByZWNvcmQgdG8gYSBuZXcgb3duZXIsIGUuZy4gYSB0ZWFtIG11bHRpc2lnIChyZWNvcmQgb3duZXIgb25seSkKVGhlIG9yaWdpbmFsIGRlcGxveWVyIHN0YXlzIG9uIHRoZSByZWNvcmQgYW5kIGluIGl0cyBkZXBsb3ltZW50IGhpc3RvcnkAAAAAAAATdHJhbnNmZXJfZGVwbG95bWVudAAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAJbmV3X293bmVyAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAADpHZXQgdGhlIGRlcGxveW1lbnRzIGFuIGFkZHJlc3Mgb3ducywgaW5jbHVkaW5nIGhpZGRlbiBvbmVzAAAAAAAVZ2V0X293bmVkX2RlcGxveW1lbnRzAAAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
         "AAAAAAAAACpHZXQgYWxsIGRlcGxveW1lbnRzIG9mIGEgc3BlY2lmaWMgY29udHJhY3QAAAAAABhnZXRfY29udHJhY3RfZGVwbG95bWVudHMAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAAUlHZXQgYSBwYWdlIG9mIHRoZSBkZXBsb3ltZW50cyBydW5uaW5nIGEgV0FTTSBoYXNoCkRlcGxveW1lbnRzIGFyZSBsaXN0ZWQgaW4gdGhlIG9yZGVyIHRoZXkgd2VyZSBpbmRleGVkLCBleGNlcHQgdGhhdCB3aGVuCm9uZSBpcyB1cGdyYWRlZCBhd2F5IHRoZSBsYXN0IG9uZSB0YWtlcyBpdHMgc2xvdApIaWRkZW4gcmVjb3JkcyBhcmUgaW5jbHVkZWQsIHNvIGV2ZXJ5IGFmZmVjdGVkIGluc3RhbmNlIGNhbiBiZSBmb3VuZApzdGFydDogSW5kZXggb2YgdGhlIGZpcnN0IGRlcGxveW1lbnQgdG8gcmV0dXJuCmxpbWl0OiBNYXhpbXVtIG51bWJlciBvZiByZWNvcmRzIHRvIHJldHVybgAAAAAAABRnZXRfaGFzaF9kZXBsb3ltZW50cwAAAAMAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
+        "AAAAAAAAADFHZXQgdGhlIG51bWJlciBvZiBkZXBsb3ltZW50cyBydW5uaW5nIGEgV0FTTSBoYXNoAAAAAAAAGWdldF9oYXNoX2RlcGxveW1lbnRfY291bnQAAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAE",
+        "AAAAAAAAAKRHZXQgYSBwYWdlIG9mIHRoZSBkZXBsb3ltZW50cyBvZiBhIGxpc3RpbmcgcmVsZWFzZSwgb3JkZXJlZCBsaWtlIGdldF9oYXNoX2RlcGxveW1lbnRzCkRlcGxveW1lbnRzIGJ5IFdBU00gaGFzaCBhcmUgaW5kZXhlZCB1bmRlciB0aGUgcmVsZWFzZSB0aGF0IHB1Ymxpc2hlZCB0aGUgaGFzaAAAABdnZXRfcmVsZWFzZV9kZXBsb3ltZW50cwAAAAAEAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAEERlcGxveW1lbnRSZWNvcmQ=",
+        "AAAAAAAAADJHZXQgdGhlIG51bWJlciBvZiBkZXBsb3ltZW50cyBvZiBhIGxpc3RpbmcgcmVsZWFzZQAAAAAAHGdldF9yZWxlYXNlX2RlcGxveW1lbnRfY291bnQAAAACAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAdyZWxlYXNlAAAAABAAAAABAAAABA==",
         "AAAAAAAAABpHZXQgYWxsIGRlcGxveW1lbnQgcmVjb3JkcwAAAAAAE2dldF9hbGxfZGVwbG95bWVudHMAAAAAAAAAAAEAAAPqAAAH0AAAABBEZXBsb3ltZW50UmVjb3Jk",
         "AAAAAAAAAB9HZXQgdG90YWwgbnVtYmVyIG9mIGRlcGxveW1lbnRzAAAAABVnZXRfdG90YWxfZGVwbG95bWVudHMAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAALVBZGQgYW4gaW5zdGFuY2UgdG8gdGhlIGtlZXAtYWxpdmUgcG9vbCwgb3IgdG9wIHVwIGl0cyBkZXBvc2l0IChyZWNvcmQgb3duZXIgb25seSkKYW1vdW50OiBEZXBvc2l0IGluIHRoZSBwb29sIHRva2VuLCBzcGVudCBvbiBleHRlbnNpb24gZmVlcwpEZWNvbW1pc3Npb25lZCBpbnN0YW5jZXMgY2FuJ3QgYmUgZnVuZGVkAAAAAAAAD2Z1bmRfa2VlcF9hbGl2ZQAAAAACAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAHxUYWtlIGFuIGluc3RhbmNlIG91dCBvZiB0aGUga2VlcC1hbGl2ZSBwb29sIGFuZCByZWZ1bmQgaXRzIHJlbWFpbmluZyBkZXBvc2l0CihyZWNvcmQgb3duZXIgb25seSkKUmV0dXJucyB0aGUgcmVmdW5kZWQgYW1vdW50AAAAEGxlYXZlX2tlZXBfYWxpdmUAAAABAAAAAAAAAA1kZXBsb3ltZW50X2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAXNFeHRlbmQgdGhlIFRUTCBvZiBpbnN0YW5jZXMgaW4gdGhlIGtlZXAtYWxpdmUgcG9vbCB0aGF0IGFyZSBkdWUgKGFueW9uZSBjYW4gY2FsbCkKRWFjaCBleHRlbnNpb24gY292ZXJzIHRoZSBpbnN0YW5jZSBhbmQgaXRzIGNvZGUgYW5kIHBheXMgdGhlIGNhbGxlciB0aGUgcG9vbCBmZWUKZnJvbSB0aGF0IGluc3RhbmNlJ3MgZGVwb3NpdDsgaW5zdGFuY2VzIHRoYXQgY2FuJ3QgcGF5IHRoZSBmZWUgYXJlIHNraXBwZWQKc3RhcnQ6IEluZGV4IG9mIHRoZSBmaXJzdCBwb29sIGVudHJ5IHRvIGNoZWNrCmxpbWl0OiBNYXhpbXVtIG51bWJlciBvZiBwb29sIGVudHJpZXMgdG8gY2hlY2sKUmV0dXJucyB0aGUgbnVtYmVyIG9mIGluc3RhbmNlcyBleHRlbmRlZAAAAAAQZXh0ZW5kX2luc3RhbmNlcwAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADJHZXQgdGhlIG51bWJlciBvZiBpbnN0YW5jZXMgaW4gdGhlIGtlZXAtYWxpdmUgcG9vbAAAAAAAGGdldF9rZWVwX2FsaXZlX3Bvb2xfc2l6ZQAAAAAAAAABAAAABA==",
+        "AAAAAAAAADpHZXQgYW4gaW5zdGFuY2UncyBrZWVwLWFsaXZlIGRlcG9zaXQsIGlmIGl0IGlzIGluIHRoZSBwb29sAAAAAAAOZ2V0X2tlZXBfYWxpdmUAAAAAAAEAAAAAAAAADWRlcGxveW1lbnRfaWQAAAAAAAAEAAAAAQAAA+gAAAfQAAAACUtlZXBBbGl2ZQAAAA==",
+        "AAAAAAAAAIJTZXQgYXNpZGUgYSBidWRnZXQgdGhhdCBwYXlzIGRlcGxveSBwcmljZXMgZm9yIGFsbG93bGlzdGVkIGRlcGxveWVycwphbW91bnQ6IEluaXRpYWwgYnVkZ2V0IGluIGBwYXJhbXMudG9rZW5gLCBoZWxkIGJ5IHRoZSBtYW5hZ2VyAAAAAAASY3JlYXRlX3Nwb25zb3JzaGlwAAAAAAADAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAABnBhcmFtcwAAAAAH0AAAABFTcG9uc29yc2hpcFBhcmFtcwAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAACxBZGQgdG8gYSBzcG9uc29yc2hpcCdzIGJ1ZGdldCAoc3BvbnNvciBvbmx5KQAAABBmdW5kX3Nwb25zb3JzaGlwAAAAAgAAAAAAAAAOc3BvbnNvcnNoaXBfaWQAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEZSZXBsYWNlIHRoZSBkZXBsb3llcnMgYW5kIGxpc3RpbmdzIGEgc3BvbnNvcnNoaXAgY292ZXJzIChzcG9uc29yIG9ubHkpAAAAAAAZc2V0X3Nwb25zb3JzaGlwX2FsbG93bGlzdAAAAAAAAAMAAAAAAAAADnNwb25zb3JzaGlwX2lkAAAAAAAEAAAAAAAAAAlkZXBsb3llcnMAAAAAAAPqAAAAEwAAAAAAAAAIbGlzdGluZ3MAAAPqAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAKZSZWZ1bmQgYSBzcG9uc29yc2hpcCdzIHJlbWFpbmluZyBidWRnZXQgdG8gdGhlIHNwb25zb3IgKHNwb25zb3Igb25seSkKVGhlIHNwb25zb3JzaGlwIHN0YXlzLCB3aXRoIGFuIGVtcHR5IGJ1ZGdldCwgYW5kIGNhbiBiZSBmdW5kZWQgYWdhaW4KUmV0dXJucyB0aGUgcmVmdW5kZWQgYW1vdW50AAAAAAAUd2l0aGRyYXdfc3BvbnNvcnNoaXAAAAABAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAATtEZXBsb3kgYSByZWxlYXNlIG9mIGEgcmVnaXN0cnkgbGlzdGluZyB3aXRoIHRoZSBkZXBsb3kgcHJpY2UgcGFpZCBmcm9tIGEgc3BvbnNvcnNoaXAKVGhlIGRlcGxveWVyIG11c3QgYmUgb24gdGhlIHNwb25zb3JzaGlwJ3MgYWxsb3dsaXN0LCB0aGUgbGlzdGluZyBtdXN0IGJlIGNvdmVyZWQKYW5kIHByaWNlZCBpbiB0aGUgc3BvbnNvcnNoaXAncyB0b2tlbiwgYW5kIHRoZSBidWRnZXQgbXVzdCBjb3ZlciB0aGUgcHJpY2UKRXhhY3RseSB0aGUgYW1vdW50IGRlYml0ZWQgZnJvbSB0aGUgc3BvbnNvcnNoaXAgaXMgcGFpZCBvdXQgb2YgdGhlIG1hbmFnZXIAAAAAEGRlcGxveV9zcG9uc29yZWQAAAAGAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAAAAAALY29udHJhY3RfaWQAAAAABAAAAAAAAAAHcmVsZWFzZQAAAAAQAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAJaW5pdF9hcmdzAAAAAAAD6gAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAERHZXQgYSBzcGVjaWZpYyBzcG9uc29yc2hpcCwgaW5jbHVkaW5nIGl0cyByZW1haW5pbmcgYW5kIHNwZW50IGJ1ZGdldAAAAA9nZXRfc3BvbnNvcnNoaXAAAAAAAQAAAAAAAAAOc3BvbnNvcnNoaXBfaWQAAAAAAAQAAAABAAAD6QAAB9AAAAALU3BvbnNvcnNoaXAAAAAAAw==",
+        "AAAAAAAAAClHZXQgYWxsIHNwb25zb3JzaGlwcyBjcmVhdGVkIGJ5IGEgc3BvbnNvcgAAAAAAABBnZXRfc3BvbnNvcnNoaXBzAAAAAQAAAAAAAAAHc3BvbnNvcgAAAAATAAAAAQAAA+oAAAfQAAAAC1Nwb25zb3JzaGlwAA==",
+        "AAAAAAAAADhHZXQgdGhlIGRlcGxveW1lbnRzIGEgc3BvbnNvcnNoaXAgcGFpZCBmb3IsIG9sZGVzdCBmaXJzdAAAABVnZXRfc3BvbnNvcnNoaXBfdXNhZ2UAAAAAAAABAAAAAAAAAA5zcG9uc29yc2hpcF9pZAAAAAAABAAAAAEAAAPqAAAH0AAAABNTcG9uc29yZWREZXBsb3ltZW50AA==",
+        "AAAAAAAAAL5PZmZlciBsaWNlbnNlcyBmb3IgYSBsaXN0aW5nIChsaXN0aW5nIGF1dGhvciBvbmx5KQpPbmNlIGEgbGlzdGluZyBoYXMgYW4gb2ZmZXIsIGRlcGxveWluZyBpdCByZXF1aXJlcyBhIHZhbGlkIGxpY2Vuc2UKZHVyYXRpb246IFNlY29uZHMgYSBsaWNlbnNlIHN0YXlzIHZhbGlkIGFmdGVyIHB1cmNoYXNlICgwIGZvciBwZXJwZXR1YWwpAAAAAAAUY3JlYXRlX2xpY2Vuc2Vfb2ZmZXIAAAAFAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAAAAAAVzY29wZQAAAAAAB9AAAAAMTGljZW5zZVNjb3BlAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAHFPcGVuIG9yIGNsb3NlIGFuIG9mZmVyIGZvciBuZXcgcHVyY2hhc2VzIChsaXN0aW5nIGF1dGhvciBvbmx5KQpDbG9zaW5nIGV2ZXJ5IG9mZmVyIGRvZXMgbm90IHVubGljZW5zZSB0aGUgbGlzdGluZwAAAAAAABhzZXRfbGljZW5zZV9vZmZlcl9hY3RpdmUAAAACAAAAAAAAAAhvZmZlcl9pZAAAAAQAAAAAAAAABmFjdGl2ZQAAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAD5CdXkgYSBsaWNlbnNlLCBwYXlpbmcgdGhlIG9mZmVyIHByaWNlIHRvIHRoZSBsaXN0aW5nJ3MgYXV0aG9ycwAAAAAAEHB1cmNoYXNlX2xpY2Vuc2UAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAABxHZXQgYSBzcGVjaWZpYyBsaWNlbnNlIG9mZmVyAAAAEWdldF9saWNlbnNlX29mZmVyAAAAAAAAAQAAAAAAAAAIb2ZmZXJfaWQAAAAEAAAAAQAAA+kAAAfQAAAADExpY2Vuc2VPZmZlcgAAAAM=",
+        "AAAAAAAAACRHZXQgYWxsIGxpY2Vuc2Ugb2ZmZXJzIGZvciBhIGxpc3RpbmcAAAASZ2V0X2xpY2Vuc2Vfb2ZmZXJzAAAAAAABAAAAAAAAAAtjb250cmFjdF9pZAAAAAAEAAAAAQAAA+oAAAfQAAAADExpY2Vuc2VPZmZlcg==",
+        "AAAAAAAAAERHZXQgYWxsIGxpY2Vuc2VzIGJvdWdodCBieSBhIGhvbGRlciwgaW5jbHVkaW5nIGV4cGlyZWQgYW5kIHVzZWQgb25lcwAAAAxnZXRfbGljZW5zZXMAAAABAAAAAAAAAAZob2xkZXIAAAAAABMAAAABAAAD6gAAB9AAAAAHTGljZW5zZQA=",
+        "AAAAAAAAADVDaGVjayB3aGV0aGVyIGEgaG9sZGVyIGNhbiBjdXJyZW50bHkgZGVwbG95IGEgbGlzdGluZwAAAAAAABFoYXNfdmFsaWRfbGljZW5zZQAAAAAAAAIAAAAAAAAAC2NvbnRyYWN0X2lkAAAAAAQAAAAAAAAABmhvbGRlcgAAAAAAEwAAAAEAAAAB",
       ]),
       options,
     );
   }
   public readonly fromJSON = {
+    set_platform_fee: this.txFromJSON<Result<void>>,
+    get_platform_fee: this.txFromJSON<Option<PlatformFee>>,
+    set_keep_alive_config: this.txFromJSON<Result<void>>,
+    get_keep_alive_config: this.txFromJSON<Option<KeepAliveConfig>>,
+    set_deploy_policy: this.txFromJSON<void>,
+    get_deploy_policy: this.txFromJSON<Option<DeployPolicy>>,
+    set_deployer_policy: this.txFromJSON<void>,
+    get_deployer_policy: this.txFromJSON<Option<DeployPolicy>>,
+    get_registry: this.txFromJSON<string>,
     deploy_with_admin: this.txFromJSON<Result<u32>>,
     deploy_from_wasm: this.txFromJSON<Result<u32>>,
+    deploy_from_bytes: this.txFromJSON<Result<u32>>,
+    deploy_from_registry: this.txFromJSON<Result<u32>>,
+    deploy_and_call: this.txFromJSON<Result<u32>>,
+    deploy_next: this.txFromJSON<Result<u32>>,
+    deploy_batch: this.txFromJSON<Result<u32>>,
+    get_group_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    create_template: this.txFromJSON<Result<u32>>,
+    update_template: this.txFromJSON<Result<void>>,
+    delete_template: this.txFromJSON<Result<void>>,
+    deploy_from_template: this.txFromJSON<Result<u32>>,
+    get_template: this.txFromJSON<Result<DeploymentTemplate>>,
+    get_templates: this.txFromJSON<Array<DeploymentTemplate>>,
+    get_recommended_templates: this.txFromJSON<Array<DeploymentTemplate>>,
+    propose_intent: this.txFromJSON<Result<u32>>,
+    approve_intent: this.txFromJSON<Result<void>>,
+    execute_intent: this.txFromJSON<Result<u32>>,
+    get_intent: this.txFromJSON<Result<DeploymentIntent>>,
+    get_deployer_nonce: this.txFromJSON<u64>,
+    derive_salt: this.txFromJSON<Buffer>,
+    derive_intent_salt: this.txFromJSON<Buffer>,
+    redeploy: this.txFromJSON<Result<u32>>,
+    set_managed_upgrades: this.txFromJSON<Result<void>>,
+    upgrade_deployment: this.txFromJSON<Result<void>>,
+    rollback_deployment: this.txFromJSON<Result<void>>,
+    sync_deployment: this.txFromJSON<Result<Buffer>>,
+    get_observed_hashes: this.txFromJSON<Array<ObservedHash>>,
+    predict_address: this.txFromJSON<string>,
+    is_address_taken: this.txFromJSON<boolean>,
+    check_init_args: this.txFromJSON<Option<u32>>,
     get_deployment: this.txFromJSON<Result<DeploymentRecord>>,
+    get_deployment_by_address: this.txFromJSON<Result<DeploymentRecord>>,
     get_deployment_history: this.txFromJSON<Array<DeploymentRecord>>,
+    set_deployment_info: this.txFromJSON<Result<void>>,
+    get_project_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    get_environment_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    set_deployment_status: this.txFromJSON<Result<void>>,
+    set_deployment_hidden: this.txFromJSON<Result<void>>,
+    transfer_deployment: this.txFromJSON<Result<void>>,
+    get_owned_deployments: this.txFromJSON<Array<DeploymentRecord>>,
     get_contract_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    get_hash_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    get_hash_deployment_count: this.txFromJSON<u32>,
+    get_release_deployments: this.txFromJSON<Array<DeploymentRecord>>,
+    get_release_deployment_count: this.txFromJSON<u32>,
     get_all_deployments: this.txFromJSON<Array<DeploymentRecord>>,
     get_total_deployments: this.txFromJSON<u32>,
+    fund_keep_alive: this.txFromJSON<Result<void>>,
+    leave_keep_alive: this.txFromJSON<Result<i128>>,
+    extend_instances: this.txFromJSON<Result<u32>>,
+    get_keep_alive_pool_size: this.txFromJSON<u32>,
+    get_keep_alive: this.txFromJSON<Option<KeepAlive>>,
+    create_sponsorship: this.txFromJSON<Result<u32>>,
+    fund_sponsorship: this.txFromJSON<Result<void>>,
+    set_sponsorship_allowlist: this.txFromJSON<Result<void>>,
+    withdraw_sponsorship: this.txFromJSON<Result<i128>>,
+    deploy_sponsored: this.txFromJSON<Result<u32>>,
+    get_sponsorship: this.txFromJSON<Result<Sponsorship>>,
+    get_sponsorships: this.txFromJSON<Array<Sponsorship>>,
+    get_sponsorship_usage: this.txFromJSON<Array<SponsoredDeployment>>,
+    create_license_offer: this.txFromJSON<Result<u32>>,
+    set_license_offer_active: this.txFromJSON<Result<void>>,
+    purchase_license: this.txFromJSON<Result<u32>>,
+    get_license_offer: this.txFromJSON<Result<LicenseOffer>>,
+    get_license_offers: this.txFromJSON<Array<LicenseOffer>>,
+    get_licenses: this.txFromJSON<Array<License>>,
+    has_valid_license: this.txFromJSON<boolean>,
   };
 }
@@ -293,6 +293,7 @@ export function PublishContract() {
           documentation_url: formData.documentationUrl,
           license: formData.license,
           tags: tags,
+          forked_from: { tag: "Original", values: undefined },
         },
       });
 